@@ -0,0 +1,147 @@
+//! Python exceptions for `WtgError`.
+//!
+//! Every exception derives from `WtgError`, so `except wtg_cli.WtgError`
+//! catches anything wtg raises.
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyUserWarning};
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+
+use wtg_cli::error::WtgError as RustError;
+use wtg_cli::output::plain_error;
+
+create_exception!(
+    _wtg,
+    WtgError,
+    PyException,
+    "Base class for everything wtg raises."
+);
+create_exception!(
+    _wtg,
+    NotFoundError,
+    WtgError,
+    "The commit, issue, PR, file, tag or release doesn't exist."
+);
+create_exception!(
+    _wtg,
+    AmbiguousInputError,
+    WtgError,
+    "The input matches several things; `candidates` lists `(kind, preview)` for each."
+);
+create_exception!(
+    _wtg,
+    InvalidInputError,
+    WtgError,
+    "The input, a URL or a setting couldn't be understood."
+);
+create_exception!(
+    _wtg,
+    NotInGitRepoError,
+    WtgError,
+    "No repository was given and the working directory isn't a git repository."
+);
+create_exception!(
+    _wtg,
+    UnsupportedError,
+    WtgError,
+    "The backend can't answer this kind of query (e.g. PRs without forge access)."
+);
+create_exception!(
+    _wtg,
+    GitError,
+    WtgError,
+    "Reading the git repository failed."
+);
+create_exception!(
+    _wtg,
+    ForgeError,
+    WtgError,
+    "Talking to GitHub (or another forge) failed."
+);
+create_exception!(
+    _wtg,
+    RateLimitError,
+    ForgeError,
+    "The forge API rate limit was hit."
+);
+create_exception!(
+    _wtg,
+    AuthenticationError,
+    ForgeError,
+    "The forge rejected the token, or SAML SSO blocks access."
+);
+create_exception!(
+    _wtg,
+    RequestTimeoutError,
+    ForgeError,
+    "A network request took too long."
+);
+
+create_exception!(
+    _wtg,
+    WtgWarning,
+    PyUserWarning,
+    "Something worth knowing happened while resolving (rate limits, fallbacks, clones)."
+);
+
+/// Convert a wtg error into the matching Python exception.
+pub(crate) fn to_py_err(py: Python<'_>, err: RustError) -> PyErr {
+    let message = plain_error(&err);
+    match err {
+        RustError::NotFound(_)
+        | RustError::TagNotFound(_)
+        | RustError::NoReleaseAt(_)
+        | RustError::LinesOutOfRange { .. } => NotFoundError::new_err(message),
+        RustError::MultipleMatches(candidates) => {
+            let err = AmbiguousInputError::new_err(message);
+            let candidates: Vec<(&str, String)> = candidates
+                .into_iter()
+                .map(|candidate| (candidate.kind, candidate.preview))
+                .collect();
+            // Best effort: the message lists the candidates too
+            if let Ok(candidates) = PyTuple::new(py, candidates) {
+                let _ = err.value(py).setattr("candidates", candidates);
+            }
+            err
+        }
+        RustError::EmptyInput
+        | RustError::Cli { .. }
+        | RustError::NotGitHubUrl(_)
+        | RustError::MalformedGitHubUrl(_)
+        | RustError::MalformedUrl(_)
+        | RustError::SecurityRejection(_)
+        | RustError::Config { .. } => InvalidInputError::new_err(message),
+        RustError::NotInGitRepo => NotInGitRepoError::new_err(message),
+        RustError::Unsupported(_) => UnsupportedError::new_err(message),
+        RustError::Git(_) | RustError::Io(_) => GitError::new_err(message),
+        RustError::GhRateLimit(_) => RateLimitError::new_err(message),
+        RustError::GhBadCredentials(_) | RustError::GhSaml(_) => {
+            AuthenticationError::new_err(message)
+        }
+        RustError::Timeout => RequestTimeoutError::new_err(message),
+        RustError::GitHub(_)
+        | RustError::Http(_)
+        | RustError::GhConnectionLost
+        | RustError::GitHubClientFailed => ForgeError::new_err(message),
+        RustError::BatchFailed { .. } => WtgError::new_err(message),
+    }
+}
+
+/// Register the exception classes on the module.
+pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add("WtgError", py.get_type::<WtgError>())?;
+    m.add("NotFoundError", py.get_type::<NotFoundError>())?;
+    m.add("AmbiguousInputError", py.get_type::<AmbiguousInputError>())?;
+    m.add("InvalidInputError", py.get_type::<InvalidInputError>())?;
+    m.add("NotInGitRepoError", py.get_type::<NotInGitRepoError>())?;
+    m.add("UnsupportedError", py.get_type::<UnsupportedError>())?;
+    m.add("GitError", py.get_type::<GitError>())?;
+    m.add("ForgeError", py.get_type::<ForgeError>())?;
+    m.add("RateLimitError", py.get_type::<RateLimitError>())?;
+    m.add("AuthenticationError", py.get_type::<AuthenticationError>())?;
+    m.add("RequestTimeoutError", py.get_type::<RequestTimeoutError>())?;
+    m.add("WtgWarning", py.get_type::<WtgWarning>())?;
+    Ok(())
+}
@@ -1,7 +1,19 @@
+use std::sync::{Arc, LazyLock, Mutex};
+
 use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
+use serde::Serialize;
+
+use wtg_cli::{
+    IdentifiedThing, Notice, NoticeCallback, ReleaseFilter, Wtg, WtgResult, run_with_args,
+};
+
+mod errors;
+mod task;
+mod types;
 
-use wtg_cli::run_with_args;
+use task::AbortOnDrop;
+use types::{PyEnrichedInfo, PyFileResult, PyTagResult};
 
 /// Entry point used by the Python package to execute the CLI.
 #[pyfunction]
@@ -21,8 +33,238 @@ fn run_cli(argv: Vec<String>) -> i32 {
     }
 }
 
+/// Shared by every call, so concurrent `resolve_async` calls run side by side.
+static RUNTIME: LazyLock<tokio::runtime::Runtime> = LazyLock::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .thread_name("wtg")
+        .build()
+        .expect("failed to start the tokio runtime")
+});
+
+/// Options for one `resolve` call, copied out of Python before the GIL is released.
+struct Options {
+    repo: Option<String>,
+    release: Option<String>,
+    skip_prereleases: bool,
+    tag_prefix: Option<String>,
+    all_releases: bool,
+    fetch: bool,
+}
+
+impl Options {
+    fn release_filter(&self) -> ReleaseFilter {
+        self.release
+            .as_deref()
+            .map_or_else(ReleaseFilter::default, ReleaseFilter::specific)
+            .skip_prereleases(self.skip_prereleases)
+            .tag_prefix(self.tag_prefix.clone())
+            .release_lines(self.all_releases)
+    }
+
+    fn client(&self, notice_cb: NoticeCallback) -> WtgResult<Wtg> {
+        let mut builder = Wtg::builder()
+            .fetch(self.fetch)
+            .release_filter(self.release_filter())
+            .notice_callback(notice_cb);
+        if let Some(repo) = &self.repo {
+            builder = builder.repo(repo);
+        }
+        builder.build()
+    }
+}
+
+/// Identify a commit, issue, PR, file or tag and find the release that shipped it.
+///
+/// Returns an `EnrichedInfo` (commits, PRs, issues), `FileResult` or `TagResult`.
+/// Other results (file history, blamed lines, directories, release ranges) come
+/// back as a dict shaped like `wtg --format json` output.
+///
+/// Notices are passed to `on_notice` as dicts (`{"kind": "gh_rate_limit_hit", ...}`)
+/// once the query is done; without a callback they're issued as `WtgWarning`s.
+#[pyfunction]
+#[pyo3(signature = (
+    query,
+    *,
+    repo = None,
+    release = None,
+    skip_prereleases = false,
+    tag_prefix = None,
+    all_releases = false,
+    fetch = false,
+    on_notice = None,
+))]
+#[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
+fn resolve(
+    py: Python<'_>,
+    query: &str,
+    repo: Option<String>,
+    release: Option<String>,
+    skip_prereleases: bool,
+    tag_prefix: Option<String>,
+    all_releases: bool,
+    fetch: bool,
+    on_notice: Option<&Bound<'_, PyAny>>,
+) -> PyResult<Py<PyAny>> {
+    let options = Options {
+        repo,
+        release,
+        skip_prereleases,
+        tag_prefix,
+        all_releases,
+        fetch,
+    };
+
+    let notices = NoticeSink::default();
+    // Network and git work happens without holding the GIL
+    let outcome = py.detach(|| RUNTIME.block_on(resolve_with(query, &options, notices.callback())));
+    finish(py, outcome, &notices, on_notice)
+}
+
+/// Awaitable version of `resolve`, for asyncio code.
+///
+/// The query runs on a shared tokio runtime without holding the GIL, so many
+/// queries can be awaited concurrently (e.g. with `asyncio.gather`). Cancelling
+/// the awaiting task stops the query.
+#[pyfunction]
+#[pyo3(signature = (
+    query,
+    *,
+    repo = None,
+    release = None,
+    skip_prereleases = false,
+    tag_prefix = None,
+    all_releases = false,
+    fetch = false,
+    on_notice = None,
+))]
+#[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
+async fn resolve_async(
+    query: String,
+    repo: Option<String>,
+    release: Option<String>,
+    skip_prereleases: bool,
+    tag_prefix: Option<String>,
+    all_releases: bool,
+    fetch: bool,
+    on_notice: Option<Py<PyAny>>,
+) -> PyResult<Py<PyAny>> {
+    let options = Options {
+        repo,
+        release,
+        skip_prereleases,
+        tag_prefix,
+        all_releases,
+        fetch,
+    };
+
+    let notices = NoticeSink::default();
+    let notice_cb = notices.callback();
+    let task =
+        AbortOnDrop(RUNTIME.spawn(async move { resolve_with(&query, &options, notice_cb).await }));
+    let outcome = task.await;
+
+    Python::attach(|py| {
+        let outcome = outcome.map_err(|err| errors::WtgError::new_err(err.to_string()))?;
+        finish(
+            py,
+            outcome,
+            &notices,
+            on_notice.as_ref().map(|cb| cb.bind(py)),
+        )
+    })
+}
+
+async fn resolve_with(
+    query: &str,
+    options: &Options,
+    notice_cb: NoticeCallback,
+) -> WtgResult<IdentifiedThing> {
+    options.client(notice_cb)?.resolve(query).await
+}
+
+/// Deliver the collected notices, then hand over the result or raise the error.
+fn finish(
+    py: Python<'_>,
+    outcome: WtgResult<IdentifiedThing>,
+    notices: &NoticeSink,
+    on_notice: Option<&Bound<'_, PyAny>>,
+) -> PyResult<Py<PyAny>> {
+    deliver_notices(py, notices.take(), on_notice)?;
+    match outcome {
+        Ok(thing) => into_py_result(py, thing),
+        Err(err) => Err(errors::to_py_err(py, err)),
+    }
+}
+
+/// Notices collected while resolving, delivered to Python once the query is done.
+#[derive(Default)]
+struct NoticeSink(Arc<Mutex<Vec<Notice>>>);
+
+impl NoticeSink {
+    fn callback(&self) -> NoticeCallback {
+        let sink = Arc::clone(&self.0);
+        Arc::new(move |notice| {
+            if let Ok(mut notices) = sink.lock() {
+                notices.push(notice);
+            }
+        })
+    }
+
+    fn take(&self) -> Vec<Notice> {
+        self.0
+            .lock()
+            .map(|mut notices| std::mem::take(&mut *notices))
+            .unwrap_or_default()
+    }
+}
+
+fn into_py_result(py: Python<'_>, thing: IdentifiedThing) -> PyResult<Py<PyAny>> {
+    Ok(match thing {
+        IdentifiedThing::Enriched(info) => Py::new(py, PyEnrichedInfo::from(*info))?.into_any(),
+        IdentifiedThing::File(file) => Py::new(py, PyFileResult::from(*file))?.into_any(),
+        IdentifiedThing::Tag(tag) => Py::new(py, PyTagResult::from(*tag))?.into_any(),
+        other => to_py_object(py, &other)?,
+    })
+}
+
+/// Hand notices to the callback, or raise them as `WtgWarning`s.
+fn deliver_notices(
+    py: Python<'_>,
+    notices: Vec<Notice>,
+    on_notice: Option<&Bound<'_, PyAny>>,
+) -> PyResult<()> {
+    if notices.is_empty() {
+        return Ok(());
+    }
+    let warnings = py.import("warnings")?;
+    let category = py.get_type::<errors::WtgWarning>();
+    for notice in notices {
+        if let Some(callback) = on_notice {
+            callback.call1((to_py_object(py, &notice)?,))?;
+        } else {
+            let details = serde_json::to_string(&notice).unwrap_or_default();
+            warnings.call_method1("warn", (format!("wtg: {details}"), &category))?;
+        }
+    }
+    Ok(())
+}
+
+/// Turn a serializable value into plain Python objects, shaped like its JSON output.
+fn to_py_object<T: Serialize>(py: Python<'_>, value: &T) -> PyResult<Py<PyAny>> {
+    let json =
+        serde_json::to_string(value).map_err(|err| errors::WtgError::new_err(err.to_string()))?;
+    Ok(py.import("json")?.call_method1("loads", (json,))?.unbind())
+}
+
 #[pymodule]
-fn _wtg(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+fn _wtg(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(run_cli, m)?)?;
+    m.add_function(wrap_pyfunction!(resolve, m)?)?;
+    m.add_function(wrap_pyfunction!(resolve_async, m)?)?;
+    py.import("atexit")?
+        .call_method1("register", (wrap_pyfunction!(task::shutdown, m)?,))?;
+    types::register(m)?;
+    errors::register(m)?;
     Ok(())
 }
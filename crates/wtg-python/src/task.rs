@@ -0,0 +1,100 @@
+//! Awaiting tokio tasks from Python coroutines.
+//!
+//! pyo3's coroutines are woken from tokio's threads, which take the GIL. Doing
+//! that while the interpreter finalizes kills the thread mid-wake, so wakes are
+//! counted and `shutdown` (registered with `atexit`) waits for them. This is
+//! what `pyo3_async_runtimes::tokio::future_into_py` would do for us; switch to
+//! it once a release matching our pyo3 version is available to the build.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll, Wake, Waker};
+use std::time::Duration;
+
+use pyo3::prelude::*;
+use tokio::task::{JoinError, JoinHandle};
+
+/// A spawned task that's aborted when whoever awaits it goes away (a cancelled coroutine).
+pub(crate) struct AbortOnDrop<T>(pub(crate) JoinHandle<T>);
+
+impl<T> Future for AbortOnDrop<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let waker = Waker::from(Arc::new(GuardedWaker(cx.waker().clone())));
+        Pin::new(&mut self.0).poll(&mut Context::from_waker(&waker))
+    }
+}
+
+impl<T> Drop for AbortOnDrop<T> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Coroutine wakes running on runtime threads, and whether new ones are still allowed.
+struct Wakes {
+    /// Set once the interpreter starts shutting down; coroutines aren't woken after that
+    shutting_down: bool,
+    in_flight: usize,
+}
+
+static WAKES: Mutex<Wakes> = Mutex::new(Wakes {
+    shutting_down: false,
+    in_flight: 0,
+});
+/// Signalled when the last wake in flight is done.
+static WAKES_DONE: Condvar = Condvar::new();
+
+/// How long `shutdown` waits for wakes in flight. A wake only takes the GIL
+/// and writes to a pipe, so this is only reached if one is stuck; exiting
+/// then beats hanging forever.
+const SHUTDOWN_WAKE_TIMEOUT: Duration = Duration::from_secs(2);
+
+fn wakes() -> MutexGuard<'static, Wakes> {
+    WAKES.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Wakes the coroutine awaiting a task.
+///
+/// Waking it takes the GIL on a runtime thread, and can give it up halfway
+/// (asyncio writes to its self-pipe). Python must not finalize in that window,
+/// so `shutdown` waits for wakes in flight.
+struct GuardedWaker(Waker);
+
+impl Wake for GuardedWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        {
+            let mut wakes = wakes();
+            if wakes.shutting_down {
+                return;
+            }
+            wakes.in_flight += 1;
+        }
+        self.0.wake_by_ref();
+
+        let mut wakes = wakes();
+        wakes.in_flight -= 1;
+        if wakes.in_flight == 0 {
+            WAKES_DONE.notify_all();
+        }
+    }
+}
+
+/// Registered with `atexit`: stop waking coroutines and let running wakes finish.
+#[pyfunction]
+pub(crate) fn shutdown(py: Python<'_>) {
+    // Running wakes need the GIL to finish
+    py.detach(|| {
+        let mut wakes = wakes();
+        wakes.shutting_down = true;
+        let _ = WAKES_DONE
+            .wait_timeout_while(wakes, SHUTDOWN_WAKE_TIMEOUT, |wakes| wakes.in_flight > 0)
+            .unwrap_or_else(PoisonError::into_inner);
+    });
+}
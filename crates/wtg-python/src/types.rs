@@ -0,0 +1,330 @@
+//! Python classes mirroring wtg's result types.
+//!
+//! They're plain read-only snapshots: every field is copied out of the Rust
+//! value when a result is handed to Python.
+
+use chrono::{DateTime, Utc};
+use pyo3::prelude::*;
+
+use wtg_cli::git::{CommitInfo, FileInfo, TagInfo};
+use wtg_cli::github::PullRequestInfo;
+use wtg_cli::resolution::{ChangesSource, EnrichedInfo, FileResult, IssueInfo, TagResult};
+
+/// A git commit.
+#[pyclass(name = "CommitInfo", module = "wtg_cli._wtg", frozen, get_all)]
+#[derive(Clone)]
+pub(crate) struct PyCommitInfo {
+    hash: String,
+    short_hash: String,
+    /// First line of the commit message
+    message: String,
+    message_lines: usize,
+    commit_url: Option<String>,
+    author_name: String,
+    author_email: Option<String>,
+    author_login: Option<String>,
+    author_url: Option<String>,
+    date: DateTime<Utc>,
+}
+
+#[pymethods]
+impl PyCommitInfo {
+    fn __repr__(&self) -> String {
+        format!("CommitInfo({} {:?})", self.short_hash, self.message)
+    }
+}
+
+impl From<CommitInfo> for PyCommitInfo {
+    fn from(commit: CommitInfo) -> Self {
+        Self {
+            hash: commit.hash,
+            short_hash: commit.short_hash,
+            message: commit.message,
+            message_lines: commit.message_lines,
+            commit_url: commit.commit_url,
+            author_name: commit.author_name,
+            author_email: commit.author_email,
+            author_login: commit.author_login,
+            author_url: commit.author_url,
+            date: commit.date,
+        }
+    }
+}
+
+/// A pull request (or GitLab merge request).
+#[pyclass(name = "PullRequestInfo", module = "wtg_cli._wtg", frozen, get_all)]
+#[derive(Clone)]
+pub(crate) struct PyPullRequestInfo {
+    number: u64,
+    /// `owner/repo` the PR lives in, when known
+    repo: Option<String>,
+    title: String,
+    body: Option<String>,
+    state: String,
+    url: String,
+    merged: bool,
+    merge_commit_sha: Option<String>,
+    author: Option<String>,
+    author_url: Option<String>,
+    created_at: Option<DateTime<Utc>>,
+    labels: Vec<String>,
+}
+
+#[pymethods]
+impl PyPullRequestInfo {
+    fn __repr__(&self) -> String {
+        format!("PullRequestInfo(#{} {:?})", self.number, self.title)
+    }
+}
+
+impl From<PullRequestInfo> for PyPullRequestInfo {
+    fn from(pr: PullRequestInfo) -> Self {
+        Self {
+            number: pr.number,
+            repo: pr
+                .repo_info
+                .map(|repo| format!("{}/{}", repo.owner(), repo.repo())),
+            title: pr.title,
+            body: pr.body,
+            state: pr.state,
+            url: pr.url,
+            merged: pr.merged,
+            merge_commit_sha: pr.merge_commit_sha,
+            author: pr.author,
+            author_url: pr.author_url,
+            created_at: pr.created_at,
+            labels: pr.labels,
+        }
+    }
+}
+
+/// An issue.
+#[pyclass(name = "IssueInfo", module = "wtg_cli._wtg", frozen, get_all)]
+#[derive(Clone)]
+pub(crate) struct PyIssueInfo {
+    number: u64,
+    title: String,
+    body: Option<String>,
+    /// `open` or `closed`
+    state: String,
+    url: String,
+    author: Option<String>,
+    author_url: Option<String>,
+    /// Timeline data may be incomplete due to SAML-restricted org access
+    timeline_may_be_incomplete: bool,
+}
+
+#[pymethods]
+impl PyIssueInfo {
+    fn __repr__(&self) -> String {
+        format!("IssueInfo(#{} {:?})", self.number, self.title)
+    }
+}
+
+impl From<IssueInfo> for PyIssueInfo {
+    fn from(issue: IssueInfo) -> Self {
+        // Reuse the serialized name rather than matching octocrab's non-exhaustive enum
+        let state = serde_json::to_value(&issue.state)
+            .ok()
+            .and_then(|state| state.as_str().map(str::to_string))
+            .unwrap_or_default();
+        Self {
+            number: issue.number,
+            title: issue.title,
+            body: issue.body,
+            state,
+            url: issue.url,
+            author: issue.author,
+            author_url: issue.author_url,
+            timeline_may_be_incomplete: issue.timeline_may_be_incomplete,
+        }
+    }
+}
+
+/// A tag, and the forge release made from it if there is one.
+#[pyclass(name = "TagInfo", module = "wtg_cli._wtg", frozen, get_all)]
+#[derive(Clone)]
+pub(crate) struct PyTagInfo {
+    name: String,
+    commit_hash: String,
+    /// Date of the commit the tag points to
+    created_at: DateTime<Utc>,
+    is_semver: bool,
+    is_release: bool,
+    release_name: Option<String>,
+    release_url: Option<String>,
+    published_at: Option<DateTime<Utc>>,
+    tag_url: Option<String>,
+}
+
+#[pymethods]
+impl PyTagInfo {
+    fn __repr__(&self) -> String {
+        format!("TagInfo({:?})", self.name)
+    }
+}
+
+impl From<TagInfo> for PyTagInfo {
+    fn from(tag: TagInfo) -> Self {
+        Self {
+            is_semver: tag.is_semver(),
+            name: tag.name,
+            commit_hash: tag.commit_hash,
+            created_at: tag.created_at,
+            is_release: tag.is_release,
+            release_name: tag.release_name,
+            release_url: tag.release_url,
+            published_at: tag.published_at,
+            tag_url: tag.tag_url,
+        }
+    }
+}
+
+/// A commit, PR or issue, with everything that could be found about where it shipped.
+#[pyclass(name = "EnrichedInfo", module = "wtg_cli._wtg", frozen, get_all)]
+#[derive(Clone)]
+pub(crate) struct PyEnrichedInfo {
+    commit: Option<PyCommitInfo>,
+    pr: Option<PyPullRequestInfo>,
+    issue: Option<PyIssueInfo>,
+    /// The first release that shipped the change
+    release: Option<PyTagInfo>,
+    /// Every release line shipping the change, including cherry-picked copies
+    releases: Vec<PyTagInfo>,
+}
+
+#[pymethods]
+impl PyEnrichedInfo {
+    fn __repr__(&self) -> String {
+        let subject = self.pr.as_ref().map_or_else(
+            || {
+                self.commit
+                    .as_ref()
+                    .map_or_else(String::new, |commit| commit.short_hash.clone())
+            },
+            |pr| format!("#{}", pr.number),
+        );
+        let release = self
+            .release
+            .as_ref()
+            .map_or("unreleased", |release| &release.name);
+        format!("EnrichedInfo({subject} in {release})")
+    }
+}
+
+impl From<EnrichedInfo> for PyEnrichedInfo {
+    fn from(info: EnrichedInfo) -> Self {
+        Self {
+            commit: info.commit.map(Into::into),
+            pr: info.pr.map(Into::into),
+            issue: info.issue.map(Into::into),
+            release: info.release.map(Into::into),
+            releases: info
+                .releases
+                .into_iter()
+                .map(|line| line.release.into())
+                .collect(),
+        }
+    }
+}
+
+/// A file: the last change to it, who else worked on it, and where that change shipped.
+#[pyclass(name = "FileResult", module = "wtg_cli._wtg", frozen, get_all)]
+#[derive(Clone)]
+pub(crate) struct PyFileResult {
+    path: String,
+    last_commit: PyCommitInfo,
+    commit_url: Option<String>,
+    /// `(hash, name, email)` of earlier changes, most recent first
+    previous_authors: Vec<(String, String, String)>,
+    /// `(old path, new path, commit hash)` of each rename, most recent first
+    renames: Vec<(String, String, String)>,
+    release: Option<PyTagInfo>,
+}
+
+#[pymethods]
+impl PyFileResult {
+    fn __repr__(&self) -> String {
+        format!("FileResult({:?})", self.path)
+    }
+}
+
+impl From<FileResult> for PyFileResult {
+    fn from(result: FileResult) -> Self {
+        let FileInfo {
+            path,
+            last_commit,
+            previous_authors,
+            renames,
+        } = result.file_info;
+        Self {
+            path,
+            last_commit: last_commit.into(),
+            commit_url: result.commit_url,
+            previous_authors,
+            renames: renames
+                .into_iter()
+                .map(|rename| (rename.from, rename.to, rename.commit_hash))
+                .collect(),
+            release: result.release.map(Into::into),
+        }
+    }
+}
+
+/// A tag with its release notes, changelog section or commit list.
+#[pyclass(name = "TagResult", module = "wtg_cli._wtg", frozen, get_all)]
+#[derive(Clone)]
+pub(crate) struct PyTagResult {
+    tag: PyTagInfo,
+    url: Option<String>,
+    /// Release notes, changelog section or commit list
+    changes: Option<String>,
+    /// `release`, `changelog` or `commits`
+    changes_source: Option<String>,
+    /// Lines cut from `changes` to keep it short
+    truncated_lines: usize,
+    commits: Vec<PyCommitInfo>,
+    /// When the tag was looked up as the release current at some date
+    current_at: Option<DateTime<Utc>>,
+}
+
+#[pymethods]
+impl PyTagResult {
+    fn __repr__(&self) -> String {
+        format!("TagResult({:?})", self.tag.name)
+    }
+}
+
+impl From<TagResult> for PyTagResult {
+    fn from(result: TagResult) -> Self {
+        let changes_source = result.changes_source.map(|source| {
+            match source {
+                ChangesSource::GitHubRelease => "release",
+                ChangesSource::Changelog => "changelog",
+                ChangesSource::Commits { .. } => "commits",
+            }
+            .to_string()
+        });
+        Self {
+            tag: result.tag_info.into(),
+            url: result.github_url,
+            changes: result.changes,
+            changes_source,
+            truncated_lines: result.truncated_lines,
+            commits: result.commits.into_iter().map(Into::into).collect(),
+            current_at: result.current_at,
+        }
+    }
+}
+
+/// Register the result classes on the module.
+pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyCommitInfo>()?;
+    m.add_class::<PyPullRequestInfo>()?;
+    m.add_class::<PyIssueInfo>()?;
+    m.add_class::<PyTagInfo>()?;
+    m.add_class::<PyEnrichedInfo>()?;
+    m.add_class::<PyFileResult>()?;
+    m.add_class::<PyTagResult>()?;
+    Ok(())
+}
@@ -0,0 +1,301 @@
+//! Persistent on-disk cache for GitHub API responses.
+//!
+//! Responses live under `<cache dir>/wtg/api/<host>/<owner>/<repo>/`, one JSON
+//! file per resource, next to the cloned repositories in `<cache dir>/wtg/repos`.
+//!
+//! - Immutable resources (merged PRs, commits by SHA, tag-to-release-id mappings,
+//!   closing PRs of closed issues) are stored without an `ETag` and served
+//!   straight from disk.
+//! - Mutable resources keep their `ETag` and are revalidated with a conditional
+//!   request; a `304 Not Modified` doesn't count against the rate limit.
+//!
+//! With a TTL set (`cache.ttl_secs` in the config), mutable resources fetched
+//! less than the TTL ago are served from disk without revalidating.
+//!
+//! The cache is best effort: read and write failures are logged and treated as
+//! misses.
+
+use std::{
+    fs,
+    io::{Error as IoError, ErrorKind},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime},
+};
+
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{LogError, WtgError, WtgResult};
+use crate::github::GhRepoInfo;
+
+/// Characters left as-is in cache file names (everything else is percent-encoded).
+const FILE_NAME_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'_').remove(b'.');
+
+/// How a GitHub client uses the API cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheSettings {
+    /// Whether responses are read from and written to the cache at all.
+    pub enabled: bool,
+    /// Mutable entries younger than this are served without revalidating them.
+    pub ttl: Duration,
+}
+
+impl Default for CacheSettings {
+    /// Enabled, always revalidating mutable entries.
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ttl: Duration::ZERO,
+        }
+    }
+}
+
+/// Root directory of the API cache (`<cache dir>/wtg/api`).
+#[must_use]
+pub fn cache_root() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("wtg").join("api"))
+}
+
+/// Delete every cached API response.
+///
+/// Returns the removed directory, or `None` if there was nothing to remove.
+pub fn clear() -> WtgResult<Option<PathBuf>> {
+    let root = cache_root().ok_or_else(|| {
+        WtgError::Io(IoError::new(
+            ErrorKind::NotFound,
+            "Could not determine cache directory",
+        ))
+    })?;
+
+    match fs::remove_dir_all(&root) {
+        Ok(()) => Ok(Some(root)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// A stored API response body.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedResponse {
+    /// `ETag` to revalidate with; `None` for immutable resources.
+    pub etag: Option<String>,
+    pub body: serde_json::Value,
+    /// Time since the entry was written, set when read from disk.
+    #[serde(skip)]
+    pub age: Option<Duration>,
+}
+
+impl CachedResponse {
+    pub(crate) const fn new(etag: Option<String>, body: serde_json::Value) -> Self {
+        Self {
+            etag,
+            body,
+            age: None,
+        }
+    }
+
+    /// Immutable entries are served without asking GitHub.
+    pub(crate) const fn is_immutable(&self) -> bool {
+        self.etag.is_none()
+    }
+
+    /// Whether the entry can be served without asking GitHub: it's immutable
+    /// or younger than `ttl`.
+    fn is_fresh_within(&self, ttl: Duration) -> bool {
+        self.is_immutable() || self.age.is_some_and(|age| age < ttl)
+    }
+}
+
+/// API response cache for a single host.
+#[derive(Debug)]
+pub(crate) struct ApiCache {
+    root: PathBuf,
+    ttl: Duration,
+}
+
+impl ApiCache {
+    /// Cache for `host` with the default settings.
+    pub(crate) fn for_host(host: &str) -> Option<Self> {
+        Self::for_host_with(host, CacheSettings::default())
+    }
+
+    /// Cache for `host`, or `None` when caching is disabled or there's no cache directory.
+    pub(crate) fn for_host_with(host: &str, settings: CacheSettings) -> Option<Self> {
+        if !settings.enabled {
+            log::debug!("API cache disabled");
+            return None;
+        }
+        cache_root().map(|root| Self {
+            ttl: settings.ttl,
+            ..Self::at(root.join(encode(host)))
+        })
+    }
+
+    /// Cache rooted at an explicit directory, always revalidating mutable entries.
+    pub(crate) const fn at(root: PathBuf) -> Self {
+        Self {
+            root,
+            ttl: Duration::ZERO,
+        }
+    }
+
+    /// Whether `cached` can be served without asking GitHub: it's immutable
+    /// or younger than this cache's TTL.
+    pub(crate) fn is_fresh(&self, cached: &CachedResponse) -> bool {
+        cached.is_fresh_within(self.ttl)
+    }
+
+    /// Look up `key` (e.g. `pulls/123`) for a repository.
+    pub(crate) fn get(&self, repo_info: &GhRepoInfo, key: &str) -> Option<CachedResponse> {
+        let path = self.path(repo_info, key);
+        let contents = match fs::read(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return None,
+            Err(e) => {
+                log::debug!("Failed to read API cache entry {}: {e}", path.display());
+                return None;
+            }
+        };
+
+        let mut cached: CachedResponse = serde_json::from_slice(&contents)
+            .map_err(|e| WtgError::Io(e.into()))
+            .log_err(&format!(
+                "Ignoring corrupt API cache entry {}",
+                path.display()
+            ))?;
+        cached.age = fs::metadata(&path)
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+        Some(cached)
+    }
+
+    /// Store `key` for a repository, replacing any previous entry.
+    pub(crate) fn put(&self, repo_info: &GhRepoInfo, key: &str, response: &CachedResponse) {
+        let path = self.path(repo_info, key);
+        Self::write(&path, response).log_err(&format!(
+            "Failed to write API cache entry {}",
+            path.display()
+        ));
+    }
+
+    fn write(path: &Path, response: &CachedResponse) -> WtgResult<()> {
+        static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        // Write to a unique temp file and rename, so concurrent writers and
+        // readers never see a partial entry
+        let tmp = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let contents = serde_json::to_vec(response).map_err(|e| WtgError::Io(e.into()))?;
+        fs::write(&tmp, contents)?;
+        fs::rename(&tmp, path).inspect_err(|_| {
+            let _ = fs::remove_file(&tmp);
+        })?;
+
+        log::debug!("Cached API response in {}", path.display());
+        Ok(())
+    }
+
+    fn path(&self, repo_info: &GhRepoInfo, key: &str) -> PathBuf {
+        let mut path = self
+            .root
+            .join(encode(repo_info.owner()))
+            .join(encode(repo_info.repo()));
+        let (dirs, name) = key.rsplit_once('/').unwrap_or(("", key));
+        for segment in dirs.split('/').filter(|segment| !segment.is_empty()) {
+            path.push(encode(segment));
+        }
+        path.push(format!("{}.json", encode(name)));
+        path
+    }
+}
+
+/// Encode a path segment as a safe file name.
+fn encode(segment: &str) -> String {
+    utf8_percent_encode(segment, FILE_NAME_ENCODE_SET).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo() -> GhRepoInfo {
+        GhRepoInfo::new("owner".to_string(), "repo".to_string())
+    }
+
+    #[test]
+    fn round_trips_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ApiCache::at(dir.path().to_path_buf());
+
+        assert!(cache.get(&repo(), "pulls/1").is_none());
+
+        let response = CachedResponse::new(
+            Some("W/\"abc\"".to_string()),
+            serde_json::json!({ "number": 1 }),
+        );
+        cache.put(&repo(), "pulls/1", &response);
+
+        let cached = cache
+            .get(&repo(), "pulls/1")
+            .expect("entry should be cached");
+        assert_eq!(cached.etag.as_deref(), Some("W/\"abc\""));
+        assert_eq!(cached.body["number"], 1);
+        assert!(!cached.is_immutable());
+        assert!(dir.path().join("owner/repo/pulls/1.json").is_file());
+    }
+
+    #[test]
+    fn encodes_keys_as_file_names() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ApiCache::at(dir.path().to_path_buf());
+        let response = CachedResponse::new(None, serde_json::json!({}));
+
+        cache.put(&repo(), "releases/tags/crate/v1.0.0", &response);
+
+        assert!(cache.get(&repo(), "releases/tags/crate/v1.0.0").is_some());
+        assert!(cache.get(&repo(), "releases/tags/crate").is_none());
+        assert!(
+            dir.path()
+                .join("owner/repo/releases/tags/crate/v1.0.0.json")
+                .is_file()
+        );
+    }
+
+    #[test]
+    fn mutable_entries_are_fresh_within_ttl() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ApiCache::at(dir.path().to_path_buf());
+        cache.put(
+            &repo(),
+            "issues/7",
+            &CachedResponse::new(Some("\"v1\"".to_string()), serde_json::json!({})),
+        );
+
+        let cached = cache
+            .get(&repo(), "issues/7")
+            .expect("entry should be cached");
+        assert!(cached.age.is_some());
+        assert!(!cached.is_fresh_within(Duration::ZERO));
+        assert!(cached.is_fresh_within(Duration::from_hours(1)));
+    }
+
+    #[test]
+    fn ignores_corrupt_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ApiCache::at(dir.path().to_path_buf());
+        let path = dir.path().join("owner/repo/issues/7.json");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "{ not json").unwrap();
+
+        assert!(cache.get(&repo(), "issues/7").is_none());
+    }
+}
@@ -0,0 +1,274 @@
+//! Pure Bitbucket Cloud API backend implementation.
+//!
+//! This backend only uses the Bitbucket REST API via `BitbucketClient`.
+//! It can fetch commits, pull requests and tags, but cannot walk file history
+//! or perform local git operations. Bitbucket has no releases, so every tag is
+//! a plain tag.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+use super::Backend;
+use crate::bitbucket::{BbRepoInfo, BitbucketClient};
+use crate::error::{WtgError, WtgResult};
+use crate::git::{CommitInfo, TagInfo, looks_like_commit_hash};
+use crate::github::PullRequestInfo;
+use crate::parse_input::{ParsedQuery, Query};
+use crate::release_filter::ReleaseFilter;
+
+/// Pure Bitbucket Cloud API backend.
+///
+/// Bitbucket issue trackers are rarely enabled, so `#123` resolves to a pull request.
+pub(crate) struct BitbucketBackend {
+    client: Arc<BitbucketClient>,
+    repo_info: BbRepoInfo,
+}
+
+impl BitbucketBackend {
+    /// Create a new `BitbucketBackend` for a repository.
+    ///
+    /// Returns `None` if no Bitbucket client can be created.
+    #[must_use]
+    pub(crate) fn new(repo_info: BbRepoInfo) -> Option<Self> {
+        let client = BitbucketClient::new()?;
+        Some(Self::with_client(Arc::new(client), repo_info))
+    }
+
+    /// Create a `BitbucketBackend` with a shared client.
+    #[must_use]
+    pub(crate) const fn with_client(client: Arc<BitbucketClient>, repo_info: BbRepoInfo) -> Self {
+        Self { client, repo_info }
+    }
+}
+
+#[async_trait]
+impl Backend for BitbucketBackend {
+    // ============================================
+    // Commit operations
+    // ============================================
+
+    async fn find_commit(&self, hash: &str) -> WtgResult<CommitInfo> {
+        self.client
+            .fetch_commit(&self.repo_info, hash)
+            .await
+            .ok_or_else(|| WtgError::NotFound(hash.to_string()))
+    }
+
+    async fn enrich_commit(&self, mut commit: CommitInfo) -> CommitInfo {
+        if commit.commit_url.is_none() {
+            commit.commit_url = self.commit_url(&commit.hash);
+        }
+        commit
+    }
+
+    // ============================================
+    // Pull request operations
+    // ============================================
+
+    async fn fetch_pr(&self, number: u64) -> WtgResult<PullRequestInfo> {
+        self.client
+            .fetch_pull_request(&self.repo_info, number)
+            .await
+            .ok_or_else(|| WtgError::NotFound(format!("PR #{number}")))
+    }
+
+    async fn find_pr_for_commit(&self, hash: &str) -> Option<PullRequestInfo> {
+        self.client
+            .fetch_pull_request_for_commit(&self.repo_info, hash)
+            .await
+    }
+
+    // ============================================
+    // Tag operations
+    // ============================================
+
+    async fn find_tag(&self, name: &str) -> WtgResult<TagInfo> {
+        self.client
+            .fetch_tag(&self.repo_info, name)
+            .await
+            .ok_or_else(|| WtgError::NotFound(format!("Tag {name}")))
+    }
+
+    async fn find_release_for_commit(
+        &self,
+        commit_hash: &str,
+        commit_date: Option<DateTime<Utc>>,
+        filter: &ReleaseFilter,
+    ) -> Option<TagInfo> {
+        // Fast path for specific tag lookup
+        if let Some(tag_name) = filter.specific_tag() {
+            let tag = self.client.fetch_tag(&self.repo_info, tag_name).await?;
+            return self
+                .client
+                .ref_contains_commit(&self.repo_info, &tag.commit_hash, commit_hash)
+                .await
+                .then_some(tag);
+        }
+
+        let since = commit_date.unwrap_or_else(Utc::now);
+        // Bitbucket has no releases, so requiring one rules out every tag
+        let tags = filter.filter_releases(
+            filter.filter_tags(self.client.fetch_tags_since(&self.repo_info, since).await),
+        );
+
+        // Oldest first, semver tags before the rest - the same preference as local lookups
+        let (semver, other): (Vec<TagInfo>, Vec<TagInfo>) =
+            tags.into_iter().partition(TagInfo::is_semver);
+        for tag in semver.into_iter().chain(other) {
+            if self
+                .client
+                .ref_contains_commit(&self.repo_info, &tag.commit_hash, commit_hash)
+                .await
+            {
+                return Some(tag);
+            }
+        }
+
+        None
+    }
+
+    async fn disambiguate_query(&self, query: &ParsedQuery) -> WtgResult<Query> {
+        match query {
+            ParsedQuery::Resolved(Query::IssueOrPr(number)) => Ok(Query::Pr(*number)),
+            ParsedQuery::Resolved(resolved) => Ok(resolved.clone()),
+            ParsedQuery::Unknown(input) => {
+                if looks_like_commit_hash(input) && self.find_commit(input).await.is_ok() {
+                    return Ok(Query::GitCommit(input.clone()));
+                }
+                Err(WtgError::NotFound(input.clone()))
+            }
+            ParsedQuery::UnknownPath { segments, .. } => {
+                Err(WtgError::NotFound(segments.join("/")))
+            }
+        }
+    }
+
+    // ============================================
+    // URL generation
+    // ============================================
+
+    fn commit_url(&self, hash: &str) -> Option<String> {
+        Some(BitbucketClient::commit_url(&self.repo_info, hash))
+    }
+
+    fn tag_url(&self, tag: &str) -> Option<String> {
+        Some(BitbucketClient::tag_url(&self.repo_info, tag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forge_http::mock::{ForgeMock, MERGE_SHA};
+    use crate::resolution::{IdentifiedThing, resolve};
+    use serde_json::json;
+    use wiremock::MockServer;
+
+    const REPO: &str = "/repositories/ws/repo";
+
+    async fn forge() -> ForgeMock {
+        ForgeMock::start(REPO, ("Authorization", "Bearer bb-test")).await
+    }
+
+    fn backend(server: &MockServer) -> BitbucketBackend {
+        let client = BitbucketClient::with_token(&server.uri(), Some("bb-test".to_string()))
+            .expect("client");
+        BitbucketBackend::with_client(Arc::new(client), BbRepoInfo::new("ws", "repo"))
+    }
+
+    fn tag(name: &str, hash: &str, date: &str) -> serde_json::Value {
+        json!({ "name": name, "target": { "hash": hash, "date": date } })
+    }
+
+    #[tokio::test]
+    async fn resolves_pull_request_to_first_tag() {
+        let server = forge().await;
+        server.mock_get("/pullrequests/12",
+            json!({
+                "id": 12,
+                "title": "Fix the flux capacitor",
+                "description": "",
+                "state": "MERGED",
+                "links": { "html": { "href": "https://bitbucket.org/ws/repo/pull-requests/12" } },
+                "merge_commit": { "hash": MERGE_SHA },
+                "author": {
+                    "nickname": "doc",
+                    "links": { "html": { "href": "https://bitbucket.org/doc/" } }
+                },
+                "created_on": "2026-01-02T03:04:05+00:00"
+            }),
+        )
+        .await;
+        server.mock_get(&format!("/commit/{MERGE_SHA}"),
+            json!({
+                "hash": MERGE_SHA,
+                "message": "Merged in fix (pull request #12)\n\nFix the flux capacitor",
+                "date": "2026-01-03T00:00:00+00:00",
+                "author": { "raw": "Doc Brown <doc@example.com>" },
+                "links": { "html": { "href": format!("https://bitbucket.org/ws/repo/commits/{MERGE_SHA}") } }
+            }),
+        )
+        .await;
+        server
+            .mock_get(
+                "/refs/tags",
+                json!({
+                    "values": [
+                        tag("v1.1.0", "v110sha", "2026-02-01T00:00:00+00:00"),
+                        tag("v1.0.0", "v100sha", "2026-01-10T00:00:00+00:00"),
+                        tag("nightly", "nightlysha", "2026-01-05T00:00:00+00:00"),
+                        tag("v0.9.0", "v090sha", "2025-12-01T00:00:00+00:00")
+                    ]
+                }),
+            )
+            .await;
+        // v1.0.0 contains the merge commit: their merge base is the merge commit itself
+        server
+            .mock_get(
+                &format!("/merge-base/{MERGE_SHA}..v100sha"),
+                json!({ "hash": MERGE_SHA }),
+            )
+            .await;
+
+        let backend = backend(&server);
+        let query = backend
+            .disambiguate_query(&ParsedQuery::Resolved(Query::IssueOrPr(12)))
+            .await
+            .expect("disambiguate");
+        assert_eq!(query, Query::Pr(12));
+
+        let result = resolve(&backend, &query, &ReleaseFilter::default())
+            .await
+            .expect("resolve");
+        let IdentifiedThing::Enriched(info) = result else {
+            panic!("Expected Enriched result");
+        };
+
+        let pr = info.pr.expect("pr");
+        assert!(pr.merged);
+        assert_eq!(pr.state, "merged");
+        assert_eq!(pr.body, None);
+        assert_eq!(pr.author_url.as_deref(), Some("https://bitbucket.org/doc/"));
+
+        let commit = info.commit.expect("commit");
+        assert_eq!(commit.hash, MERGE_SHA);
+        assert_eq!(commit.author_name, "Doc Brown");
+        assert_eq!(commit.author_email.as_deref(), Some("doc@example.com"));
+
+        let release = info.release.expect("release");
+        assert_eq!(release.name, "v1.0.0");
+        assert!(!release.is_release);
+        assert_eq!(
+            release.tag_url.as_deref(),
+            Some("https://bitbucket.org/ws/repo/src/v1.0.0")
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_pull_request_is_not_found() {
+        let server = forge().await;
+        let err = backend(&server).fetch_pr(404).await.unwrap_err();
+        assert!(err.is_not_found());
+    }
+}
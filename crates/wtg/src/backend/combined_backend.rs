@@ -5,6 +5,7 @@
 //! - Files: Local git only (API would be too slow)
 //! - PRs/Issues: GitHub API only
 //! - Releases: Local tags + GitHub API for metadata
+//! - Branches: Local branches first, fallback to API
 
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -12,12 +13,16 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 
+use crate::backend::tag_selection::{pick_best_tag, pick_release_at, pick_release_lines};
 use crate::backend::{Backend, git_backend::GitBackend, github_backend::GitHubBackend};
+use crate::branch_filter::BranchFilter;
 use crate::error::{WtgError, WtgResult};
-use crate::git::{CommitInfo, FileInfo, GitRepo, TagInfo};
+use crate::git::{
+    BlameHunk, BranchInfo, CommitInfo, FileChange, FileInfo, GitRepo, PathsInfo, TagInfo,
+};
 use crate::github::{ExtendedIssueInfo, PullRequestInfo};
 use crate::notice::{Notice, NoticeCallback, no_notices};
-use crate::parse_input::{ParsedQuery, Query};
+use crate::parse_input::{LineRange, ParsedQuery, Query};
 use crate::release_filter::ReleaseFilter;
 
 /// Combined backend using both local git and GitHub API.
@@ -34,6 +39,8 @@ pub(crate) struct CombinedBackend {
     git: GitBackend,
     github: GitHubBackend,
     notice_cb: NoticeCallback,
+    /// Whether repositories of cross-project PRs are cloned, or only queried via the API
+    clone_remotes: bool,
 }
 
 impl CombinedBackend {
@@ -44,9 +51,15 @@ impl CombinedBackend {
             git,
             github,
             notice_cb: no_notices(),
+            clone_remotes: true,
         }
     }
 
+    /// Set whether repositories of cross-project PRs are cloned.
+    pub(crate) const fn set_clone_remotes(&mut self, clone: bool) {
+        self.clone_remotes = clone;
+    }
+
     /// Set the notice callback for emitting operational messages.
     pub(crate) fn set_notice_callback(&mut self, cb: NoticeCallback) {
         self.notice_cb = cb.clone();
@@ -67,7 +80,6 @@ impl CombinedBackend {
     /// 3. Enrich candidates with GitHub release metadata
     /// 4. Pick best tag (prefer semver releases)
     /// 5. If no local candidates, fall back to GitHub API release search
-    #[allow(clippy::too_many_lines)]
     async fn find_release_combined(
         &self,
         commit_hash: &str,
@@ -101,56 +113,11 @@ impl CombinedBackend {
                 .await;
         }
 
-        // Get local tag candidates (ensure_tags is called internally)
-        let candidates = repo.tags_containing_commit(commit_hash);
-
-        // Apply filter to candidates
-        let filtered_candidates = filter.filter_tags(candidates);
-
-        let has_semver = filtered_candidates.iter().any(TagInfo::is_semver);
-
-        // Build timestamp map for sorting
-        let timestamps: HashMap<String, i64> = filtered_candidates
-            .iter()
-            .map(|tag| {
-                (
-                    tag.commit_hash.clone(),
-                    repo.get_commit_timestamp(&tag.commit_hash),
-                )
-            })
-            .collect();
-
-        // Enrich candidates with release metadata from GitHub
-        let mut enriched_candidates = filtered_candidates.clone();
-        if !filtered_candidates.is_empty() {
-            let target_names: Vec<_> = if has_semver {
-                filtered_candidates
-                    .iter()
-                    .filter(|c| c.is_semver())
-                    .map(|c| c.name.clone())
-                    .collect()
-            } else {
-                filtered_candidates.iter().map(|c| c.name.clone()).collect()
-            };
-
-            for tag_name in &target_names {
-                if let Some(release) = client.fetch_release_by_tag(gh_repo_info, tag_name).await {
-                    // Find the candidate with matching name and enrich it
-                    if let Some(candidate) =
-                        enriched_candidates.iter_mut().find(|c| &c.name == tag_name)
-                    {
-                        candidate.is_release = true;
-                        candidate.release_name.clone_from(&release.name);
-                        candidate.release_url = Some(release.url.clone());
-                        candidate.published_at = release.published_at;
-                        candidate.tag_url = Some(release.url);
-                    }
-                }
-            }
-        }
+        let (candidates, timestamps) = self.enriched_local_candidates(commit_hash, filter).await;
+        let has_semver = candidates.iter().any(TagInfo::is_semver);
 
         // Pick best from local candidates
-        let local_best = Self::pick_best_tag(&enriched_candidates, &timestamps);
+        let local_best = pick_best_tag(&candidates, &timestamps);
 
         // If we have a semver tag, prefer it
         if has_semver {
@@ -158,18 +125,17 @@ impl CombinedBackend {
         }
 
         // Otherwise, try fetching releases from API as fallback
-        let skip_prereleases = filter.skips_prereleases();
-        if filtered_candidates.is_empty()
+        if candidates.is_empty()
             && let Some(since) = commit_date
         {
             let releases = client.fetch_releases_since(gh_repo_info, since).await;
             let mut api_candidates: Vec<TagInfo> = Vec::new();
 
             for release in releases {
-                // Early filter: skip prereleases BEFORE making expensive API calls.
-                // This is intentionally separate from filter_tags() which operates on
-                // already-fetched TagInfo objects.
-                if skip_prereleases && release.prerelease {
+                // Early filter: skip prereleases and other components BEFORE making
+                // expensive API calls. This is intentionally separate from filter_tags()
+                // which operates on already-fetched TagInfo objects.
+                if !filter.allows_release(&release.tag_name, release.prerelease) {
                     continue;
                 }
 
@@ -205,46 +171,74 @@ impl CombinedBackend {
                         )
                     })
                     .collect();
-                return Self::pick_best_tag(&api_candidates, &api_timestamps);
+                return pick_best_tag(&api_candidates, &api_timestamps);
             }
         }
 
         local_best
     }
 
-    /// Pick the best tag from candidates based on priority rules.
-    fn pick_best_tag(candidates: &[TagInfo], timestamps: &HashMap<String, i64>) -> Option<TagInfo> {
-        fn select_with_pred<F>(
-            candidates: &[TagInfo],
-            timestamps: &HashMap<String, i64>,
-            predicate: F,
-        ) -> Option<TagInfo>
-        where
-            F: Fn(&TagInfo) -> bool,
-        {
-            candidates
-                .iter()
-                .filter(|tag| predicate(tag))
-                .min_by_key(|tag| {
-                    timestamps
-                        .get(&tag.commit_hash)
-                        .copied()
-                        .unwrap_or(i64::MAX)
-                })
-                .cloned()
-        }
+    /// Local tags containing a commit that pass the filter, with their commit
+    /// timestamps. Semver candidates (or all of them, if none is semver or the
+    /// filter requires releases) are enriched with GitHub release metadata.
+    async fn enriched_local_candidates(
+        &self,
+        commit_hash: &str,
+        filter: &ReleaseFilter,
+    ) -> (Vec<TagInfo>, HashMap<String, i64>) {
+        let repo = self.git.git_repo();
+        let gh_repo_info = self.github.repo_info();
+        let client = self.github.client();
 
-        // Priority: released semver > unreleased semver > released non-semver > unreleased non-semver
-        select_with_pred(candidates, timestamps, |t| t.is_release && t.is_semver())
-            .or_else(|| {
-                select_with_pred(candidates, timestamps, |t| !t.is_release && t.is_semver())
-            })
-            .or_else(|| {
-                select_with_pred(candidates, timestamps, |t| t.is_release && !t.is_semver())
-            })
-            .or_else(|| {
-                select_with_pred(candidates, timestamps, |t| !t.is_release && !t.is_semver())
+        // Get local tag candidates (ensure_tags is called internally)
+        let candidates = repo.tags_containing_commit(commit_hash);
+
+        // Apply filter to candidates
+        let filtered_candidates = filter.filter_tags(candidates);
+
+        let has_semver = filtered_candidates.iter().any(TagInfo::is_semver);
+
+        // Build timestamp map for sorting
+        let timestamps: HashMap<String, i64> = filtered_candidates
+            .iter()
+            .map(|tag| {
+                (
+                    tag.commit_hash.clone(),
+                    repo.get_commit_timestamp(&tag.commit_hash),
+                )
             })
+            .collect();
+
+        // Enrich candidates with release metadata from GitHub
+        let mut enriched_candidates = filtered_candidates.clone();
+        if !filtered_candidates.is_empty() {
+            let target_names: Vec<_> = if has_semver && !filter.requires_release() {
+                filtered_candidates
+                    .iter()
+                    .filter(|c| c.is_semver())
+                    .map(|c| c.name.clone())
+                    .collect()
+            } else {
+                filtered_candidates.iter().map(|c| c.name.clone()).collect()
+            };
+
+            for tag_name in &target_names {
+                if let Some(release) = client.fetch_release_by_tag(gh_repo_info, tag_name).await {
+                    // Find the candidate with matching name and enrich it
+                    if let Some(candidate) =
+                        enriched_candidates.iter_mut().find(|c| &c.name == tag_name)
+                    {
+                        candidate.is_release = true;
+                        candidate.release_name.clone_from(&release.name);
+                        candidate.release_url = Some(release.url.clone());
+                        candidate.published_at = release.published_at;
+                        candidate.tag_url = Some(release.url);
+                    }
+                }
+            }
+        }
+
+        (filter.filter_releases(enriched_candidates), timestamps)
     }
 }
 
@@ -254,6 +248,10 @@ impl Backend for CombinedBackend {
         self.emit(notice);
     }
 
+    fn is_rate_limited(&self) -> bool {
+        self.github.is_rate_limited()
+    }
+
     async fn backend_for_pr(&self, pr: &PullRequestInfo) -> Option<Box<dyn Backend>> {
         let pr_repo = pr.repo_info.as_ref()?;
         let our_repo = self.github.repo_info();
@@ -265,6 +263,9 @@ impl Backend for CombinedBackend {
 
         // Create GitHubBackend with shared client
         let github = GitHubBackend::with_client(Arc::clone(self.github.client()), pr_repo.clone());
+        if !self.clone_remotes {
+            return Some(Box::new(github));
+        }
 
         // Try to create GitRepo for cross-project git operations
         match GitRepo::remote(pr_repo.clone()) {
@@ -299,6 +300,11 @@ impl Backend for CombinedBackend {
         }
     }
 
+    async fn find_equivalent_commits(&self, hash: &str) -> Vec<CommitInfo> {
+        // Cherry-pick detection needs the commit graph - local git only
+        self.git.find_equivalent_commits(hash).await
+    }
+
     async fn enrich_commit(&self, mut commit: CommitInfo) -> CommitInfo {
         // Already enriched?
         if commit.commit_url.is_some() && commit.author_url.is_some() {
@@ -345,6 +351,29 @@ impl Backend for CombinedBackend {
         self.git.find_file(branch, path).await
     }
 
+    async fn file_history(
+        &self,
+        branch: &str,
+        path: &str,
+        skip: usize,
+        limit: usize,
+    ) -> WtgResult<Vec<FileChange>> {
+        self.git.file_history(branch, path, skip, limit).await
+    }
+
+    async fn blame_lines(
+        &self,
+        branch: &str,
+        path: &str,
+        lines: LineRange,
+    ) -> WtgResult<Vec<BlameHunk>> {
+        self.git.blame_lines(branch, path, lines).await
+    }
+
+    async fn find_paths(&self, branch: &str, pattern: &str) -> WtgResult<PathsInfo> {
+        self.git.find_paths(branch, pattern).await
+    }
+
     // ============================================
     // Tag/Release operations - combined
     // ============================================
@@ -404,6 +433,58 @@ impl Backend for CombinedBackend {
             .await
     }
 
+    async fn find_releases_for_commit(
+        &self,
+        commit_hash: &str,
+        commit_date: Option<DateTime<Utc>>,
+        filter: &ReleaseFilter,
+    ) -> Vec<TagInfo> {
+        if filter.specific_tag().is_none() {
+            let (candidates, timestamps) =
+                self.enriched_local_candidates(commit_hash, filter).await;
+            if !candidates.is_empty() {
+                return pick_release_lines(&candidates, &timestamps);
+            }
+        }
+        // Specific tags and API-only lookups have a single answer
+        self.find_release_combined(commit_hash, commit_date, filter)
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    async fn find_release_at(
+        &self,
+        at: DateTime<Utc>,
+        filter: &ReleaseFilter,
+    ) -> WtgResult<Option<TagInfo>> {
+        let mut candidates = filter.filter_tags(self.git.git_repo().get_tags());
+
+        // GitHub knows which tags are releases. Its answer is the best release,
+        // so the other releases don't need to be marked to pick the right tag.
+        match self.github.find_release_at(at, filter).await {
+            Ok(Some(release)) => match candidates.iter_mut().find(|t| t.name == release.name) {
+                Some(local) => {
+                    local.is_release = true;
+                    local.release_name = release.release_name;
+                    local.release_url = release.release_url;
+                    local.published_at = release.published_at;
+                    local.tag_url = release.tag_url;
+                }
+                None => candidates.push(release),
+            },
+            Ok(None) => {}
+            Err(e) => log::debug!("GitHub release lookup by date failed: {e:?}"),
+        }
+
+        let candidates = filter.filter_releases(candidates);
+        let Some(best) = pick_release_at(&candidates, at) else {
+            return Ok(None);
+        };
+        // Plain tags still get their tree URL
+        self.find_tag(&best.name).await.map(Some)
+    }
+
     async fn fetch_release_body(&self, tag_name: &str) -> Option<String> {
         self.github.fetch_release_body(tag_name).await
     }
@@ -417,13 +498,33 @@ impl Backend for CombinedBackend {
         self.github.changelog_for_version(version).await
     }
 
+    async fn branches_containing_commit(
+        &self,
+        commit_hash: &str,
+        filter: &BranchFilter,
+    ) -> Option<Vec<BranchInfo>> {
+        // Try git first (graph walk), fall back to the compare API
+        if let Some(branches) = self
+            .git
+            .branches_containing_commit(commit_hash, filter)
+            .await
+        {
+            return Some(branches);
+        }
+        self.github
+            .branches_containing_commit(commit_hash, filter)
+            .await
+    }
+
     async fn disambiguate_query(&self, query: &ParsedQuery) -> WtgResult<Query> {
         match query {
             ParsedQuery::Resolved(resolved) => Ok(resolved.clone()),
             ParsedQuery::Unknown(input) => {
                 // Try git disambiguation first (tag, file, commit)
-                if let Ok(q) = self.git.disambiguate_query(query).await {
-                    return Ok(q);
+                match self.git.disambiguate_query(query).await {
+                    Ok(q) => return Ok(q),
+                    Err(err @ WtgError::MultipleMatches(_)) => return Err(err),
+                    Err(_) => {}
                 }
                 // Fall back: treat numeric input as issue/PR number
                 if let Ok(number) = input.parse::<u64>() {
@@ -447,6 +548,10 @@ impl Backend for CombinedBackend {
         self.github.fetch_pr(number).await
     }
 
+    async fn find_pr_for_commit(&self, hash: &str) -> Option<PullRequestInfo> {
+        self.github.find_pr_for_commit(hash).await
+    }
+
     // ============================================
     // URL generation - delegate to GitHub backend
     // ============================================
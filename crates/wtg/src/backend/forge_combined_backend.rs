@@ -0,0 +1,420 @@
+//! Combined backend pairing local git with a non-GitHub forge API backend.
+//!
+//! Same split as `CombinedBackend`, expressed purely in terms of the `Backend`
+//! trait so any forge backend (e.g. `GitLabBackend`) can be plugged in:
+//! - Commits: Local git first (fast), fallback to the forge API
+//! - Files: Local git only
+//! - PRs/Issues: Forge API only
+//! - Releases: Local tags + forge API for release metadata
+//! - Branches: Local branches first, fallback to the forge API
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::backend::tag_selection::{pick_best_tag, pick_release_at, pick_release_lines};
+use crate::backend::{Backend, git_backend::GitBackend};
+use crate::branch_filter::BranchFilter;
+use crate::error::{WtgError, WtgResult};
+use crate::git::{BlameHunk, BranchInfo, CommitInfo, FileChange, FileInfo, PathsInfo, TagInfo};
+use crate::github::{ExtendedIssueInfo, PullRequestInfo};
+use crate::notice::{Notice, NoticeCallback, no_notices};
+use crate::parse_input::{LineRange, ParsedQuery, Query};
+use crate::release_filter::ReleaseFilter;
+
+/// Combined backend using local git and a forge API backend `F`.
+pub(crate) struct ForgeCombinedBackend<F> {
+    git: GitBackend,
+    forge: F,
+    notice_cb: NoticeCallback,
+}
+
+impl<F: Backend> ForgeCombinedBackend<F> {
+    /// Create a new `ForgeCombinedBackend` from git and forge backends.
+    #[must_use]
+    pub(crate) fn new(git: GitBackend, forge: F) -> Self {
+        Self {
+            git,
+            forge,
+            notice_cb: no_notices(),
+        }
+    }
+
+    /// Set the notice callback for emitting operational messages.
+    pub(crate) fn set_notice_callback(&mut self, cb: NoticeCallback) {
+        self.notice_cb = cb.clone();
+        self.git.set_notice_callback(cb);
+    }
+
+    /// Copy release metadata from the forge's view of a tag onto a local tag.
+    async fn enrich_tag(&self, tag: &mut TagInfo) {
+        match self.forge.find_tag(&tag.name).await {
+            Ok(remote) if remote.is_release => {
+                tag.is_release = true;
+                tag.release_name = remote.release_name;
+                tag.release_url = remote.release_url;
+                tag.published_at = remote.published_at;
+                tag.tag_url = remote.tag_url;
+            }
+            _ => tag.tag_url = self.forge.tag_url(&tag.name),
+        }
+    }
+
+    /// Find the best release/tag for a commit.
+    ///
+    /// Local tags decide containment; the forge only contributes release metadata.
+    /// Like `CombinedBackend`, only semver candidates are enriched when present.
+    async fn find_release_combined(
+        &self,
+        commit_hash: &str,
+        filter: &ReleaseFilter,
+    ) -> Option<TagInfo> {
+        let repo = self.git.git_repo();
+
+        // Fast path for specific tag lookup
+        if let Some(tag_name) = filter.specific_tag() {
+            let mut tag = repo.get_tags().into_iter().find(|t| t.name == tag_name)?;
+            if !repo.tag_contains_commit(&tag.commit_hash, commit_hash) {
+                return None;
+            }
+            self.enrich_tag(&mut tag).await;
+            return Some(tag);
+        }
+
+        let (candidates, timestamps) = self.enriched_candidates(commit_hash, filter).await;
+        pick_best_tag(&candidates, &timestamps)
+    }
+
+    /// Local tags containing a commit that pass the filter, enriched with release
+    /// metadata, and their commit timestamps. Every candidate is enriched when
+    /// the filter requires releases.
+    async fn enriched_candidates(
+        &self,
+        commit_hash: &str,
+        filter: &ReleaseFilter,
+    ) -> (Vec<TagInfo>, HashMap<String, i64>) {
+        let repo = self.git.git_repo();
+        let mut candidates = filter.filter_tags(repo.tags_containing_commit(commit_hash));
+        let has_semver = candidates.iter().any(TagInfo::is_semver);
+
+        for candidate in &mut candidates {
+            if !has_semver || candidate.is_semver() || filter.requires_release() {
+                self.enrich_tag(candidate).await;
+            }
+        }
+        let candidates = filter.filter_releases(candidates);
+
+        let timestamps: HashMap<String, i64> = candidates
+            .iter()
+            .map(|tag| {
+                (
+                    tag.commit_hash.clone(),
+                    repo.get_commit_timestamp(&tag.commit_hash),
+                )
+            })
+            .collect();
+
+        (candidates, timestamps)
+    }
+}
+
+#[async_trait]
+impl<F: Backend> Backend for ForgeCombinedBackend<F> {
+    fn emit_notice(&self, notice: Notice) {
+        (self.notice_cb)(notice);
+    }
+
+    fn is_rate_limited(&self) -> bool {
+        self.forge.is_rate_limited()
+    }
+
+    // ============================================
+    // Commit operations - local first, fallback to API
+    // ============================================
+
+    async fn find_commit(&self, hash: &str) -> WtgResult<CommitInfo> {
+        match self.git.find_commit(hash).await {
+            Ok(commit) => Ok(commit),
+            Err(WtgError::NotFound(_)) => self.forge.find_commit(hash).await,
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn find_equivalent_commits(&self, hash: &str) -> Vec<CommitInfo> {
+        self.git.find_equivalent_commits(hash).await
+    }
+
+    async fn enrich_commit(&self, commit: CommitInfo) -> CommitInfo {
+        self.forge.enrich_commit(commit).await
+    }
+
+    // ============================================
+    // File operations - local only
+    // ============================================
+
+    async fn find_file(&self, branch: &str, path: &str) -> WtgResult<FileInfo> {
+        self.git.find_file(branch, path).await
+    }
+
+    async fn file_history(
+        &self,
+        branch: &str,
+        path: &str,
+        skip: usize,
+        limit: usize,
+    ) -> WtgResult<Vec<FileChange>> {
+        self.git.file_history(branch, path, skip, limit).await
+    }
+
+    async fn blame_lines(
+        &self,
+        branch: &str,
+        path: &str,
+        lines: LineRange,
+    ) -> WtgResult<Vec<BlameHunk>> {
+        self.git.blame_lines(branch, path, lines).await
+    }
+
+    async fn find_paths(&self, branch: &str, pattern: &str) -> WtgResult<PathsInfo> {
+        self.git.find_paths(branch, pattern).await
+    }
+
+    // ============================================
+    // Tag/Release operations - combined
+    // ============================================
+
+    async fn find_tag(&self, name: &str) -> WtgResult<TagInfo> {
+        let mut tag = self.git.find_tag(name).await?;
+        self.enrich_tag(&mut tag).await;
+        Ok(tag)
+    }
+
+    async fn find_previous_tag(&self, tag_name: &str) -> WtgResult<Option<TagInfo>> {
+        self.git.find_previous_tag(tag_name).await
+    }
+
+    async fn commits_between_tags(
+        &self,
+        from_tag: &str,
+        to_tag: &str,
+        limit: usize,
+    ) -> WtgResult<Vec<CommitInfo>> {
+        self.git.commits_between_tags(from_tag, to_tag, limit).await
+    }
+
+    async fn find_release_for_commit(
+        &self,
+        commit_hash: &str,
+        _commit_date: Option<DateTime<Utc>>,
+        filter: &ReleaseFilter,
+    ) -> Option<TagInfo> {
+        self.find_release_combined(commit_hash, filter).await
+    }
+
+    async fn find_releases_for_commit(
+        &self,
+        commit_hash: &str,
+        _commit_date: Option<DateTime<Utc>>,
+        filter: &ReleaseFilter,
+    ) -> Vec<TagInfo> {
+        if filter.specific_tag().is_some() {
+            return self
+                .find_release_combined(commit_hash, filter)
+                .await
+                .into_iter()
+                .collect();
+        }
+        let (candidates, timestamps) = self.enriched_candidates(commit_hash, filter).await;
+        pick_release_lines(&candidates, &timestamps)
+    }
+
+    async fn find_release_at(
+        &self,
+        at: DateTime<Utc>,
+        filter: &ReleaseFilter,
+    ) -> WtgResult<Option<TagInfo>> {
+        let mut candidates = filter.filter_tags(self.git.git_repo().get_tags());
+        // Releases are only known after asking the forge about every candidate
+        if filter.requires_release() {
+            for candidate in &mut candidates {
+                self.enrich_tag(candidate).await;
+            }
+        }
+
+        let Some(mut best) = pick_release_at(&filter.filter_releases(candidates), at) else {
+            return Ok(None);
+        };
+        self.enrich_tag(&mut best).await;
+        Ok(Some(best))
+    }
+
+    async fn fetch_release_body(&self, tag_name: &str) -> Option<String> {
+        self.forge.fetch_release_body(tag_name).await
+    }
+
+    async fn changelog_for_version(&self, version: &str) -> Option<String> {
+        if let Some(content) = self.git.changelog_for_version(version).await {
+            return Some(content);
+        }
+        self.forge.changelog_for_version(version).await
+    }
+
+    async fn branches_containing_commit(
+        &self,
+        commit_hash: &str,
+        filter: &BranchFilter,
+    ) -> Option<Vec<BranchInfo>> {
+        if let Some(branches) = self
+            .git
+            .branches_containing_commit(commit_hash, filter)
+            .await
+        {
+            return Some(branches);
+        }
+        self.forge
+            .branches_containing_commit(commit_hash, filter)
+            .await
+    }
+
+    async fn disambiguate_query(&self, query: &ParsedQuery) -> WtgResult<Query> {
+        match query {
+            // The forge knows how its own references are numbered
+            ParsedQuery::Resolved(_) => self.forge.disambiguate_query(query).await,
+            ParsedQuery::Unknown(input) => {
+                match self.git.disambiguate_query(query).await {
+                    Ok(q) => return Ok(q),
+                    Err(err @ WtgError::MultipleMatches(_)) => return Err(err),
+                    Err(_) => {}
+                }
+                if let Ok(number) = input.parse::<u64>() {
+                    return self
+                        .forge
+                        .disambiguate_query(&ParsedQuery::Resolved(Query::IssueOrPr(number)))
+                        .await;
+                }
+                Err(WtgError::NotFound(input.clone()))
+            }
+            ParsedQuery::UnknownPath { .. } => self.git.disambiguate_query(query).await,
+        }
+    }
+
+    // ============================================
+    // Issue/PR operations - forge API only
+    // ============================================
+
+    async fn fetch_issue(&self, number: u64) -> WtgResult<ExtendedIssueInfo> {
+        self.forge.fetch_issue(number).await
+    }
+
+    async fn fetch_pr(&self, number: u64) -> WtgResult<PullRequestInfo> {
+        self.forge.fetch_pr(number).await
+    }
+
+    async fn find_pr_for_commit(&self, hash: &str) -> Option<PullRequestInfo> {
+        self.forge.find_pr_for_commit(hash).await
+    }
+
+    // ============================================
+    // URL generation - delegate to forge backend
+    // ============================================
+
+    fn commit_url(&self, hash: &str) -> Option<String> {
+        self.forge.commit_url(hash)
+    }
+
+    fn tag_url(&self, tag: &str) -> Option<String> {
+        self.forge.tag_url(tag)
+    }
+
+    fn release_tag_url(&self, tag: &str) -> Option<String> {
+        self.forge.release_tag_url(tag)
+    }
+
+    fn author_url_from_email(&self, email: &str) -> Option<String> {
+        self.forge.author_url_from_email(email)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use git2::{Repository, Signature};
+    use serde_json::json;
+    use tempfile::tempdir;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::backend::GitLabBackend;
+    use crate::git::GitRepo;
+    use crate::gitlab::{GitLabClient, GlRepoInfo};
+
+    #[tokio::test]
+    async fn local_tags_get_release_metadata_from_forge() {
+        let temp = tempdir().expect("temp dir");
+        let commit_hash = {
+            let repo = Repository::init(temp.path()).expect("git repo");
+            let sig = Signature::now("Test User", "tester@example.com").expect("sig");
+            let tree_oid = repo.index().expect("index").write_tree().expect("tree");
+            let tree = repo.find_tree(tree_oid).expect("tree");
+            let oid = repo
+                .commit(Some("HEAD"), &sig, &sig, "Initial commit", &tree, &[])
+                .expect("commit");
+            let target = repo.find_object(oid, None).expect("object");
+            repo.tag_lightweight("v1.0.0", &target, false).expect("tag");
+            oid.to_string()
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/api/v4/projects/group%2Fproject/repository/tags/v1.0.0",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "name": "v1.0.0",
+                "commit": { "id": commit_hash, "authored_date": "2026-01-10T00:00:00Z" },
+                "release": { "tag_name": "v1.0.0" }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v4/projects/group%2Fproject/releases/v1.0.0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "tag_name": "v1.0.0",
+                "name": "First light",
+                "description": "Hello",
+                "released_at": "2026-01-11T00:00:00Z"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = GitLabClient::with_token(&server.uri(), None).expect("client");
+        let gitlab = GitLabBackend::with_client(
+            Arc::new(client),
+            GlRepoInfo::new(&server.uri(), "group/project"),
+        );
+        let git = GitBackend::new(GitRepo::from_path(temp.path()).expect("open repo"));
+        let backend = ForgeCombinedBackend::new(git, gitlab);
+
+        let release = backend
+            .find_release_for_commit(&commit_hash, None, &ReleaseFilter::default())
+            .await
+            .expect("release");
+        assert_eq!(release.name, "v1.0.0");
+        assert!(release.is_release);
+        assert_eq!(release.release_name.as_deref(), Some("First light"));
+        assert_eq!(
+            release.release_url,
+            Some(format!("{}/group/project/-/releases/v1.0.0", server.uri()))
+        );
+
+        // Bare numbers fall through to the forge's own numbering rules
+        let query = backend
+            .disambiguate_query(&ParsedQuery::Unknown("5".to_string()))
+            .await
+            .expect("disambiguate");
+        assert_eq!(query, Query::Issue(5));
+    }
+}
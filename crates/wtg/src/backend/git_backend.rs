@@ -7,12 +7,17 @@ use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use super::tag_selection::{pick_best_tag, pick_release_at, pick_release_lines};
 use super::{Backend, NoticeCallback};
+use crate::branch_filter::BranchFilter;
 use crate::changelog;
 use crate::error::{WtgError, WtgResult};
-use crate::git::{CommitInfo, FileInfo, GitRepo, TagInfo, looks_like_commit_hash};
-use crate::github::GitHubClient;
-use crate::parse_input::{ParsedQuery, Query};
+use crate::git::{
+    BlameHunk, BranchInfo, CommitInfo, FileChange, FileInfo, GitRepo, PathsInfo, TagInfo,
+    looks_like_commit_hash,
+};
+use crate::github::{GITHUB_COM, GitHubClient};
+use crate::parse_input::{Candidate, LineRange, ParsedQuery, Query, split_line_range};
 use crate::release_filter::ReleaseFilter;
 
 /// Pure local git backend wrapping a `GitRepo`.
@@ -62,20 +67,24 @@ impl GitBackend {
             return None;
         }
 
-        let candidates = self.repo.tags_containing_commit(commit_hash);
-        if candidates.is_empty() {
-            return None;
-        }
+        let (candidates, timestamps) = self.tag_candidates(commit_hash, filter);
 
-        // Apply filter to candidates
-        let filtered = filter.filter_tags(candidates);
+        // Pick best tag: prefer semver releases, then semver, then any release, then any
+        pick_best_tag(&candidates, &timestamps)
+    }
 
-        if filtered.is_empty() {
-            return None;
-        }
+    /// Tags containing a commit that pass the filter, with their commit timestamps.
+    fn tag_candidates(
+        &self,
+        commit_hash: &str,
+        filter: &ReleaseFilter,
+    ) -> (Vec<TagInfo>, HashMap<String, i64>) {
+        // Local tags carry no release metadata, so requiring a release rules them all out
+        let candidates = filter
+            .filter_releases(filter.filter_tags(self.repo.tags_containing_commit(commit_hash)));
 
         // Build timestamp map for sorting
-        let timestamps: HashMap<String, i64> = filtered
+        let timestamps = candidates
             .iter()
             .map(|tag| {
                 (
@@ -85,74 +94,141 @@ impl GitBackend {
             })
             .collect();
 
-        // Pick best tag: prefer semver releases, then semver, then any release, then any
-        Self::pick_best_tag(&filtered, &timestamps)
+        (candidates, timestamps)
+    }
+
+    /// Pick the only reading of `input`, or report all of them if there are several.
+    fn disambiguate_input_string(&self, input: &str) -> WtgResult<Query> {
+        let mut candidates = self.candidates(input);
+        match candidates.len() {
+            0 => Err(WtgError::NotFound(input.to_string())),
+            1 => Ok(candidates.remove(0).query),
+            _ => Err(WtgError::MultipleMatches(candidates)),
+        }
     }
 
-    /// Pick the best tag from candidates based on priority rules.
-    fn pick_best_tag(candidates: &[TagInfo], timestamps: &HashMap<String, i64>) -> Option<TagInfo> {
-        fn select_with_pred<F>(
-            candidates: &[TagInfo],
-            timestamps: &HashMap<String, i64>,
-            predicate: F,
-        ) -> Option<TagInfo>
-        where
-            F: Fn(&TagInfo) -> bool,
+    /// Every way `input` can be read in this repository.
+    fn candidates(&self, input: &str) -> Vec<Candidate> {
+        let mut candidates = Vec::new();
+
+        if let Some(tag) = self
+            .repo
+            .get_tags()
+            .into_iter()
+            .find(|tag| tag.name == input)
         {
-            candidates
-                .iter()
-                .filter(|tag| predicate(tag))
-                .min_by_key(|tag| {
-                    timestamps
-                        .get(&tag.commit_hash)
-                        .copied()
-                        .unwrap_or(i64::MAX)
-                })
-                .cloned()
+            let preview = self.repo.find_commit_local(&tag.commit_hash).map_or_else(
+                || input.to_string(),
+                |commit| format!("{input} → {}", commit_preview(&commit)),
+            );
+            candidates.push(Candidate {
+                query: Query::Tag(input.to_string()),
+                kind: "tag",
+                preview,
+            });
         }
 
-        // Priority: released semver > unreleased semver > released non-semver > unreleased non-semver
-        select_with_pred(candidates, timestamps, |t| t.is_release && t.is_semver())
-            .or_else(|| {
-                select_with_pred(candidates, timestamps, |t| !t.is_release && t.is_semver())
-            })
-            .or_else(|| {
-                select_with_pred(candidates, timestamps, |t| t.is_release && !t.is_semver())
-            })
-            .or_else(|| {
-                select_with_pred(candidates, timestamps, |t| !t.is_release && !t.is_semver())
-            })
-    }
+        // `src/backend/` or `src/backend`
+        let dir = input.trim_end_matches('/');
+        if !dir.is_empty() && self.repo.is_dir_at_head(dir) {
+            candidates.push(Candidate {
+                query: Query::PathGlob {
+                    branch: "HEAD".to_string(),
+                    pattern: format!("{dir}/**"),
+                },
+                kind: "directory",
+                preview: format!("{dir}/"),
+            });
+        } else if self.repo.has_path_at_head(input) {
+            candidates.push(Candidate {
+                query: Query::FilePath {
+                    branch: "HEAD".to_string(),
+                    path: PathBuf::from(input),
+                },
+                kind: "file",
+                preview: input.to_string(),
+            });
+        }
 
-    fn disambiguate_input_string(&self, input: &str) -> WtgResult<Query> {
-        if self.repo.get_tags().iter().any(|tag| tag.name == input) {
-            return Ok(Query::Tag(input.to_string()));
+        // `path:42` or `path:120-140`
+        if let Some((path, lines)) = split_line_range(input)
+            && self.repo.has_path_at_head(path)
+        {
+            candidates.push(Candidate {
+                query: Query::FileLines {
+                    branch: "HEAD".to_string(),
+                    path: PathBuf::from(path),
+                    lines,
+                },
+                kind: "lines",
+                preview: format!("lines {lines} of {path}"),
+            });
         }
 
-        if self.repo.has_path_at_head(input) {
-            return Ok(Query::FilePath {
-                branch: "HEAD".to_string(),
-                path: PathBuf::from(input),
+        let commit = looks_like_commit_hash(input)
+            .then(|| self.repo.find_commit_local(input))
+            .flatten();
+
+        // A branch pointing at the very commit the input abbreviates is the same answer
+        if let Some(tip) = self.repo.branch_tip(input)
+            && commit.as_ref().is_none_or(|commit| commit.hash != tip.hash)
+        {
+            candidates.push(Candidate {
+                preview: format!("{input} → {}", commit_preview(&tip)),
+                query: Query::GitCommit(tip.hash),
+                kind: "branch",
             });
         }
 
-        if looks_like_commit_hash(input) && self.repo.find_commit_local(input).is_some() {
-            return Ok(Query::GitCommit(input.to_string()));
+        if let Some(commit) = commit {
+            candidates.push(Candidate {
+                query: Query::GitCommit(input.to_string()),
+                kind: "commit",
+                preview: commit_preview(&commit),
+            });
+        }
+
+        // `crates/*/Cargo.toml`
+        if input.contains(['*', '?', '[']) && self.repo.has_paths_matching_at_head(input) {
+            candidates.push(Candidate {
+                query: Query::PathGlob {
+                    branch: "HEAD".to_string(),
+                    pattern: input.to_string(),
+                },
+                kind: "glob",
+                preview: input.to_string(),
+            });
         }
 
-        Err(WtgError::NotFound(input.to_string()))
+        candidates
     }
 
-    fn disambiguate_unknown_path(&self, segments: &[String]) -> Option<Query> {
+    fn disambiguate_unknown_path(
+        &self,
+        segments: &[String],
+        lines: Option<LineRange>,
+    ) -> Option<Query> {
         let (branch, remainder) = self.repo.find_branch_path_match(segments)?;
         let mut path = PathBuf::new();
         for segment in remainder {
             path.push(segment);
         }
-        Some(Query::FilePath { branch, path })
+        Some(match lines {
+            Some(lines) => Query::FileLines {
+                branch,
+                path,
+                lines,
+            },
+            None => Query::FilePath { branch, path },
+        })
     }
 }
 
+/// `abc1234 Subject line` for picking between interpretations.
+fn commit_preview(commit: &CommitInfo) -> String {
+    format!("{} {}", commit.short_hash, commit.message)
+}
+
 #[async_trait]
 impl Backend for GitBackend {
     // Note: backend_for_pr() uses default (returns None) since GitBackend
@@ -179,6 +255,10 @@ impl Backend for GitBackend {
         commit
     }
 
+    async fn find_equivalent_commits(&self, hash: &str) -> Vec<CommitInfo> {
+        self.repo.find_equivalent_commits(hash)
+    }
+
     // ============================================
     // File operations
     // ============================================
@@ -189,6 +269,33 @@ impl Backend for GitBackend {
             .ok_or_else(|| WtgError::NotFound(path.to_string()))
     }
 
+    async fn file_history(
+        &self,
+        branch: &str,
+        path: &str,
+        skip: usize,
+        limit: usize,
+    ) -> WtgResult<Vec<FileChange>> {
+        self.repo
+            .file_history(branch, path, skip, limit)
+            .ok_or_else(|| WtgError::NotFound(path.to_string()))
+    }
+
+    async fn blame_lines(
+        &self,
+        branch: &str,
+        path: &str,
+        lines: LineRange,
+    ) -> WtgResult<Vec<BlameHunk>> {
+        self.repo.blame_lines(branch, path, lines)
+    }
+
+    async fn find_paths(&self, branch: &str, pattern: &str) -> WtgResult<PathsInfo> {
+        self.repo
+            .find_paths_on_branch(branch, pattern)
+            .ok_or_else(|| WtgError::NotFound(pattern.to_string()))
+    }
+
     // ============================================
     // Tag/Release operations
     // ============================================
@@ -209,9 +316,16 @@ impl Backend for GitBackend {
             return Ok(None);
         };
 
-        // If current is semver, find previous by semver ordering
-        if current.is_semver() {
-            let mut semver_tags: Vec<_> = tags.iter().filter(|t| t.is_semver()).collect();
+        // If current is semver, find previous by semver ordering within its component
+        if let Some(current_semver) = &current.semver_info {
+            let mut semver_tags: Vec<_> = tags
+                .iter()
+                .filter(|t| {
+                    t.semver_info
+                        .as_ref()
+                        .is_some_and(|s| s.prefix == current_semver.prefix)
+                })
+                .collect();
 
             // Sort by semver (ascending)
             semver_tags.sort_by(|a, b| {
@@ -262,8 +376,8 @@ impl Backend for GitBackend {
         match query {
             ParsedQuery::Resolved(resolved) => Ok(resolved.clone()),
             ParsedQuery::Unknown(input) => self.disambiguate_input_string(input),
-            ParsedQuery::UnknownPath { segments } => self
-                .disambiguate_unknown_path(segments)
+            ParsedQuery::UnknownPath { segments, lines } => self
+                .disambiguate_unknown_path(segments, *lines)
                 .ok_or_else(|| WtgError::NotFound(segments.join("/"))),
         }
     }
@@ -277,8 +391,47 @@ impl Backend for GitBackend {
         self.find_best_tag_for_commit(commit_hash, filter)
     }
 
+    async fn find_releases_for_commit(
+        &self,
+        commit_hash: &str,
+        _commit_date: Option<DateTime<Utc>>,
+        filter: &ReleaseFilter,
+    ) -> Vec<TagInfo> {
+        if filter.specific_tag().is_some() {
+            return self
+                .find_best_tag_for_commit(commit_hash, filter)
+                .into_iter()
+                .collect();
+        }
+        let (candidates, timestamps) = self.tag_candidates(commit_hash, filter);
+        pick_release_lines(&candidates, &timestamps)
+    }
+
+    async fn find_release_at(
+        &self,
+        at: DateTime<Utc>,
+        filter: &ReleaseFilter,
+    ) -> WtgResult<Option<TagInfo>> {
+        let candidates = filter.filter_releases(filter.filter_tags(self.repo.get_tags()));
+        Ok(pick_release_at(&candidates, at))
+    }
+
     async fn changelog_for_version(&self, version: &str) -> Option<String> {
-        changelog::parse_changelog_for_version(self.repo.path(), version)
+        let root = self.repo.path();
+        self.repo.changelog_path().map_or_else(
+            || changelog::parse_changelog_for_version(root, version),
+            |path| changelog::parse_changelog_file(&root.join(path), version),
+        )
+    }
+
+    async fn branches_containing_commit(
+        &self,
+        commit_hash: &str,
+        filter: &BranchFilter,
+    ) -> Option<Vec<BranchInfo>> {
+        // A commit we don't have can't be placed on any branch
+        self.repo.find_commit_local(commit_hash)?;
+        Some(filter.filter_branches(self.repo.branches_containing_commit(commit_hash)))
     }
 
     // ============================================
@@ -304,6 +457,10 @@ impl Backend for GitBackend {
     }
 
     fn author_url_from_email(&self, email: &str) -> Option<String> {
-        GitHubClient::author_url_from_email(email)
+        let host = self
+            .repo
+            .github_remote()
+            .map_or_else(|| GITHUB_COM.to_string(), |ri| ri.host().to_string());
+        GitHubClient::author_url_from_email(&host, email)
     }
 }
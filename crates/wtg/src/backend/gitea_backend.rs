@@ -0,0 +1,418 @@
+//! Pure Gitea/Forgejo API backend implementation.
+//!
+//! This backend only uses the Gitea REST API via `GiteaClient`.
+//! It can fetch commits, pull requests, issues, tags and releases, but cannot
+//! walk file history or perform local git operations.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+use super::Backend;
+use crate::changelog;
+use crate::error::{WtgError, WtgResult};
+use crate::git::{CommitInfo, TagInfo, looks_like_commit_hash};
+use crate::gitea::{GiteaClient, GtRepoInfo};
+use crate::github::{ExtendedIssueInfo, PullRequestInfo};
+use crate::parse_input::{ParsedQuery, Query};
+use crate::release_filter::ReleaseFilter;
+
+/// Pure Gitea/Forgejo API backend.
+///
+/// Issues and pull requests share one number sequence, so `#123` is looked up
+/// to tell which one it is.
+pub(crate) struct GiteaBackend {
+    client: Arc<GiteaClient>,
+    repo_info: GtRepoInfo,
+}
+
+impl GiteaBackend {
+    /// Create a new `GiteaBackend` for a repository.
+    ///
+    /// Returns `None` if no Gitea client can be created.
+    #[must_use]
+    pub(crate) fn new(repo_info: GtRepoInfo) -> Option<Self> {
+        let client = GiteaClient::new(repo_info.base_url())?;
+        Some(Self::with_client(Arc::new(client), repo_info))
+    }
+
+    /// Create a `GiteaBackend` with a shared client.
+    #[must_use]
+    pub(crate) const fn with_client(client: Arc<GiteaClient>, repo_info: GtRepoInfo) -> Self {
+        Self { client, repo_info }
+    }
+}
+
+#[async_trait]
+impl Backend for GiteaBackend {
+    // Note: backend_for_pr() uses default (returns None) - pull requests
+    // are always resolved against the current repository.
+
+    // ============================================
+    // Commit operations
+    // ============================================
+
+    async fn find_commit(&self, hash: &str) -> WtgResult<CommitInfo> {
+        self.client
+            .fetch_commit(&self.repo_info, hash)
+            .await
+            .ok_or_else(|| WtgError::NotFound(hash.to_string()))
+    }
+
+    async fn enrich_commit(&self, mut commit: CommitInfo) -> CommitInfo {
+        if commit.commit_url.is_none() {
+            commit.commit_url = self.commit_url(&commit.hash);
+        }
+        if commit.author_url.is_none()
+            && let Some(email) = commit.author_email.as_deref()
+        {
+            commit.author_url = self.author_url_from_email(email);
+        }
+        commit
+    }
+
+    // ============================================
+    // Issue/PR operations
+    // ============================================
+
+    async fn fetch_issue(&self, number: u64) -> WtgResult<ExtendedIssueInfo> {
+        self.client
+            .fetch_issue(&self.repo_info, number)
+            .await
+            .ok_or_else(|| WtgError::NotFound(format!("Issue #{number}")))
+    }
+
+    async fn fetch_pr(&self, number: u64) -> WtgResult<PullRequestInfo> {
+        self.client
+            .fetch_pull_request(&self.repo_info, number)
+            .await
+            .ok_or_else(|| WtgError::NotFound(format!("PR #{number}")))
+    }
+
+    async fn find_pr_for_commit(&self, hash: &str) -> Option<PullRequestInfo> {
+        self.client
+            .fetch_pull_request_for_commit(&self.repo_info, hash)
+            .await
+    }
+
+    // ============================================
+    // Tag/Release operations
+    // ============================================
+
+    async fn find_tag(&self, name: &str) -> WtgResult<TagInfo> {
+        self.client
+            .fetch_tag(&self.repo_info, name)
+            .await
+            .ok_or_else(|| WtgError::NotFound(format!("Tag {name}")))
+    }
+
+    async fn find_release_for_commit(
+        &self,
+        commit_hash: &str,
+        commit_date: Option<DateTime<Utc>>,
+        filter: &ReleaseFilter,
+    ) -> Option<TagInfo> {
+        // Fast path for specific tag lookup
+        if let Some(tag_name) = filter.specific_tag() {
+            let tag = self.client.fetch_tag(&self.repo_info, tag_name).await?;
+            return self
+                .client
+                .ref_contains_commit(&self.repo_info, tag_name, commit_hash)
+                .await
+                .then_some(tag);
+        }
+
+        let since = commit_date.unwrap_or_else(Utc::now);
+        let releases = self
+            .client
+            .fetch_releases_since(&self.repo_info, since)
+            .await;
+
+        // Oldest first, so the first release containing the commit is where it shipped
+        for release in releases {
+            if !filter.allows_release(&release.tag_name, release.prerelease) {
+                continue;
+            }
+            if self
+                .client
+                .ref_contains_commit(&self.repo_info, &release.tag_name, commit_hash)
+                .await
+            {
+                return self
+                    .client
+                    .fetch_tag(&self.repo_info, &release.tag_name)
+                    .await;
+            }
+        }
+
+        None
+    }
+
+    async fn fetch_release_body(&self, tag_name: &str) -> Option<String> {
+        let release = self
+            .client
+            .fetch_release_by_tag(&self.repo_info, tag_name)
+            .await?;
+        release.body.filter(|b| !b.trim().is_empty())
+    }
+
+    async fn changelog_for_version(&self, version: &str) -> Option<String> {
+        for path in ["CHANGELOG.md", "changelog.md", "Changelog.md"] {
+            if let Some(content) = self.client.fetch_file_content(&self.repo_info, path).await
+                && let Some(section) = changelog::extract_version_section(&content, version)
+            {
+                return Some(section);
+            }
+        }
+        None
+    }
+
+    async fn disambiguate_query(&self, query: &ParsedQuery) -> WtgResult<Query> {
+        match query {
+            ParsedQuery::Resolved(Query::IssueOrPr(number)) => {
+                match self.client.is_pull_request(&self.repo_info, *number).await {
+                    Some(true) => Ok(Query::Pr(*number)),
+                    Some(false) => Ok(Query::Issue(*number)),
+                    None => Err(WtgError::NotFound(format!("#{number}"))),
+                }
+            }
+            ParsedQuery::Resolved(resolved) => Ok(resolved.clone()),
+            ParsedQuery::Unknown(input) => {
+                if looks_like_commit_hash(input) && self.find_commit(input).await.is_ok() {
+                    return Ok(Query::GitCommit(input.clone()));
+                }
+                Err(WtgError::NotFound(input.clone()))
+            }
+            ParsedQuery::UnknownPath { segments, .. } => {
+                Err(WtgError::NotFound(segments.join("/")))
+            }
+        }
+    }
+
+    // ============================================
+    // URL generation
+    // ============================================
+
+    fn commit_url(&self, hash: &str) -> Option<String> {
+        Some(GiteaClient::commit_url(&self.repo_info, hash))
+    }
+
+    fn tag_url(&self, tag: &str) -> Option<String> {
+        Some(GiteaClient::tag_url(&self.repo_info, tag))
+    }
+
+    fn release_tag_url(&self, tag: &str) -> Option<String> {
+        Some(GiteaClient::release_tag_url(&self.repo_info, tag))
+    }
+
+    fn author_url_from_email(&self, email: &str) -> Option<String> {
+        GiteaClient::author_url_from_email(&self.repo_info, email)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forge_http::mock::{ForgeMock, MERGE_SHA};
+    use crate::resolution::{IdentifiedThing, resolve};
+    use serde_json::json;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const REPO: &str = "/api/v1/repos/owner/repo";
+
+    async fn forge() -> ForgeMock {
+        ForgeMock::start(REPO, ("Authorization", "token gitea-test")).await
+    }
+
+    fn backend(server: &MockServer) -> GiteaBackend {
+        let client =
+            GiteaClient::with_token(&server.uri(), Some("gitea-test".to_string())).expect("client");
+        GiteaBackend::with_client(
+            Arc::new(client),
+            GtRepoInfo::new(&server.uri(), "owner", "repo"),
+        )
+    }
+
+    fn pull_request() -> serde_json::Value {
+        json!({
+            "number": 7,
+            "title": "Fix the flux capacitor",
+            "body": "Fixes #3",
+            "state": "closed",
+            "html_url": "https://codeberg.org/owner/repo/pulls/7",
+            "merged": true,
+            "merge_commit_sha": MERGE_SHA,
+            "user": { "login": "doc", "html_url": "https://codeberg.org/doc" },
+            "created_at": "2026-01-02T03:04:05Z",
+            "labels": [{ "name": "bug" }]
+        })
+    }
+
+    fn release(tag: &str, created_at: &str) -> serde_json::Value {
+        json!({
+            "tag_name": tag,
+            "name": format!("Release {tag}"),
+            "body": "notes",
+            "html_url": format!("https://codeberg.org/owner/repo/releases/tag/{tag}"),
+            "draft": false,
+            "prerelease": false,
+            "created_at": created_at,
+            "published_at": created_at
+        })
+    }
+
+    #[tokio::test]
+    async fn fetches_pull_request() {
+        let server = forge().await;
+        server.mock_get("/pulls/7", pull_request()).await;
+
+        let pr = backend(&server).fetch_pr(7).await.expect("PR");
+        assert_eq!(pr.number, 7);
+        assert!(pr.merged);
+        assert_eq!(pr.merge_commit_sha.as_deref(), Some(MERGE_SHA));
+        assert_eq!(pr.author.as_deref(), Some("doc"));
+        assert_eq!(pr.labels, ["bug"]);
+    }
+
+    #[tokio::test]
+    async fn resolves_issue_through_closing_pr_to_release() {
+        let server = forge().await;
+        server
+            .mock_get(
+                "/issues/3",
+                json!({
+                    "number": 3,
+                    "title": "Flux capacitor is broken",
+                    "body": "",
+                    "state": "closed",
+                    "html_url": "https://codeberg.org/owner/repo/issues/3",
+                    "user": { "login": "marty", "html_url": "https://codeberg.org/marty" },
+                    "created_at": "2026-01-01T00:00:00Z",
+                    "pull_request": null
+                }),
+            )
+            .await;
+        server.mock_get("/issues/3/timeline",
+            json!([
+                { "type": "comment", "ref_issue": null },
+                { "type": "pull_ref", "ref_issue": { "number": 7, "pull_request": { "merged": true } } }
+            ]),
+        )
+        .await;
+        server.mock_get("/pulls/7", pull_request()).await;
+        server.mock_get(&format!("/git/commits/{MERGE_SHA}"),
+            json!({
+                "sha": MERGE_SHA,
+                "html_url": format!("https://codeberg.org/owner/repo/commit/{MERGE_SHA}"),
+                "commit": {
+                    "message": "Fix the flux capacitor (#7)\n\nFixes #3",
+                    "author": { "name": "Doc Brown", "email": "doc@noreply.codeberg.org", "date": "2026-01-03T00:00:00Z" }
+                },
+                "author": { "login": "doc", "html_url": "https://codeberg.org/doc" }
+            }),
+        )
+        .await;
+        server
+            .mock_get(
+                "/releases",
+                json!([
+                    release("v1.1.0", "2026-02-01T00:00:00Z"),
+                    release("v1.0.0", "2026-01-10T00:00:00Z"),
+                    release("v0.9.0", "2025-12-01T00:00:00Z")
+                ]),
+            )
+            .await;
+        server
+            .mock_get(
+                &format!("/compare/v1.0.0...{MERGE_SHA}"),
+                json!({ "total_commits": 0 }),
+            )
+            .await;
+        server.mock_get("/tags/v1.0.0",
+            json!({ "name": "v1.0.0", "commit": { "sha": "tag-sha", "created": "2026-01-10T00:00:00Z" } }),
+        )
+        .await;
+        server
+            .mock_get(
+                "/releases/tags/v1.0.0",
+                release("v1.0.0", "2026-01-10T00:00:00Z"),
+            )
+            .await;
+
+        let backend = backend(&server);
+        let query = backend
+            .disambiguate_query(&ParsedQuery::Resolved(Query::IssueOrPr(3)))
+            .await
+            .expect("disambiguate");
+        assert_eq!(query, Query::Issue(3));
+
+        let result = resolve(&backend, &query, &ReleaseFilter::default())
+            .await
+            .expect("resolve");
+        let IdentifiedThing::Enriched(info) = result else {
+            panic!("Expected Enriched result");
+        };
+
+        assert_eq!(info.issue.as_ref().map(|i| i.number), Some(3));
+        assert_eq!(info.pr.as_ref().map(|p| p.number), Some(7));
+
+        let commit = info.commit.expect("commit");
+        assert_eq!(commit.hash, MERGE_SHA);
+        assert_eq!(commit.author_login.as_deref(), Some("doc"));
+
+        let release = info.release.expect("release");
+        assert_eq!(release.name, "v1.0.0");
+        assert!(release.is_release);
+        assert_eq!(release.release_name.as_deref(), Some("Release v1.0.0"));
+    }
+
+    #[tokio::test]
+    async fn disambiguates_pull_request_numbers() {
+        let server = forge().await;
+        server
+            .mock_get(
+                "/issues/7",
+                json!({
+                    "number": 7,
+                    "title": "Fix the flux capacitor",
+                    "state": "closed",
+                    "html_url": "https://codeberg.org/owner/repo/pulls/7",
+                    "pull_request": { "merged": true }
+                }),
+            )
+            .await;
+
+        let query = backend(&server)
+            .disambiguate_query(&ParsedQuery::Resolved(Query::IssueOrPr(7)))
+            .await
+            .expect("disambiguate");
+        assert_eq!(query, Query::Pr(7));
+    }
+
+    #[tokio::test]
+    async fn reads_changelog_from_raw_file() {
+        let server = forge().await;
+        Mock::given(method("GET"))
+            .and(path(format!("{REPO}/raw/CHANGELOG.md")))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "# Changelog\n\n## [1.0.0]\n\n- Fixed it\n\n## [0.9.0]\n\n- Broke it\n",
+            ))
+            .mount(&server)
+            .await;
+
+        let section = backend(&server)
+            .changelog_for_version("1.0.0")
+            .await
+            .expect("changelog section");
+        assert!(section.contains("Fixed it"));
+        assert!(!section.contains("Broke it"));
+    }
+
+    #[tokio::test]
+    async fn missing_pull_request_is_not_found() {
+        let server = forge().await;
+        let err = backend(&server).fetch_pr(404).await.unwrap_err();
+        assert!(err.is_not_found());
+    }
+}
@@ -6,17 +6,25 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use std::ops::ControlFlow;
 use std::sync::Arc;
 
 use super::Backend;
+use crate::branch_filter::BranchFilter;
 use crate::changelog;
 use crate::error::{WtgError, WtgResult};
-use crate::git::{CommitInfo, TagInfo, looks_like_commit_hash};
+use crate::git::{BranchInfo, CommitInfo, TagInfo, looks_like_commit_hash, parse_semver};
 use crate::github::{ExtendedIssueInfo, GhRepoInfo, GitHubClient, PullRequestInfo};
 use crate::notice::{Notice, NoticeCallback};
 use crate::parse_input::{ParsedQuery, Query};
 use crate::release_filter::ReleaseFilter;
 
+/// Most branches checked with the compare API (one request per branch).
+const MAX_API_BRANCH_CHECKS: usize = 50;
+/// How many branches are checked concurrently.
+const BRANCH_CHECK_CONCURRENCY: usize = 8;
+
 /// Pure GitHub API backend.
 ///
 /// Uses `GitHubClient` for all operations. Cannot perform local git operations,
@@ -27,17 +35,6 @@ pub(crate) struct GitHubBackend {
 }
 
 impl GitHubBackend {
-    /// Create a new `GitHubBackend` for a repository.
-    ///
-    /// Returns `None` if no GitHub client can be created.
-    #[must_use]
-    pub(crate) fn new(gh_repo_info: GhRepoInfo) -> Option<Self> {
-        Some(Self {
-            client: Arc::new(GitHubClient::new()?),
-            gh_repo_info,
-        })
-    }
-
     /// Create a `GitHubBackend` with a shared client.
     #[must_use]
     pub(crate) const fn with_client(client: Arc<GitHubClient>, gh_repo_info: GhRepoInfo) -> Self {
@@ -79,7 +76,7 @@ impl GitHubBackend {
             // Check if commit is contained in this tag
             if self
                 .client
-                .tag_contains_commit(&self.gh_repo_info, tag_name, commit_hash)
+                .ref_contains_commit(&self.gh_repo_info, tag_name, commit_hash)
                 .await
             {
                 return Some(tag);
@@ -92,12 +89,9 @@ impl GitHubBackend {
             .fetch_releases_since(&self.gh_repo_info, since)
             .await;
 
-        // Apply filter: skip prereleases if requested
-        let skip_prereleases = filter.skips_prereleases();
-
         for release in releases {
-            // Skip pre-releases if filter is active
-            if skip_prereleases && release.prerelease {
+            // Skip releases the filter rules out (pre-releases, other components)
+            if !filter.allows_release(&release.tag_name, release.prerelease) {
                 continue;
             }
 
@@ -126,6 +120,10 @@ impl Backend for GitHubBackend {
         self.client.emit(notice);
     }
 
+    fn is_rate_limited(&self) -> bool {
+        self.client.is_rate_limited()
+    }
+
     async fn backend_for_pr(&self, pr: &PullRequestInfo) -> Option<Box<dyn Backend>> {
         let pr_repo = pr.repo_info.as_ref()?;
 
@@ -172,6 +170,12 @@ impl Backend for GitHubBackend {
             .ok_or_else(|| WtgError::NotFound(format!("PR #{number}")))
     }
 
+    async fn find_pr_for_commit(&self, hash: &str) -> Option<PullRequestInfo> {
+        self.client
+            .fetch_pr_for_commit(&self.gh_repo_info, hash)
+            .await
+    }
+
     // ============================================
     // Tag/Release operations
     // ============================================
@@ -224,7 +228,7 @@ impl Backend for GitHubBackend {
             .filter(|r| r.created_at.is_some_and(|d| d < current.created_at))
             .collect();
 
-        candidates.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        candidates.sort_by_key(|tag| std::cmp::Reverse(tag.created_at));
 
         if let Some(prev) = candidates.first() {
             return self.find_tag(&prev.tag_name).await.map(Some);
@@ -235,13 +239,17 @@ impl Backend for GitHubBackend {
 
     async fn commits_between_tags(
         &self,
-        _from_tag: &str,
-        _to_tag: &str,
-        _limit: usize,
+        from_tag: &str,
+        to_tag: &str,
+        limit: usize,
     ) -> WtgResult<Vec<CommitInfo>> {
-        // GitHub compare API is not yet implemented in GitHubClient.
-        // The CombinedBackend will use the git backend for this operation.
-        Err(WtgError::Unsupported("GitHub commits between tags".into()))
+        let mut commits = self
+            .client
+            .fetch_commits_between(&self.gh_repo_info, from_tag, to_tag)
+            .await
+            .ok_or_else(|| WtgError::NotFound(format!("{from_tag}...{to_tag}")))?;
+        commits.truncate(limit);
+        Ok(commits)
     }
 
     async fn find_release_for_commit(
@@ -255,6 +263,37 @@ impl Backend for GitHubBackend {
             .await
     }
 
+    async fn find_release_at(
+        &self,
+        at: DateTime<Utc>,
+        filter: &ReleaseFilter,
+    ) -> WtgResult<Option<TagInfo>> {
+        // Releases come newest first: the first semver one shipped by then wins,
+        // otherwise the newest release of any kind
+        let mut semver = None;
+        let mut other = None;
+        self.client
+            .for_each_release(&self.gh_repo_info, |release| {
+                let shipped = release.published_at.or(release.created_at);
+                if shipped.is_some_and(|shipped| shipped <= at)
+                    && filter.allows_release(&release.tag_name, release.prerelease)
+                {
+                    if parse_semver(&release.tag_name).is_some() {
+                        semver = Some(release);
+                        return ControlFlow::Break(());
+                    }
+                    other.get_or_insert(release);
+                }
+                ControlFlow::Continue(())
+            })
+            .await;
+
+        match semver.or(other) {
+            Some(release) => self.find_tag(&release.tag_name).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
     async fn fetch_release_body(&self, tag_name: &str) -> Option<String> {
         let release = self
             .client
@@ -278,6 +317,53 @@ impl Backend for GitHubBackend {
         None
     }
 
+    async fn branches_containing_commit(
+        &self,
+        commit_hash: &str,
+        filter: &BranchFilter,
+    ) -> Option<Vec<BranchInfo>> {
+        let branches: Vec<BranchInfo> = self
+            .client
+            .fetch_branch_names(&self.gh_repo_info)
+            .await?
+            .into_iter()
+            .map(|name| BranchInfo {
+                name,
+                is_remote: false,
+            })
+            .collect();
+        let mut branches = filter.filter_branches(branches);
+        branches.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Every branch costs a compare request, so don't go overboard
+        if branches.len() > MAX_API_BRANCH_CHECKS {
+            self.client.emit(Notice::BranchCheckLimited {
+                checked: MAX_API_BRANCH_CHECKS,
+                total: branches.len(),
+            });
+            branches.truncate(MAX_API_BRANCH_CHECKS);
+        }
+
+        let names: Vec<String> = branches.iter().map(|branch| branch.name.clone()).collect();
+        let contains: Vec<bool> = stream::iter(names)
+            .map(|name| async move {
+                self.client
+                    .ref_contains_commit(&self.gh_repo_info, &name, commit_hash)
+                    .await
+            })
+            .buffered(BRANCH_CHECK_CONCURRENCY)
+            .collect()
+            .await;
+
+        Some(
+            branches
+                .into_iter()
+                .zip(contains)
+                .filter_map(|(branch, contains)| contains.then_some(branch))
+                .collect(),
+        )
+    }
+
     async fn disambiguate_query(&self, query: &ParsedQuery) -> WtgResult<Query> {
         match query {
             ParsedQuery::Resolved(resolved) => Ok(resolved.clone()),
@@ -287,7 +373,9 @@ impl Backend for GitHubBackend {
                 }
                 Err(WtgError::NotFound(input.clone()))
             }
-            ParsedQuery::UnknownPath { segments } => Err(WtgError::NotFound(segments.join("/"))),
+            ParsedQuery::UnknownPath { segments, .. } => {
+                Err(WtgError::NotFound(segments.join("/")))
+            }
         }
     }
 
@@ -308,6 +396,6 @@ impl Backend for GitHubBackend {
     }
 
     fn author_url_from_email(&self, email: &str) -> Option<String> {
-        GitHubClient::author_url_from_email(email)
+        GitHubClient::author_url_from_email(self.gh_repo_info.host(), email)
     }
 }
@@ -0,0 +1,433 @@
+//! Pure GitLab API backend implementation.
+//!
+//! This backend only uses the GitLab REST API via `GitLabClient`.
+//! It can fetch commits, merge requests, issues, tags and releases, but cannot
+//! walk file history or perform local git operations.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::Backend;
+use super::tag_selection::{pick_best_tag, pick_release_lines};
+use crate::changelog;
+use crate::error::{WtgError, WtgResult};
+use crate::git::{CommitInfo, TagInfo, looks_like_commit_hash};
+use crate::github::{ExtendedIssueInfo, PullRequestInfo};
+use crate::gitlab::{GitLabClient, GlRepoInfo};
+use crate::parse_input::{ParsedQuery, Query};
+use crate::release_filter::ReleaseFilter;
+
+/// Pure GitLab API backend.
+///
+/// Merge requests are exposed through the PR operations. GitLab numbers issues
+/// and merge requests independently, so bare `#123` references resolve to issues.
+pub(crate) struct GitLabBackend {
+    client: Arc<GitLabClient>,
+    repo_info: GlRepoInfo,
+}
+
+impl GitLabBackend {
+    /// Create a new `GitLabBackend` for a project.
+    ///
+    /// Returns `None` if no GitLab client can be created.
+    #[must_use]
+    pub(crate) fn new(repo_info: GlRepoInfo) -> Option<Self> {
+        let client = GitLabClient::new(repo_info.base_url())?;
+        Some(Self::with_client(Arc::new(client), repo_info))
+    }
+
+    /// Create a `GitLabBackend` with a shared client.
+    #[must_use]
+    pub(crate) const fn with_client(client: Arc<GitLabClient>, repo_info: GlRepoInfo) -> Self {
+        Self { client, repo_info }
+    }
+
+    /// Attach release metadata to a tag found without it.
+    async fn enrich_with_release(&self, mut tag: TagInfo) -> TagInfo {
+        if let Some(release) = self
+            .client
+            .fetch_release_by_tag(&self.repo_info, &tag.name)
+            .await
+        {
+            tag.is_release = true;
+            tag.release_name = release.name;
+            tag.release_url = Some(release.url.clone());
+            tag.published_at = release.published_at;
+            tag.tag_url = Some(release.url);
+        }
+        tag
+    }
+
+    /// Apply the filter to tag candidates and time them by tag creation.
+    ///
+    /// GitLab tags already say whether they have a release, so the release
+    /// requirement needs no extra requests.
+    fn tag_candidates(
+        candidates: Vec<TagInfo>,
+        filter: &ReleaseFilter,
+    ) -> (Vec<TagInfo>, HashMap<String, i64>) {
+        let filtered = filter.filter_releases(filter.filter_tags(candidates));
+        let timestamps = filtered
+            .iter()
+            .map(|tag| (tag.commit_hash.clone(), tag.created_at.timestamp()))
+            .collect();
+        (filtered, timestamps)
+    }
+}
+
+#[async_trait]
+impl Backend for GitLabBackend {
+    // Note: backend_for_pr() uses default (returns None) - merge requests
+    // are always resolved against the current project.
+
+    // ============================================
+    // Commit operations
+    // ============================================
+
+    async fn find_commit(&self, hash: &str) -> WtgResult<CommitInfo> {
+        self.client
+            .fetch_commit(&self.repo_info, hash)
+            .await
+            .ok_or_else(|| WtgError::NotFound(hash.to_string()))
+    }
+
+    async fn enrich_commit(&self, mut commit: CommitInfo) -> CommitInfo {
+        if commit.commit_url.is_none() {
+            commit.commit_url = self.commit_url(&commit.hash);
+        }
+        if commit.author_url.is_none()
+            && let Some(email) = commit.author_email.as_deref()
+        {
+            commit.author_url = self.author_url_from_email(email);
+        }
+        commit
+    }
+
+    // ============================================
+    // Issue/MR operations
+    // ============================================
+
+    async fn fetch_issue(&self, number: u64) -> WtgResult<ExtendedIssueInfo> {
+        self.client
+            .fetch_issue(&self.repo_info, number)
+            .await
+            .ok_or_else(|| WtgError::NotFound(format!("Issue #{number}")))
+    }
+
+    async fn fetch_pr(&self, number: u64) -> WtgResult<PullRequestInfo> {
+        self.client
+            .fetch_merge_request(&self.repo_info, number)
+            .await
+            .ok_or_else(|| WtgError::NotFound(format!("MR !{number}")))
+    }
+
+    async fn find_pr_for_commit(&self, hash: &str) -> Option<PullRequestInfo> {
+        self.client
+            .fetch_merge_request_for_commit(&self.repo_info, hash)
+            .await
+    }
+
+    // ============================================
+    // Tag/Release operations
+    // ============================================
+
+    async fn find_tag(&self, name: &str) -> WtgResult<TagInfo> {
+        self.client
+            .fetch_tag(&self.repo_info, name)
+            .await
+            .ok_or_else(|| WtgError::NotFound(format!("Tag {name}")))
+    }
+
+    async fn find_release_for_commit(
+        &self,
+        commit_hash: &str,
+        _commit_date: Option<DateTime<Utc>>,
+        filter: &ReleaseFilter,
+    ) -> Option<TagInfo> {
+        let candidates = self
+            .client
+            .fetch_tags_containing_commit(&self.repo_info, commit_hash)
+            .await;
+
+        // Fast path for specific tag lookup
+        if let Some(tag_name) = filter.specific_tag() {
+            let tag = candidates.into_iter().find(|t| t.name == tag_name)?;
+            return Some(self.enrich_with_release(tag).await);
+        }
+
+        let (filtered, timestamps) = Self::tag_candidates(candidates, filter);
+        let best = pick_best_tag(&filtered, &timestamps)?;
+        Some(self.enrich_with_release(best).await)
+    }
+
+    async fn find_releases_for_commit(
+        &self,
+        commit_hash: &str,
+        commit_date: Option<DateTime<Utc>>,
+        filter: &ReleaseFilter,
+    ) -> Vec<TagInfo> {
+        if filter.specific_tag().is_some() {
+            return self
+                .find_release_for_commit(commit_hash, commit_date, filter)
+                .await
+                .into_iter()
+                .collect();
+        }
+
+        let candidates = self
+            .client
+            .fetch_tags_containing_commit(&self.repo_info, commit_hash)
+            .await;
+        let (filtered, timestamps) = Self::tag_candidates(candidates, filter);
+
+        let mut releases = Vec::new();
+        for tag in pick_release_lines(&filtered, &timestamps) {
+            releases.push(self.enrich_with_release(tag).await);
+        }
+        releases
+    }
+
+    async fn fetch_release_body(&self, tag_name: &str) -> Option<String> {
+        let release = self
+            .client
+            .fetch_release_by_tag(&self.repo_info, tag_name)
+            .await?;
+        release.body.filter(|b| !b.trim().is_empty())
+    }
+
+    async fn changelog_for_version(&self, version: &str) -> Option<String> {
+        for path in ["CHANGELOG.md", "changelog.md", "Changelog.md"] {
+            if let Some(content) = self.client.fetch_file_content(&self.repo_info, path).await
+                && let Some(section) = changelog::extract_version_section(&content, version)
+            {
+                return Some(section);
+            }
+        }
+        None
+    }
+
+    async fn disambiguate_query(&self, query: &ParsedQuery) -> WtgResult<Query> {
+        match query {
+            // `#123` is always an issue on GitLab (merge requests are `!123`)
+            ParsedQuery::Resolved(Query::IssueOrPr(number)) => Ok(Query::Issue(*number)),
+            ParsedQuery::Resolved(resolved) => Ok(resolved.clone()),
+            ParsedQuery::Unknown(input) => {
+                if looks_like_commit_hash(input) && self.find_commit(input).await.is_ok() {
+                    return Ok(Query::GitCommit(input.clone()));
+                }
+                Err(WtgError::NotFound(input.clone()))
+            }
+            ParsedQuery::UnknownPath { segments, .. } => {
+                Err(WtgError::NotFound(segments.join("/")))
+            }
+        }
+    }
+
+    // ============================================
+    // URL generation
+    // ============================================
+
+    fn commit_url(&self, hash: &str) -> Option<String> {
+        Some(GitLabClient::commit_url(&self.repo_info, hash))
+    }
+
+    fn tag_url(&self, tag: &str) -> Option<String> {
+        Some(GitLabClient::tag_url(&self.repo_info, tag))
+    }
+
+    fn release_tag_url(&self, tag: &str) -> Option<String> {
+        Some(GitLabClient::release_tag_url(&self.repo_info, tag))
+    }
+
+    fn author_url_from_email(&self, email: &str) -> Option<String> {
+        GitLabClient::author_url_from_email(&self.repo_info, email)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forge_http::mock::{ForgeMock, MERGE_SHA};
+    use crate::resolution::{IdentifiedThing, resolve};
+    use serde_json::json;
+    use wiremock::MockServer;
+
+    const PROJECT: &str = "/api/v4/projects/group%2Fproject";
+
+    async fn forge() -> ForgeMock {
+        ForgeMock::start(PROJECT, ("PRIVATE-TOKEN", "glpat-test")).await
+    }
+
+    fn backend(server: &MockServer) -> GitLabBackend {
+        let client = GitLabClient::with_token(&server.uri(), Some("glpat-test".to_string()))
+            .expect("client");
+        GitLabBackend::with_client(
+            Arc::new(client),
+            GlRepoInfo::new(&server.uri(), "group/project"),
+        )
+    }
+
+    fn merge_request() -> serde_json::Value {
+        json!({
+            "iid": 7,
+            "title": "Fix the flux capacitor",
+            "description": "Closes #3",
+            "state": "merged",
+            "web_url": "https://gitlab.example.com/group/project/-/merge_requests/7",
+            "sha": "fedcba9876543210fedcba9876543210fedcba98",
+            "merge_commit_sha": MERGE_SHA,
+            "squash_commit_sha": null,
+            "author": { "username": "doc", "web_url": "https://gitlab.example.com/doc" },
+            "created_at": "2026-01-02T03:04:05Z"
+        })
+    }
+
+    fn commit() -> serde_json::Value {
+        json!({
+            "id": MERGE_SHA,
+            "short_id": "0123456",
+            "title": "Merge branch 'fix' into 'main'",
+            "message": "Merge branch 'fix' into 'main'\n\nSee merge request group/project!7",
+            "author_name": "Doc Brown",
+            "author_email": "42-doc@users.noreply.gitlab.example.com",
+            "authored_date": "2026-01-03T00:00:00Z",
+            "committed_date": "2026-01-03T00:00:00Z",
+            "web_url": "https://gitlab.example.com/group/project/-/commit/0123456789abcdef"
+        })
+    }
+
+    fn tag(name: &str, has_release: bool) -> serde_json::Value {
+        json!({
+            "name": name,
+            "commit": {
+                "id": format!("{name}-sha"),
+                "short_id": "abcdef0",
+                "title": "Release",
+                "message": "Release",
+                "author_name": "Doc Brown",
+                "authored_date": "2026-01-10T00:00:00Z",
+            },
+            "release": if has_release { json!({ "tag_name": name, "description": "notes" }) } else { json!(null) }
+        })
+    }
+
+    #[tokio::test]
+    async fn fetches_merge_request_as_pr() {
+        let server = forge().await;
+        server.mock_get("/merge_requests/7", merge_request()).await;
+
+        let pr = backend(&server).fetch_pr(7).await.expect("MR");
+        assert_eq!(pr.number, 7);
+        assert!(pr.merged);
+        assert_eq!(pr.state, "merged");
+        assert_eq!(pr.merge_commit_sha.as_deref(), Some(MERGE_SHA));
+        assert_eq!(pr.author.as_deref(), Some("doc"));
+        assert_eq!(pr.body.as_deref(), Some("Closes #3"));
+    }
+
+    #[tokio::test]
+    async fn resolves_issue_through_closing_mr_to_release() {
+        let server = forge().await;
+        server.mock_get("/issues/3",
+            json!({
+                "iid": 3,
+                "title": "Flux capacitor is broken",
+                "description": "",
+                "state": "closed",
+                "web_url": "https://gitlab.example.com/group/project/-/issues/3",
+                "author": { "username": "marty", "web_url": "https://gitlab.example.com/marty" },
+                "created_at": "2026-01-01T00:00:00Z"
+            }),
+        )
+        .await;
+        server
+            .mock_get("/issues/3/closed_by", json!([merge_request()]))
+            .await;
+        server
+            .mock_get(&format!("/repository/commits/{MERGE_SHA}"), commit())
+            .await;
+        server
+            .mock_get(
+                &format!("/repository/commits/{MERGE_SHA}/refs"),
+                json!([
+                    { "type": "tag", "name": "v1.1.0" },
+                    { "type": "tag", "name": "v1.0.0" }
+                ]),
+            )
+            .await;
+        server
+            .mock_get("/repository/tags/v1.0.0", tag("v1.0.0", true))
+            .await;
+        server
+            .mock_get("/repository/tags/v1.1.0", tag("v1.1.0", false))
+            .await;
+        server.mock_get("/releases/v1.0.0",
+            json!({
+                "tag_name": "v1.0.0",
+                "name": "Great Scott",
+                "description": "First release",
+                "created_at": "2026-01-10T00:00:00Z",
+                "released_at": "2026-01-11T00:00:00Z",
+                "_links": { "self": "https://gitlab.example.com/group/project/-/releases/v1.0.0" }
+            }),
+        )
+        .await;
+
+        let backend = backend(&server);
+        let query = backend
+            .disambiguate_query(&ParsedQuery::Resolved(Query::IssueOrPr(3)))
+            .await
+            .expect("disambiguate");
+        assert_eq!(query, Query::Issue(3));
+
+        let result = resolve(&backend, &query, &ReleaseFilter::default())
+            .await
+            .expect("resolve");
+        let IdentifiedThing::Enriched(info) = result else {
+            panic!("Expected Enriched result");
+        };
+
+        assert_eq!(info.issue.as_ref().map(|i| i.number), Some(3));
+        assert_eq!(info.pr.as_ref().map(|p| p.number), Some(7));
+
+        let commit = info.commit.expect("commit");
+        assert_eq!(commit.hash, MERGE_SHA);
+        assert_eq!(commit.message, "Merge branch 'fix' into 'main'");
+        assert_eq!(commit.message_lines, 3);
+        assert_eq!(
+            commit.author_url.as_deref(),
+            Some(format!("{}/doc", server.uri()).as_str())
+        );
+
+        let release = info.release.expect("release");
+        assert_eq!(release.name, "v1.0.0");
+        assert!(release.is_release);
+        assert_eq!(release.release_name.as_deref(), Some("Great Scott"));
+    }
+
+    #[tokio::test]
+    async fn missing_merge_request_is_not_found() {
+        let server = forge().await;
+        let err = backend(&server).fetch_pr(404).await.unwrap_err();
+        assert!(err.is_not_found());
+    }
+
+    #[test]
+    fn builds_gitlab_urls() {
+        let client = GitLabClient::with_token("https://gitlab.example.com", None).expect("client");
+        let backend = GitLabBackend::with_client(
+            Arc::new(client),
+            GlRepoInfo::new("https://gitlab.example.com", "group/project"),
+        );
+        assert_eq!(
+            backend.release_tag_url("v1").as_deref(),
+            Some("https://gitlab.example.com/group/project/-/releases/v1")
+        );
+        assert_eq!(
+            backend.commit_url("abc").as_deref(),
+            Some("https://gitlab.example.com/group/project/-/commit/abc")
+        );
+    }
+}
@@ -1,31 +1,50 @@
 //! Backend trait abstraction for git/GitHub operations.
 //!
-//! This module provides a trait-based abstraction over data sources (local git, GitHub API, or both),
-//! enabling:
+//! This module provides a trait-based abstraction over data sources (local git, a GitHub,
+//! GitLab, Gitea or Bitbucket API, or both), enabling:
 //! - Cross-project references (issues referencing PRs in different repos)
-//! - Future non-GitHub hosting support
+//! - Non-GitHub hosting support
 //! - Optimal path selection when both local and remote sources are available
 
+mod bitbucket_backend;
 mod combined_backend;
+mod forge_combined_backend;
 mod git_backend;
+mod gitea_backend;
 mod github_backend;
+mod gitlab_backend;
+mod tag_selection;
 
+pub(crate) use bitbucket_backend::BitbucketBackend;
 pub(crate) use combined_backend::CombinedBackend;
+pub(crate) use forge_combined_backend::ForgeCombinedBackend;
 pub use git_backend::GitBackend;
+pub(crate) use gitea_backend::GiteaBackend;
 pub(crate) use github_backend::GitHubBackend;
+pub(crate) use gitlab_backend::GitLabBackend;
 
 use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 
+use crate::api_cache::CacheSettings;
+use crate::bitbucket::BbRepoInfo;
+use crate::branch_filter::BranchFilter;
 use crate::error::{WtgError, WtgResult};
-use crate::git::{CommitInfo, FileInfo, GitRepo, TagInfo};
-use crate::github::{ExtendedIssueInfo, PullRequestInfo};
+use crate::git::{
+    BlameHunk, BranchInfo, CommitInfo, FileChange, FileInfo, GitRepo, PathsInfo, TagInfo,
+};
+use crate::gitea::GtRepoInfo;
+use crate::github::{ExtendedIssueInfo, GhRepoInfo, GitHubClient, PullRequestInfo};
+use crate::gitlab::GlRepoInfo;
 use crate::notice::{Notice, NoticeCallback, no_notices};
-use crate::parse_input::{ParsedInput, ParsedQuery, Query};
+use crate::parse_input::{LineRange, ParsedInput, ParsedQuery, Query, RemoteRepo};
+use crate::recording::Recording;
 use crate::release_filter::ReleaseFilter;
-use crate::remote::{RemoteHost, RemoteInfo};
+use crate::remote::{ForgeHosts, RemoteHost, RemoteInfo};
 
 /// Unified backend trait for all git/GitHub operations.
 ///
@@ -41,6 +60,12 @@ pub trait Backend: Send + Sync {
     /// Used by resolution code to surface warnings to the user.
     fn emit_notice(&self, _notice: Notice) {}
 
+    /// Whether the forge API has started refusing requests (rate limit), so
+    /// optional lookups should stop instead of failing one by one.
+    fn is_rate_limited(&self) -> bool {
+        false
+    }
+
     // ============================================
     // Cross-project support (default: not supported)
     // ============================================
@@ -74,6 +99,11 @@ pub trait Backend: Send + Sync {
         }
     }
 
+    /// Find commits carrying the same change as the given one (cherry-picks, backports).
+    async fn find_equivalent_commits(&self, _hash: &str) -> Vec<CommitInfo> {
+        Vec::new()
+    }
+
     // ============================================
     // File operations (default: Unsupported)
     // ============================================
@@ -83,6 +113,35 @@ pub trait Backend: Send + Sync {
         Err(WtgError::Unsupported("file lookup".into()))
     }
 
+    /// Commits that changed a file, most recent first, following renames.
+    ///
+    /// Skips the `skip` most recent changes and returns at most `limit` of the rest.
+    async fn file_history(
+        &self,
+        _branch: &str,
+        _path: &str,
+        _skip: usize,
+        _limit: usize,
+    ) -> WtgResult<Vec<FileChange>> {
+        Err(WtgError::Unsupported("file history".into()))
+    }
+
+    /// Find the commits that last changed some lines of a file (git blame).
+    async fn blame_lines(
+        &self,
+        _branch: &str,
+        _path: &str,
+        _lines: LineRange,
+    ) -> WtgResult<Vec<BlameHunk>> {
+        Err(WtgError::Unsupported("line blame".into()))
+    }
+
+    /// Find the latest change to files under a directory or matching a glob,
+    /// and who changed them most recently.
+    async fn find_paths(&self, _branch: &str, _pattern: &str) -> WtgResult<PathsInfo> {
+        Err(WtgError::Unsupported("directory and glob lookup".into()))
+    }
+
     // ============================================
     // Tag/Release operations (default: Unsupported)
     // ============================================
@@ -117,16 +176,18 @@ pub trait Backend: Send + Sync {
         match query {
             ParsedQuery::Resolved(resolved) => Ok(resolved.clone()),
             ParsedQuery::Unknown(input) => Err(WtgError::NotFound(input.clone())),
-            ParsedQuery::UnknownPath { segments } => Err(WtgError::NotFound(segments.join("/"))),
+            ParsedQuery::UnknownPath { segments, .. } => {
+                Err(WtgError::NotFound(segments.join("/")))
+            }
         }
     }
 
     /// Find a release/tag that contains the given commit.
     ///
     /// The `filter` parameter controls which tags are considered:
-    /// - `Unrestricted`: All tags (default behavior)
-    /// - `SkipPrereleases`: Filter out pre-release versions
-    /// - `Specific(tag)`: Check if the commit is in a specific tag
+    /// - By default: all tags
+    /// - Skipping pre-releases: filter out pre-release versions
+    /// - A specific tag: check if the commit is in that tag
     async fn find_release_for_commit(
         &self,
         _commit_hash: &str,
@@ -136,6 +197,33 @@ pub trait Backend: Send + Sync {
         None
     }
 
+    /// Find the earliest release of every release line containing the given commit.
+    ///
+    /// A release line is a tag prefix plus major version. Backends that can't
+    /// tell lines apart report the single release from `find_release_for_commit`.
+    async fn find_releases_for_commit(
+        &self,
+        commit_hash: &str,
+        commit_date: Option<DateTime<Utc>>,
+        filter: &ReleaseFilter,
+    ) -> Vec<TagInfo> {
+        self.find_release_for_commit(commit_hash, commit_date, filter)
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    /// Find the newest release shipped at or before `at` (the release current then).
+    ///
+    /// Only tags passing `filter` count. Returns `None` if there was no release yet.
+    async fn find_release_at(
+        &self,
+        _at: DateTime<Utc>,
+        _filter: &ReleaseFilter,
+    ) -> WtgResult<Option<TagInfo>> {
+        Err(WtgError::Unsupported("release lookup by date".into()))
+    }
+
     /// Fetch the body/description of a GitHub release by tag name.
     async fn fetch_release_body(&self, _tag_name: &str) -> Option<String> {
         None
@@ -150,6 +238,22 @@ pub trait Backend: Send + Sync {
         None
     }
 
+    // ============================================
+    // Branch operations (default: None)
+    // ============================================
+
+    /// Find the branches (local and remote-tracking) that contain a commit.
+    ///
+    /// Only branches passing `filter` are returned. Returns `None` if branch
+    /// containment can't be determined.
+    async fn branches_containing_commit(
+        &self,
+        _commit_hash: &str,
+        _filter: &BranchFilter,
+    ) -> Option<Vec<BranchInfo>> {
+        None
+    }
+
     // ============================================
     // Issue operations (default: Unsupported)
     // ============================================
@@ -168,6 +272,11 @@ pub trait Backend: Send + Sync {
         Err(WtgError::Unsupported("PR lookup".into()))
     }
 
+    /// Find the merged PR that introduced a commit.
+    async fn find_pr_for_commit(&self, _hash: &str) -> Option<PullRequestInfo> {
+        None
+    }
+
     // ============================================
     // URL generation (default: None)
     // ============================================
@@ -205,9 +314,11 @@ pub trait Backend: Send + Sync {
 ///
 /// Decision tree:
 /// 1. Explicit repo info provided → Use cached/cloned repo + GitHub API (hard error if GitHub client fails)
-/// 2. In local repo with GitHub remote → Combined backend (soft notice if GitHub client fails)
-/// 3. In local repo without GitHub remote → Git-only backend with appropriate notice
-/// 4. Not in repo and no info → Error
+/// 2. Explicit Gitea/Bitbucket repo provided → Local clone of it + forge API if we're in one, pure forge API otherwise
+/// 3. In local repo with GitHub remote → Combined backend (soft notice if GitHub client fails)
+/// 4. In local repo with GitLab, Gitea or Bitbucket remote → Combined git + forge backend
+/// 5. In local repo without a supported remote → Git-only backend with appropriate notice
+/// 6. Not in repo and no info → Error
 pub fn resolve_backend(
     parsed_input: &ParsedInput,
     allow_user_repo_fetch: bool,
@@ -221,40 +332,180 @@ pub fn resolve_backend_with_notices(
     allow_user_repo_fetch: bool,
     notice_cb: NoticeCallback,
 ) -> WtgResult<Box<dyn Backend>> {
-    // Case 1: Explicit repo info provided (from URL/flags)
-    if let Some(repo_info) = parsed_input.gh_repo_info() {
-        // User explicitly provided GitHub info - GitHub client failure is a hard error
-        let github = GitHubBackend::new(repo_info.clone()).ok_or(WtgError::GitHubClientFailed)?;
+    let options = BackendOptions {
+        allow_fetch: allow_user_repo_fetch,
+        notice_cb,
+        ..BackendOptions::default()
+    };
+    resolve_backend_with_options(parsed_input, &options)
+}
 
-        // Try to get local git repo for combined backend
-        if let Ok(git_repo) = GitRepo::remote_with_notices(repo_info.clone(), notice_cb.clone()) {
-            let git = GitBackend::new(git_repo);
-            let mut combined = CombinedBackend::new(git, github);
-            combined.set_notice_callback(notice_cb);
-            Ok(Box::new(combined))
-        } else {
-            // Can't access git locally, use pure API (soft notice)
-            notice_cb(Notice::ApiOnly);
-            github.set_notice_callback(notice_cb);
-            Ok(Box::new(github))
+/// Where backends find the local repository and how they talk to GitHub.
+#[derive(Clone)]
+pub(crate) struct BackendOptions {
+    /// Where to look for the local repository; the working directory if `None`
+    pub repo_path: Option<PathBuf>,
+    /// GitHub token used instead of the environment and gh CLI config
+    pub github_token: Option<String>,
+    /// GitHub REST API to use instead of the repository host's
+    pub github_api_url: Option<String>,
+    /// Whether remote repositories are cloned for local git access
+    pub clone_remotes: bool,
+    /// Where GitHub API traffic is recorded to or replayed from
+    pub recording: Option<Arc<Recording>>,
+    /// Whether the local repository may be fetched to find missing commits
+    pub allow_fetch: bool,
+    /// Self-hosted forges recognized in URLs and remotes
+    pub hosts: ForgeHosts,
+    /// Remote that beats upstream and origin when picking the local repo's forge
+    pub preferred_remote: Option<String>,
+    /// Changelog location relative to the repository root
+    pub changelog_path: Option<PathBuf>,
+    /// How GitHub clients use the on-disk API cache
+    pub cache: CacheSettings,
+    pub notice_cb: NoticeCallback,
+}
+
+impl Default for BackendOptions {
+    fn default() -> Self {
+        Self {
+            repo_path: None,
+            github_token: None,
+            github_api_url: None,
+            clone_remotes: true,
+            recording: None,
+            allow_fetch: false,
+            hosts: ForgeHosts::from_env(),
+            preferred_remote: None,
+            changelog_path: None,
+            cache: CacheSettings::default(),
+            notice_cb: no_notices(),
         }
-    } else {
-        // Case 2: Local repo detection
-        resolve_local_backend_with_notices(allow_user_repo_fetch, notice_cb)
     }
 }
 
-fn resolve_local_backend_with_notices(
-    allow_user_repo_fetch: bool,
-    notice_cb: NoticeCallback,
+impl BackendOptions {
+    fn open_repo(&self) -> WtgResult<GitRepo> {
+        let mut git_repo = self
+            .repo_path
+            .as_deref()
+            .map_or_else(GitRepo::open, GitRepo::discover)?;
+        git_repo.set_forge_hosts(self.hosts.clone());
+        git_repo.set_preferred_remote(self.preferred_remote.clone());
+        git_repo.set_changelog_path(self.changelog_path.clone());
+        Ok(git_repo)
+    }
+
+    /// GitHub backend for `repo_info`, or `None` if no client can be created.
+    fn github_backend(&self, repo_info: GhRepoInfo) -> Option<GitHubBackend> {
+        let host = repo_info.host();
+        let client = match (&self.recording, &self.github_api_url, &self.github_token) {
+            (Some(recording), api_url, token) => GitHubClient::with_recording(
+                host,
+                api_url.as_deref(),
+                token.clone(),
+                Arc::clone(recording),
+            )?,
+            (None, Some(api_url), token) => {
+                GitHubClient::with_api_url(host, api_url, token.clone())?
+                    .with_cache_settings(self.cache)
+            }
+            (None, None, Some(token)) => GitHubClient::for_host_with_token(host, token.clone())?
+                .with_cache_settings(self.cache),
+            (None, None, None) => GitHubClient::for_host(host)?.with_cache_settings(self.cache),
+        };
+        Some(GitHubBackend::with_client(Arc::new(client), repo_info))
+    }
+}
+
+pub(crate) fn resolve_backend_with_options(
+    parsed_input: &ParsedInput,
+    options: &BackendOptions,
 ) -> WtgResult<Box<dyn Backend>> {
-    let mut git_repo = GitRepo::open()?;
-    if allow_user_repo_fetch {
+    let notice_cb = options.notice_cb.clone();
+    match parsed_input.remote_repo() {
+        // Case 1: Explicit repo info provided (from URL/flags)
+        Some(RemoteRepo::GitHub(repo_info)) => {
+            // User explicitly provided GitHub info - GitHub client failure is a hard error
+            let github = options
+                .github_backend(repo_info.clone())
+                .ok_or(WtgError::GitHubClientFailed)?;
+
+            if !options.clone_remotes {
+                github.set_notice_callback(notice_cb);
+                return Ok(Box::new(github));
+            }
+
+            // Try to get local git repo for combined backend
+            if let Ok(git_repo) = GitRepo::remote_with_notices(repo_info.clone(), notice_cb.clone())
+            {
+                let git = GitBackend::new(git_repo);
+                let mut combined = CombinedBackend::new(git, github);
+                combined.set_notice_callback(notice_cb);
+                Ok(Box::new(combined))
+            } else {
+                // Can't access git locally, use pure API (soft notice)
+                notice_cb(Notice::ApiOnly);
+                github.set_notice_callback(notice_cb);
+                Ok(Box::new(github))
+            }
+        }
+        // Case 2: Explicit repo on a forge we don't clone from
+        Some(RemoteRepo::Gitea(repo_info)) => {
+            let gitea = GiteaBackend::new(repo_info.clone())
+                .ok_or_else(|| WtgError::Unsupported("Gitea API client".into()))?;
+            Ok(resolve_forge_backend_with_notices(
+                gitea,
+                |url| GtRepoInfo::from_remote_url(url).as_ref() == Some(repo_info),
+                options,
+            ))
+        }
+        Some(RemoteRepo::Bitbucket(repo_info)) => {
+            let bitbucket = BitbucketBackend::new(repo_info.clone())
+                .ok_or_else(|| WtgError::Unsupported("Bitbucket API client".into()))?;
+            Ok(resolve_forge_backend_with_notices(
+                bitbucket,
+                |url| BbRepoInfo::from_remote_url(url).as_ref() == Some(repo_info),
+                options,
+            ))
+        }
+        // Case 3: Local repo detection
+        None => resolve_local_backend_with_notices(options),
+    }
+}
+
+/// Backend for a repository given explicitly on a forge wtg doesn't clone from.
+///
+/// Local git only helps when we're already inside a clone of that repository,
+/// i.e. one of its remotes satisfies `is_clone_url`.
+fn resolve_forge_backend_with_notices<F: Backend + 'static>(
+    forge: F,
+    is_clone_url: impl Fn(&str) -> bool,
+    options: &BackendOptions,
+) -> Box<dyn Backend> {
+    let notice_cb = options.notice_cb.clone();
+    if let Ok(mut git_repo) = options.open_repo()
+        && git_repo.remotes().any(|r| is_clone_url(&r.url))
+    {
+        git_repo.set_notice_callback(notice_cb.clone());
+        let mut combined = ForgeCombinedBackend::new(GitBackend::new(git_repo), forge);
+        combined.set_notice_callback(notice_cb);
+        return Box::new(combined);
+    }
+
+    notice_cb(Notice::ApiOnly);
+    Box::new(forge)
+}
+
+fn resolve_local_backend_with_notices(options: &BackendOptions) -> WtgResult<Box<dyn Backend>> {
+    let notice_cb = options.notice_cb.clone();
+    let mut git_repo = options.open_repo()?;
+    if options.allow_fetch {
         git_repo.set_allow_fetch(true);
     }
     git_repo.set_notice_callback(notice_cb.clone());
 
-    // Collect and sort remotes by priority (upstream > origin > other, GitHub first)
+    // Collect and sort remotes by priority (preferred > upstream > origin > other, GitHub first)
     let mut remotes: Vec<RemoteInfo> = git_repo.remotes().collect();
     remotes.sort_by_key(RemoteInfo::priority);
 
@@ -276,10 +527,11 @@ fn resolve_local_backend_with_notices(
         if let Some(repo_info) = git_repo.github_remote() {
             let git = GitBackend::new(git_repo);
 
-            if let Some(github) = GitHubBackend::new(repo_info) {
+            if let Some(github) = options.github_backend(repo_info) {
                 // Full GitHub support!
                 let mut combined = CombinedBackend::new(git, github);
                 combined.set_notice_callback(notice_cb);
+                combined.set_clone_remotes(options.clone_remotes);
                 return Ok(Box::new(combined));
             }
 
@@ -291,7 +543,43 @@ fn resolve_local_backend_with_notices(
         }
     }
 
-    // No GitHub remote - analyze what we have
+    // No GitHub remote - a GitLab one is the next best thing
+    if let Some(repo_info) = remotes
+        .iter()
+        .filter(|r| r.host == Some(RemoteHost::GitLab))
+        .find_map(|r| GlRepoInfo::from_remote_url(&r.url))
+        && let Some(gitlab) = GitLabBackend::new(repo_info)
+    {
+        let mut combined = ForgeCombinedBackend::new(GitBackend::new(git_repo), gitlab);
+        combined.set_notice_callback(notice_cb);
+        return Ok(Box::new(combined));
+    }
+
+    // Same for Gitea/Forgejo hosts
+    if let Some(repo_info) = remotes
+        .iter()
+        .filter(|r| r.host == Some(RemoteHost::Gitea))
+        .find_map(|r| GtRepoInfo::from_remote_url(&r.url))
+        && let Some(gitea) = GiteaBackend::new(repo_info)
+    {
+        let mut combined = ForgeCombinedBackend::new(GitBackend::new(git_repo), gitea);
+        combined.set_notice_callback(notice_cb);
+        return Ok(Box::new(combined));
+    }
+
+    // And Bitbucket Cloud
+    if let Some(repo_info) = remotes
+        .iter()
+        .filter(|r| r.host == Some(RemoteHost::Bitbucket))
+        .find_map(|r| BbRepoInfo::from_remote_url(&r.url))
+        && let Some(bitbucket) = BitbucketBackend::new(repo_info)
+    {
+        let mut combined = ForgeCombinedBackend::new(GitBackend::new(git_repo), bitbucket);
+        combined.set_notice_callback(notice_cb);
+        return Ok(Box::new(combined));
+    }
+
+    // No supported remote - analyze what we have
     let git = GitBackend::new(git_repo);
     let unique_hosts: HashSet<Option<RemoteHost>> = remotes.iter().map(|r| r.host).collect();
 
@@ -0,0 +1,196 @@
+//! Choosing releases among the tags that contain a commit.
+//!
+//! Shared by every backend that gathers tag candidates itself, so "best release"
+//! means the same thing whether tags come from a local clone or a forge API.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Utc};
+
+use crate::git::TagInfo;
+
+/// Pick the best tag from candidates based on priority rules.
+///
+/// Priority: released semver > unreleased semver > released non-semver > unreleased
+/// non-semver, earliest first within each group. `timestamps` maps tag commit
+/// hashes to commit times; tags without one sort last.
+pub(super) fn pick_best_tag(
+    candidates: &[TagInfo],
+    timestamps: &HashMap<String, i64>,
+) -> Option<TagInfo> {
+    candidates
+        .iter()
+        .min_by_key(|tag| (priority(tag), timestamp(tag, timestamps)))
+        .cloned()
+}
+
+/// Pick the newest tag shipped at or before `at`.
+///
+/// A release ships when it's published, a plain tag when its commit was made.
+/// Priority groups are the same as for `pick_best_tag`, newest first within each.
+pub(super) fn pick_release_at(candidates: &[TagInfo], at: DateTime<Utc>) -> Option<TagInfo> {
+    candidates
+        .iter()
+        .filter(|tag| shipped_at(tag) <= at)
+        .min_by_key(|tag| (priority(tag), Reverse(shipped_at(tag))))
+        .cloned()
+}
+
+/// When a tag became available: its release's publication, else its commit time.
+pub(super) fn shipped_at(tag: &TagInfo) -> DateTime<Utc> {
+    tag.published_at.unwrap_or(tag.created_at)
+}
+
+/// Priority group of a tag, lower is better.
+const fn priority(tag: &TagInfo) -> u8 {
+    match (tag.is_semver(), tag.is_release) {
+        (true, true) => 0,
+        (true, false) => 1,
+        (false, true) => 2,
+        (false, false) => 3,
+    }
+}
+
+/// Pick the best tag of every release line, earliest first.
+///
+/// A release line is a tag prefix plus major version (`v1`, `v2`, `py-v0`), so
+/// the answer for a monorepo can be both `py-v0.9.0` and `rust-v0.3.1`. Without
+/// semver candidates there are no lines, and the single best tag is returned.
+pub(super) fn pick_release_lines(
+    candidates: &[TagInfo],
+    timestamps: &HashMap<String, i64>,
+) -> Vec<TagInfo> {
+    let mut lines: BTreeMap<(Option<&str>, u32), Vec<TagInfo>> = BTreeMap::new();
+    for tag in candidates {
+        if let Some(line) = release_line(tag) {
+            lines.entry(line).or_default().push(tag.clone());
+        }
+    }
+
+    if lines.is_empty() {
+        return pick_best_tag(candidates, timestamps).into_iter().collect();
+    }
+
+    let mut picks: Vec<TagInfo> = lines
+        .values()
+        .filter_map(|tags| pick_best_tag(tags, timestamps))
+        .collect();
+    picks.sort_by_key(|tag| timestamp(tag, timestamps));
+    picks
+}
+
+/// The release line of a semver tag: its component prefix and major version.
+fn release_line(tag: &TagInfo) -> Option<(Option<&str>, u32)> {
+    let semver = tag.semver_info.as_ref()?;
+    Some((semver.prefix.as_deref(), semver.major))
+}
+
+fn timestamp(tag: &TagInfo, timestamps: &HashMap<String, i64>) -> i64 {
+    timestamps
+        .get(&tag.commit_hash)
+        .copied()
+        .unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::semver::parse_semver;
+    use chrono::Utc;
+    use rstest::rstest;
+
+    /// A tag on a commit named after its timestamp, so tests can order them.
+    fn make_tag(name: &str, time: i64, is_release: bool) -> TagInfo {
+        TagInfo {
+            name: name.to_string(),
+            commit_hash: format!("commit-{time}"),
+            semver_info: parse_semver(name),
+            created_at: Utc::now(),
+            is_release,
+            release_name: None,
+            release_url: None,
+            published_at: None,
+            tag_url: None,
+        }
+    }
+
+    fn timestamps(tags: &[TagInfo]) -> HashMap<String, i64> {
+        tags.iter()
+            .map(|tag| {
+                let time = tag.commit_hash["commit-".len()..].parse().unwrap();
+                (tag.commit_hash.clone(), time)
+            })
+            .collect()
+    }
+
+    fn names(tags: &[TagInfo]) -> Vec<&str> {
+        tags.iter().map(|tag| tag.name.as_str()).collect()
+    }
+
+    #[rstest]
+    #[case::earliest_semver(&[("v1.1.0", 2, false), ("v1.0.0", 1, false)], "v1.0.0")]
+    #[case::release_beats_earlier_tag(&[("v1.0.0", 1, false), ("v1.1.0", 2, true)], "v1.1.0")]
+    #[case::semver_beats_release(&[("nightly", 1, true), ("v1.0.0", 2, false)], "v1.0.0")]
+    #[case::non_semver_fallback(&[("deploy-2", 2, false), ("deploy-1", 1, false)], "deploy-1")]
+    fn picks_best_tag(#[case] tags: &[(&str, i64, bool)], #[case] expected: &str) {
+        let tags: Vec<TagInfo> = tags
+            .iter()
+            .map(|&(name, time, is_release)| make_tag(name, time, is_release))
+            .collect();
+        let best = pick_best_tag(&tags, &timestamps(&tags)).unwrap();
+        assert_eq!(best.name, expected);
+    }
+
+    #[test]
+    fn picks_newest_release_at_date() {
+        let at = |secs| DateTime::from_timestamp(secs, 0).unwrap();
+        let tag = |name, time: i64, published: Option<i64>| {
+            let mut tag = make_tag(name, time, published.is_some());
+            tag.created_at = at(time);
+            tag.published_at = published.map(at);
+            tag
+        };
+        let tags = vec![
+            tag("v1.0.0", 100, Some(150)),
+            tag("v1.1.0", 200, None),
+            tag("v1.2.0", 300, Some(500)),
+            tag("nightly", 400, None),
+        ];
+
+        // v1.2.0 was tagged at 300 but only published at 500
+        assert_eq!(pick_release_at(&tags, at(450)).unwrap().name, "v1.0.0");
+        assert_eq!(pick_release_at(&tags, at(500)).unwrap().name, "v1.2.0");
+        assert!(pick_release_at(&tags, at(120)).is_none());
+
+        let plain = vec![tag("v1.1.0", 200, None), tag("nightly", 400, None)];
+        assert_eq!(pick_release_at(&plain, at(450)).unwrap().name, "v1.1.0");
+    }
+
+    #[test]
+    fn picks_earliest_release_per_line() {
+        let tags = vec![
+            make_tag("rust-v0.4.0", 5, false),
+            make_tag("v2.0.0", 4, false),
+            make_tag("py-v0.9.0", 3, false),
+            make_tag("rust-v0.3.1", 2, false),
+            make_tag("v1.4.0", 1, false),
+            make_tag("nightly", 0, false),
+        ];
+        let lines = pick_release_lines(&tags, &timestamps(&tags));
+        assert_eq!(
+            names(&lines),
+            ["v1.4.0", "rust-v0.3.1", "py-v0.9.0", "v2.0.0"]
+        );
+    }
+
+    #[test]
+    fn release_lines_without_semver_fall_back_to_best_tag() {
+        let tags = vec![
+            make_tag("deploy-2", 2, false),
+            make_tag("deploy-1", 1, false),
+        ];
+        let lines = pick_release_lines(&tags, &timestamps(&tags));
+        assert_eq!(names(&lines), ["deploy-1"]);
+    }
+}
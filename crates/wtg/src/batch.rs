@@ -0,0 +1,121 @@
+//! Batch mode: resolve many queries in one invocation.
+//!
+//! Queries that target the same repository share one backend, and with it the
+//! GitHub client, the peeled tag list and release lookups. Queries are resolved
+//! concurrently, at most `jobs` at a time, and yielded in input order.
+
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+use futures::stream::{self, Stream, StreamExt};
+
+use crate::backend::Backend;
+use crate::branch_filter::BranchFilter;
+use crate::error::WtgResult;
+use crate::parse_input::{ParsedInput, RemoteRepo, try_parse_input};
+use crate::release_filter::ReleaseFilter;
+use crate::resolution::{IdentifiedThing, resolve, resolve_branches};
+
+/// Result of one batch query.
+#[derive(Debug)]
+pub struct BatchEntry {
+    /// The query exactly as given
+    pub input: String,
+    pub outcome: WtgResult<IdentifiedThing>,
+}
+
+/// Backends keyed by the repository a query targets (`None` for the local repo).
+type BackendCache = HashMap<Option<RemoteRepo>, Arc<dyn Backend>>;
+
+/// Resolve `inputs` against shared backends from `make_backend`, yielding
+/// entries in input order. [`Wtg::resolve_batch`](crate::Wtg::resolve_batch)
+/// does this with the backends the CLI uses.
+///
+/// `make_backend` is called once per distinct repository among the inputs.
+/// Parsing and backend creation happen upfront; failures there are reported
+/// per entry, like resolution failures, instead of aborting the batch.
+/// With a `branch_filter`, results also list the branches containing the commit.
+pub fn resolve_batch_with<'a>(
+    inputs: Vec<String>,
+    repo_url: Option<&str>,
+    filter: &'a ReleaseFilter,
+    branch_filter: Option<&'a BranchFilter>,
+    jobs: NonZeroUsize,
+    make_backend: &mut dyn FnMut(&ParsedInput) -> WtgResult<Box<dyn Backend>>,
+) -> impl Stream<Item = BatchEntry> + use<'a> {
+    resolve_batch_parsed_with(
+        inputs,
+        &|input| try_parse_input(input, repo_url),
+        filter,
+        branch_filter,
+        jobs,
+        make_backend,
+    )
+}
+
+/// Like [`resolve_batch_with`], but with a custom parser for the inputs.
+pub(crate) fn resolve_batch_parsed_with<'a>(
+    inputs: Vec<String>,
+    parse: &dyn Fn(&str) -> WtgResult<ParsedInput>,
+    filter: &'a ReleaseFilter,
+    branch_filter: Option<&'a BranchFilter>,
+    jobs: NonZeroUsize,
+    make_backend: &mut dyn FnMut(&ParsedInput) -> WtgResult<Box<dyn Backend>>,
+) -> impl Stream<Item = BatchEntry> + use<'a> {
+    let mut backends = BackendCache::new();
+    let prepared: Vec<_> = inputs
+        .into_iter()
+        .map(|input| {
+            let prepared = prepare(&input, parse, &mut backends, make_backend);
+            (input, prepared)
+        })
+        .collect();
+
+    stream::iter(prepared)
+        .map(move |(input, prepared)| async move {
+            let outcome = match prepared {
+                Ok((backend, parsed)) => {
+                    resolve_parsed(backend.as_ref(), &parsed, filter, branch_filter).await
+                }
+                Err(e) => Err(e),
+            };
+            BatchEntry { input, outcome }
+        })
+        .buffered(jobs.get())
+}
+
+/// Parse one input and find (or create) the backend for its repository.
+fn prepare(
+    input: &str,
+    parse: &dyn Fn(&str) -> WtgResult<ParsedInput>,
+    backends: &mut BackendCache,
+    make_backend: &mut dyn FnMut(&ParsedInput) -> WtgResult<Box<dyn Backend>>,
+) -> WtgResult<(Arc<dyn Backend>, ParsedInput)> {
+    let parsed = parse(input)?;
+    let key = parsed.remote_repo().cloned();
+
+    if let Some(backend) = backends.get(&key) {
+        return Ok((Arc::clone(backend), parsed));
+    }
+
+    // Failures aren't cached: the next query for this repo retries and gets its own error
+    let backend: Arc<dyn Backend> = make_backend(&parsed)?.into();
+    backends.insert(key, Arc::clone(&backend));
+    Ok((backend, parsed))
+}
+
+async fn resolve_parsed(
+    backend: &dyn Backend,
+    parsed: &ParsedInput,
+    filter: &ReleaseFilter,
+    branch_filter: Option<&BranchFilter>,
+) -> WtgResult<IdentifiedThing> {
+    let query = backend.disambiguate_query(parsed.query()).await?;
+    log::debug!("Batch query disambiguated to: {query:?}");
+    let mut result = resolve(backend, &query, filter).await?;
+    if let Some(branch_filter) = branch_filter {
+        resolve_branches(backend, &mut result, branch_filter).await;
+    }
+    Ok(result)
+}
@@ -0,0 +1,499 @@
+//! Bitbucket Cloud REST API (2.0) client.
+//!
+//! Bitbucket has no releases and its issue tracker is rarely enabled, so the
+//! client sticks to commits, pull requests and tags. Only bitbucket.org is
+//! supported; Bitbucket Data Center speaks a different API.
+
+use std::env;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::LogError;
+use crate::forge_http::{ForgeAuth, ForgeHttp, encode};
+use crate::git::{CommitInfo, TagInfo, parse_semver};
+use crate::github::PullRequestInfo;
+
+const TAGS_PER_PAGE: usize = 100;
+
+/// Web host of Bitbucket Cloud.
+pub const BITBUCKET_ORG: &str = "bitbucket.org";
+
+/// API root of Bitbucket Cloud.
+pub const BITBUCKET_API: &str = "https://api.bitbucket.org/2.0";
+
+/// A Bitbucket Cloud repository: workspace plus repository slug.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct BbRepoInfo {
+    workspace: String,
+    repo: String,
+}
+
+impl BbRepoInfo {
+    #[must_use]
+    pub fn new(workspace: &str, repo: &str) -> Self {
+        Self {
+            workspace: workspace.to_string(),
+            repo: repo.trim_end_matches(".git").to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn workspace(&self) -> &str {
+        &self.workspace
+    }
+
+    #[must_use]
+    pub fn repo(&self) -> &str {
+        &self.repo
+    }
+
+    /// Parse a git remote URL pointing at a Bitbucket Cloud repository.
+    ///
+    /// Supports `https://[user@]bitbucket.org/ws/repo(.git)`,
+    /// `git@bitbucket.org:ws/repo(.git)` and `ssh://git@bitbucket.org/ws/repo(.git)`.
+    #[must_use]
+    pub fn from_remote_url(url: &str) -> Option<Self> {
+        let url = url.trim();
+
+        let (host, path) = if let Some(rest) = url.strip_prefix("git@") {
+            let (host, path) = rest.split_once(':')?;
+            (host.to_string(), path.to_string())
+        } else {
+            let parsed = url::Url::parse(url).ok()?;
+            (parsed.host_str()?.to_string(), parsed.path().to_string())
+        };
+        if !is_bitbucket_host(&host) {
+            return None;
+        }
+
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let [workspace, repo] = segments.as_slice() else {
+            return None;
+        };
+        Some(Self::new(workspace, repo))
+    }
+
+    /// Repository route under the API root.
+    fn api_path(&self, resource: &str) -> String {
+        format!(
+            "repositories/{}/{}/{resource}",
+            encode(&self.workspace),
+            encode(&self.repo)
+        )
+    }
+
+    /// Web URL of the repository.
+    fn web_url(&self) -> String {
+        format!("https://{BITBUCKET_ORG}/{}/{}", self.workspace, self.repo)
+    }
+}
+
+/// Check whether `host` is Bitbucket Cloud.
+#[must_use]
+pub fn is_bitbucket_host(host: &str) -> bool {
+    host.trim_start_matches("www.")
+        .eq_ignore_ascii_case(BITBUCKET_ORG)
+}
+
+// ============================================
+// API response types
+// ============================================
+
+#[derive(Debug, Deserialize)]
+struct BbLink {
+    href: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbLinks {
+    html: Option<BbLink>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbUser {
+    nickname: Option<String>,
+    display_name: Option<String>,
+    links: Option<BbLinks>,
+}
+
+impl BbUser {
+    fn login(&self) -> Option<String> {
+        self.nickname.clone().or_else(|| self.display_name.clone())
+    }
+
+    fn html_url(&self) -> Option<String> {
+        self.links
+            .as_ref()
+            .and_then(|l| l.html.as_ref())
+            .map(|l| l.href.clone())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BbCommitAuthor {
+    /// Git author line, `Name <email>`
+    raw: Option<String>,
+    user: Option<BbUser>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbCommit {
+    hash: String,
+    message: Option<String>,
+    date: Option<DateTime<Utc>>,
+    author: Option<BbCommitAuthor>,
+    links: Option<BbLinks>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbCommitRef {
+    hash: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbPullRequest {
+    id: u64,
+    title: String,
+    description: Option<String>,
+    /// `OPEN`, `MERGED`, `DECLINED` or `SUPERSEDED`
+    state: String,
+    links: Option<BbLinks>,
+    merge_commit: Option<BbCommitRef>,
+    author: Option<BbUser>,
+    created_on: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbTagTarget {
+    hash: String,
+    date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbTag {
+    name: String,
+    target: BbTagTarget,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbPage<T> {
+    values: Vec<T>,
+    next: Option<String>,
+}
+
+/// Split a git author line (`Name <email>`) into name and email.
+fn split_author(raw: &str) -> (String, Option<String>) {
+    raw.rsplit_once('<').map_or_else(
+        || (raw.trim().to_string(), None),
+        |(name, email)| {
+            (
+                name.trim().to_string(),
+                Some(email.trim_end_matches('>').trim().to_string()),
+            )
+        },
+    )
+}
+
+impl From<BbCommit> for CommitInfo {
+    fn from(commit: BbCommit) -> Self {
+        let full_message = commit.message.unwrap_or_default();
+        let (author_name, author_email) = commit
+            .author
+            .as_ref()
+            .and_then(|a| a.raw.as_deref())
+            .map_or_else(|| ("Unknown".to_string(), None), split_author);
+        let user = commit.author.and_then(|a| a.user);
+
+        Self {
+            short_hash: commit.hash[..7.min(commit.hash.len())].to_string(),
+            message: full_message.lines().next().unwrap_or("").to_string(),
+            message_lines: full_message.lines().count(),
+            commit_url: commit.links.and_then(|l| l.html).map(|l| l.href),
+            author_name,
+            author_email,
+            author_login: user.as_ref().and_then(BbUser::login),
+            author_url: user.as_ref().and_then(BbUser::html_url),
+            date: commit.date.unwrap_or_else(Utc::now),
+            hash: commit.hash,
+        }
+    }
+}
+
+impl From<BbPullRequest> for PullRequestInfo {
+    fn from(pr: BbPullRequest) -> Self {
+        let merged = pr.state == "MERGED";
+
+        Self {
+            number: pr.id,
+            repo_info: None,
+            title: pr.title,
+            body: pr.description.filter(|d| !d.is_empty()),
+            // Match the GitHub vocabulary: declined and superseded PRs are closed
+            state: match pr.state.as_str() {
+                "OPEN" => "open".to_string(),
+                "MERGED" => "merged".to_string(),
+                _ => "closed".to_string(),
+            },
+            url: pr
+                .links
+                .and_then(|l| l.html)
+                .map(|l| l.href)
+                .unwrap_or_default(),
+            merged,
+            merge_commit_sha: pr.merge_commit.filter(|_| merged).map(|c| c.hash),
+            author: pr.author.as_ref().and_then(BbUser::login),
+            author_url: pr.author.as_ref().and_then(BbUser::html_url),
+            created_at: pr.created_on,
+            labels: Vec::new(),
+        }
+    }
+}
+
+// ============================================
+// Client
+// ============================================
+
+/// Bitbucket Cloud API client.
+pub struct BitbucketClient {
+    /// Talks to the API root, e.g. `https://api.bitbucket.org/2.0`
+    http: ForgeHttp,
+}
+
+impl BitbucketClient {
+    /// Create a client for Bitbucket Cloud.
+    ///
+    /// Authenticates with `BITBUCKET_TOKEN` (an access token) or with
+    /// `BITBUCKET_USERNAME` plus `BITBUCKET_APP_PASSWORD`; works anonymously
+    /// otherwise. Returns `None` if the HTTP client cannot be built.
+    #[must_use]
+    pub fn new() -> Option<Self> {
+        let var = |name: &str| env::var(name).ok().filter(|v| !v.trim().is_empty());
+        let auth = var("BITBUCKET_TOKEN").map(ForgeAuth::Bearer).or_else(|| {
+            Some(ForgeAuth::Basic {
+                username: var("BITBUCKET_USERNAME")?,
+                password: var("BITBUCKET_APP_PASSWORD")?,
+            })
+        });
+        Self::build(BITBUCKET_API, auth)
+    }
+
+    /// Create a client for the API at `api_url` with an explicit access token.
+    #[must_use]
+    pub fn with_token(api_url: &str, token: Option<String>) -> Option<Self> {
+        Self::build(api_url, token.map(ForgeAuth::Bearer))
+    }
+
+    fn build(api_url: &str, auth: Option<ForgeAuth>) -> Option<Self> {
+        Some(Self {
+            http: ForgeHttp::new(api_url, auth)?,
+        })
+    }
+
+    /// Fetch a commit by (possibly abbreviated) SHA or ref name.
+    pub async fn fetch_commit(&self, repo_info: &BbRepoInfo, sha: &str) -> Option<CommitInfo> {
+        let commit: BbCommit = self
+            .http
+            .get_json(&repo_info.api_path(&format!("commit/{}", encode(sha))))
+            .await
+            .log_err(&format!(
+                "fetch_commit failed for {}/{} commit {sha}",
+                repo_info.workspace(),
+                repo_info.repo()
+            ))?;
+
+        Some(commit.into())
+    }
+
+    /// Fetch a pull request by id.
+    pub async fn fetch_pull_request(
+        &self,
+        repo_info: &BbRepoInfo,
+        id: u64,
+    ) -> Option<PullRequestInfo> {
+        let pr: BbPullRequest = self
+            .http
+            .get_json(&repo_info.api_path(&format!("pullrequests/{id}")))
+            .await
+            .log_err(&format!(
+                "fetch_pull_request failed for {}/{} PR #{id}",
+                repo_info.workspace(),
+                repo_info.repo()
+            ))?;
+
+        Some(pr.into())
+    }
+
+    /// Fetch the merged pull request that introduced a commit.
+    pub async fn fetch_pull_request_for_commit(
+        &self,
+        repo_info: &BbRepoInfo,
+        sha: &str,
+    ) -> Option<PullRequestInfo> {
+        let prs: BbPage<BbPullRequest> = self
+            .http
+            .get_json(&repo_info.api_path(&format!("commit/{}/pullrequests", encode(sha))))
+            .await
+            .log_err(&format!(
+                "fetch_pull_request_for_commit failed for {}/{} commit {sha}",
+                repo_info.workspace(),
+                repo_info.repo()
+            ))?;
+
+        prs.values
+            .into_iter()
+            .map(PullRequestInfo::from)
+            .find(|pr| pr.merged)
+    }
+
+    /// Fetch a tag by name.
+    pub async fn fetch_tag(&self, repo_info: &BbRepoInfo, tag_name: &str) -> Option<TagInfo> {
+        let tag: BbTag = self
+            .http
+            .get_json(&repo_info.api_path(&format!("refs/tags/{}", encode(tag_name))))
+            .await
+            .log_err(&format!(
+                "fetch_tag failed for {}/{} tag {tag_name}",
+                repo_info.workspace(),
+                repo_info.repo()
+            ))?;
+
+        Some(Self::tag_info(repo_info, tag))
+    }
+
+    /// Fetch tags pointing at commits made at or after `since_date`, oldest first.
+    pub async fn fetch_tags_since(
+        &self,
+        repo_info: &BbRepoInfo,
+        since_date: DateTime<Utc>,
+    ) -> Vec<TagInfo> {
+        let mut tags = Vec::new();
+
+        'pages: for page_num in 1u32.. {
+            let Ok(page) = self
+                .http
+                .get_json::<BbPage<BbTag>>(&repo_info.api_path(&format!(
+                    "refs/tags?sort=-target.date&pagelen={TAGS_PER_PAGE}&page={page_num}"
+                )))
+                .await
+            else {
+                break;
+            };
+            let is_last_page = page.next.is_none();
+
+            for tag in page.values {
+                if tag.target.date.unwrap_or_default() < since_date {
+                    break 'pages;
+                }
+                tags.push(Self::tag_info(repo_info, tag));
+            }
+
+            if is_last_page {
+                break;
+            }
+        }
+
+        tags.reverse();
+        tags
+    }
+
+    /// Check if a tag or branch contains a specific commit.
+    ///
+    /// The commit is in the ref's history when it is their merge base.
+    pub async fn ref_contains_commit(
+        &self,
+        repo_info: &BbRepoInfo,
+        git_ref: &str,
+        commit: &str,
+    ) -> bool {
+        let Ok(base) = self
+            .http
+            .get_json::<BbCommitRef>(&repo_info.api_path(&format!(
+                "merge-base/{}..{}",
+                encode(commit),
+                encode(git_ref)
+            )))
+            .await
+        else {
+            return false;
+        };
+        base.hash.starts_with(commit) || commit.starts_with(&base.hash)
+    }
+
+    fn tag_info(repo_info: &BbRepoInfo, tag: BbTag) -> TagInfo {
+        TagInfo {
+            semver_info: parse_semver(&tag.name),
+            tag_url: Some(Self::tag_url(repo_info, &tag.name)),
+            name: tag.name,
+            commit_hash: tag.target.hash,
+            created_at: tag.target.date.unwrap_or_else(Utc::now),
+            is_release: false,
+            release_name: None,
+            release_url: None,
+            published_at: None,
+        }
+    }
+
+    // ============================================
+    // URL builders
+    // ============================================
+
+    /// Build a commit URL. Uses URL encoding to prevent injection.
+    #[must_use]
+    pub fn commit_url(repo_info: &BbRepoInfo, hash: &str) -> String {
+        format!("{}/commits/{}", repo_info.web_url(), encode(hash))
+    }
+
+    /// Build a tag URL pointing to the source view.
+    #[must_use]
+    pub fn tag_url(repo_info: &BbRepoInfo, tag: &str) -> String {
+        format!("{}/src/{}", repo_info.web_url(), encode(tag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("https://bitbucket.org/ws/repo.git")]
+    #[case("https://user@bitbucket.org/ws/repo.git")]
+    #[case("git@bitbucket.org:ws/repo.git")]
+    #[case("ssh://git@bitbucket.org/ws/repo")]
+    fn parses_remote_urls(#[case] url: &str) {
+        let info = BbRepoInfo::from_remote_url(url).expect("should parse");
+        assert_eq!(info.workspace(), "ws");
+        assert_eq!(info.repo(), "repo");
+    }
+
+    #[rstest]
+    #[case("https://bitbucket.org/ws")]
+    #[case("https://github.com/ws/repo")]
+    #[case("not a url")]
+    fn rejects_non_repo_urls(#[case] url: &str) {
+        assert!(BbRepoInfo::from_remote_url(url).is_none());
+    }
+
+    #[test]
+    fn builds_urls() {
+        let info = BbRepoInfo::new("ws", "repo");
+        assert_eq!(
+            BitbucketClient::commit_url(&info, "abc123"),
+            "https://bitbucket.org/ws/repo/commits/abc123"
+        );
+        assert_eq!(
+            BitbucketClient::tag_url(&info, "v1"),
+            "https://bitbucket.org/ws/repo/src/v1"
+        );
+    }
+
+    #[rstest]
+    #[case("Doc Brown <doc@example.com>", "Doc Brown", Some("doc@example.com"))]
+    #[case("Doc Brown", "Doc Brown", None)]
+    fn splits_author_lines(#[case] raw: &str, #[case] name: &str, #[case] email: Option<&str>) {
+        let (actual_name, actual_email) = split_author(raw);
+        assert_eq!(actual_name, name);
+        assert_eq!(actual_email.as_deref(), email);
+    }
+}
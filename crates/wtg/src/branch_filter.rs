@@ -0,0 +1,110 @@
+//! Branch filtering configuration.
+//!
+//! This module provides the `BranchFilter` type which controls which branches
+//! are reported when checking which branches contain a commit.
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+
+use crate::error::{WtgError, WtgResult};
+use crate::git::BranchInfo;
+
+/// Branch name globs (e.g. `release/*`) to report branch containment for.
+///
+/// An empty filter matches every branch. Patterns are matched against the full
+/// branch name and, for remote-tracking branches, the name without the remote
+/// (so `release/*` matches both `release/2.x` and `origin/release/2.x`).
+#[derive(Debug, Clone, Default)]
+pub struct BranchFilter {
+    patterns: Vec<String>,
+    globs: GlobSet,
+}
+
+impl BranchFilter {
+    /// Build a filter from glob patterns.
+    pub fn new(patterns: &[String]) -> WtgResult<Self> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            let glob = Glob::new(pattern).map_err(|e| WtgError::Cli {
+                message: format!("Invalid branch pattern '{pattern}': {e}"),
+                code: 2,
+            })?;
+            builder.add(glob);
+        }
+
+        let globs = builder.build().map_err(|e| WtgError::Cli {
+            message: format!("Invalid branch patterns: {e}"),
+            code: 2,
+        })?;
+
+        Ok(Self {
+            patterns: patterns.to_vec(),
+            globs,
+        })
+    }
+
+    /// The glob patterns this filter was built from.
+    #[must_use]
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// Whether a branch passes the filter.
+    #[must_use]
+    pub fn matches(&self, branch: &BranchInfo) -> bool {
+        self.patterns.is_empty()
+            || self.globs.is_match(&branch.name)
+            || self.globs.is_match(branch.short_name())
+    }
+
+    /// Filter a list of branches.
+    #[must_use]
+    pub fn filter_branches(&self, branches: Vec<BranchInfo>) -> Vec<BranchInfo> {
+        branches
+            .into_iter()
+            .filter(|branch| self.matches(branch))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn branch(name: &str, is_remote: bool) -> BranchInfo {
+        BranchInfo {
+            name: name.to_string(),
+            is_remote,
+        }
+    }
+
+    #[test]
+    fn empty_filter_matches_everything() {
+        let filter = BranchFilter::default();
+        assert!(filter.matches(&branch("main", false)));
+        assert!(filter.matches(&branch("origin/feature/x", true)));
+    }
+
+    #[rstest]
+    #[case::local("release/*", "release/2.x", false, true)]
+    #[case::remote_short_name("release/*", "origin/release/2.x", true, true)]
+    #[case::remote_full_name("upstream/*", "upstream/main", true, true)]
+    #[case::no_match("release/*", "main", false, false)]
+    #[case::local_slash_is_not_a_remote("release/*", "origin/release/2.x", false, false)]
+    #[case::alternatives("{main,master}", "origin/master", true, true)]
+    fn matches_globs(
+        #[case] pattern: &str,
+        #[case] name: &str,
+        #[case] is_remote: bool,
+        #[case] expected: bool,
+    ) {
+        let filter = BranchFilter::new(&[pattern.to_string()]).unwrap();
+        assert_eq!(filter.matches(&branch(name, is_remote)), expected);
+    }
+
+    #[test]
+    fn rejects_invalid_globs() {
+        let err = BranchFilter::new(&["release/[".to_string()]).unwrap_err();
+        assert!(err.is_cli());
+    }
+}
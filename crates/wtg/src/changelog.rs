@@ -8,7 +8,7 @@
 //! See <https://keepachangelog.com> for format specification.
 
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
 use regex::Regex;
@@ -41,12 +41,18 @@ pub const MAX_LINES: usize = 20;
 #[must_use]
 pub fn parse_changelog_for_version(repo_root: &Path, version: &str) -> Option<String> {
     let changelog_path = find_changelog_file(repo_root)?;
-    let content = fs::read_to_string(changelog_path).ok()?;
+    parse_changelog_file(&changelog_path, version)
+}
+
+/// Extract the changelog section for `version` from the changelog at `path`.
+#[must_use]
+pub fn parse_changelog_file(path: &Path, version: &str) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
     extract_version_section(&content, version)
 }
 
-/// Find CHANGELOG.md file (case-insensitive) at repo root.
-fn find_changelog_file(repo_root: &Path) -> Option<std::path::PathBuf> {
+/// Find CHANGELOG.md (case-insensitive) at repo root.
+fn find_changelog_file(repo_root: &Path) -> Option<PathBuf> {
     let entries = fs::read_dir(repo_root).ok()?;
     for entry in entries.flatten() {
         let name = entry.file_name();
@@ -1,22 +1,41 @@
-use clap::Parser;
+use std::io::{self, BufRead};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use clap::{Parser, Subcommand};
 
 use crate::{
+    branch_filter::BranchFilter,
+    config::{Overrides, Setting, Settings, Source},
     constants,
     error::{WtgError, WtgResult},
-    parse_input::{ParsedInput, try_parse_input},
+    output::OutputFormat,
+    release_filter::TagPatterns,
+    remote::ForgeHosts,
+    resolution::HistoryPage,
 };
 
 #[derive(Parser, Debug)]
+#[allow(clippy::struct_excessive_bools)] // CLI flags
 #[command(
     name = "wtg",
     version,
     about = constants::DESCRIPTION,
     disable_help_flag = true,
+    disable_help_subcommand = true,
+    args_conflicts_with_subcommands = true,
 )]
 pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// The thing to identify: commit hash (c62bbcc), issue/PR (#123), file path (Cargo.toml), tag (v1.2.3), or a GitHub URL
+    ///
+    /// Optionally followed by a RELEASE to check against. With `--batch`,
+    /// every positional argument is a separate query instead.
     #[arg(value_name = "COMMIT|ISSUE|FILE|TAG|URL")]
-    pub input: Option<String>,
+    pub inputs: Vec<String>,
 
     /// GitHub repository URL to operate on (e.g., <https://github.com/owner/repo>)
     #[arg(short = 'r', long, value_name = "URL")]
@@ -33,26 +52,323 @@ pub struct Cli {
     ///
     /// Filters out tags with pre-release identifiers (e.g., -beta, -rc, -alpha)
     /// when determining which release contains a commit.
-    #[arg(short = 'S', long)]
+    #[arg(short = 'S', long, overrides_with = "no_skip_prereleases")]
     pub skip_prereleases: bool,
 
+    /// Count pre-release tags, even if the config skips them
+    #[arg(long, overrides_with = "skip_prereleases")]
+    pub no_skip_prereleases: bool,
+
+    /// Output format [default: human]
+    ///
+    /// `json` prints a single JSON document with the result and a `notices`
+    /// array instead of colored prose and stderr warnings.
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    pub format: Option<OutputFormat>,
+
     /// Specific tag/release to check against
     ///
     /// If provided, checks whether the input (commit, PR, issue) is contained
     /// in this specific release/tag rather than finding the earliest release.
-    #[arg(value_name = "RELEASE")]
+    /// Same as the positional RELEASE argument, but also works in batch mode.
+    #[arg(long, value_name = "TAG")]
     pub release: Option<String>,
 
+    /// Only consider tags of one component, e.g. `py` for `py-v1.2.0` tags
+    ///
+    /// Matches the prefix of semver tags, with or without its separator:
+    /// `py`, `py-` and `py-v` all select `py-v1.2.0`, and `sdk/go` selects
+    /// `sdk/go/v1.2.3`. Non-semver tags never match.
+    #[arg(long, value_name = "PREFIX")]
+    pub tag_prefix: Option<String>,
+
+    /// Only consider tags matching this glob, e.g. `v*` (repeatable)
+    ///
+    /// Wrap a pattern in slashes to use a regex instead: `/^v\d+\.\d+\.\d+$/`.
+    #[arg(long = "include-tag", value_name = "PATTERN")]
+    pub include_tags: Vec<String>,
+
+    /// Never consider tags matching this glob, e.g. `nightly-*` (repeatable)
+    ///
+    /// Wrap a pattern in slashes to use a regex instead: `/^deploy-/`.
+    #[arg(long = "exclude-tag", value_name = "PATTERN")]
+    pub exclude_tags: Vec<String>,
+
+    /// Only count tags that have a release on the forge (GitHub, GitLab, ...)
+    #[arg(long, overrides_with = "no_require_release")]
+    pub require_release: bool,
+
+    /// Count tags without a forge release, even if the config requires one
+    #[arg(long, overrides_with = "require_release")]
+    pub no_require_release: bool,
+
+    /// Report the earliest release of every release line
+    ///
+    /// A release line is a tag prefix plus major version, e.g. `v1.x` and
+    /// `v2.x`, or `py-v0.x` and `rust-v0.x` in a monorepo.
+    #[arg(short = 'A', long)]
+    pub all_releases: bool,
+
+    /// Also list the branches that contain the commit
+    ///
+    /// Checks local and remote-tracking branches, or asks the GitHub compare
+    /// API branch by branch when there's no local clone.
+    #[arg(long)]
+    pub branches: bool,
+
+    /// Only list branches matching this glob, e.g. `release/*` (implies `--branches`)
+    ///
+    /// Can be given several times. Remote-tracking branches match with or
+    /// without their remote prefix.
+    #[arg(long = "branch", value_name = "GLOB")]
+    pub branch_patterns: Vec<String>,
+
+    /// For file queries, list the commits that changed the file instead
+    ///
+    /// Each commit comes with its pull request and first release. Renames are
+    /// followed, so the history goes back past the file's current name.
+    #[arg(long)]
+    pub history: bool,
+
+    /// Page of the file history to show, most recent first (implies `--history`)
+    #[arg(long, value_name = "N")]
+    pub page: Option<NonZeroUsize>,
+
+    /// Number of commits per page of file history
+    #[arg(long, value_name = "N", default_value = "20")]
+    pub page_size: NonZeroUsize,
+
+    /// Resolve every positional argument as a separate query
+    #[arg(short = 'b', long)]
+    pub batch: bool,
+
+    /// Read queries from stdin, one per line (implies `--batch`)
+    ///
+    /// Blank lines and lines starting with `#` are ignored.
+    #[arg(long)]
+    pub stdin: bool,
+
+    /// Maximum number of queries resolved concurrently in batch mode
+    #[arg(short = 'j', long, value_name = "N", default_value = "8")]
+    pub jobs: NonZeroUsize,
+
+    /// Don't read or write the on-disk GitHub API cache
+    #[arg(long, overrides_with = "cache")]
+    pub no_cache: bool,
+
+    /// Use the on-disk GitHub API cache, even if the config disables it
+    #[arg(long, overrides_with = "no_cache")]
+    pub cache: bool,
+
+    /// Record every GitHub API request and response into this directory
+    ///
+    /// Tokens are left out, so the recording can be attached to a bug report
+    /// and replayed with `--replay`. Only GitHub API traffic is recorded:
+    /// remote repositories are still cloned, and a replay clones them again.
+    #[arg(long, value_name = "DIR", conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+
+    /// Answer GitHub API requests from a `--record` directory, offline
+    #[arg(long, value_name = "DIR")]
+    pub replay: Option<PathBuf>,
+
     /// Print help information
     #[arg(short, long, action = clap::ArgAction::Help)]
     help: Option<bool>,
+
+    /// Self-hosted forges, filled in from the environment and the user config
+    #[arg(skip)]
+    pub forge_hosts: ForgeHosts,
+
+    /// How long cached API responses are served without revalidating, from the config
+    #[arg(skip)]
+    pub cache_ttl: Duration,
+
+    /// Remote that beats upstream and origin, from the config
+    #[arg(skip)]
+    pub remote: Option<String>,
+
+    /// Changelog location relative to the repository root, from the config
+    #[arg(skip)]
+    pub changelog: Option<PathBuf>,
+}
+
+/// Maintenance commands. A query that happens to match a command name can
+/// still be looked up after `--`, e.g. `wtg -- cache`.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Manage the on-disk GitHub API cache
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// Inspect the configuration files
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CacheAction {
+    /// Delete all cached GitHub API responses
+    Clear,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigAction {
+    /// Print the effective settings and where each one comes from
+    Show,
 }
 
 impl Cli {
-    /// Parse the input and -r flag to determine the repository and query
-    pub(crate) fn parse_input(&self) -> WtgResult<ParsedInput> {
-        let input = self.input.as_ref().ok_or_else(|| WtgError::EmptyInput)?;
+    /// Whether several queries should be resolved in one go.
+    #[must_use]
+    pub const fn is_batch(&self) -> bool {
+        self.batch || self.stdin
+    }
+
+    /// The output format to render results with.
+    #[must_use]
+    pub fn format(&self) -> OutputFormat {
+        self.format.unwrap_or_default()
+    }
+
+    /// Settings given as flags, which beat every other source.
+    pub(crate) fn overrides(&self) -> Overrides {
+        const fn flag<T>(value: T) -> Setting<T> {
+            Setting::new(value, Source::Flag)
+        }
+        // Each setting has a flag and its negation; whichever came last wins
+        let either = |on: bool, off: bool| (on || off).then(|| flag(on));
+        Overrides {
+            skip_prereleases: either(self.skip_prereleases, self.no_skip_prereleases),
+            tag_prefix: self.tag_prefix.clone().map(flag),
+            include_tags: (!self.include_tags.is_empty()).then(|| flag(self.include_tags.clone())),
+            exclude_tags: (!self.exclude_tags.is_empty()).then(|| flag(self.exclude_tags.clone())),
+            require_release: either(self.require_release, self.no_require_release),
+            format: self.format.map(flag),
+            no_cache: either(self.no_cache, self.cache),
+            ..Overrides::default()
+        }
+    }
+
+    /// Replace flag values with the effective settings, so config defaults apply.
+    pub(crate) fn apply_settings(&mut self, settings: &Settings) {
+        self.skip_prereleases = settings.skip_prereleases.value;
+        self.tag_prefix.clone_from(&settings.tag_prefix.value);
+        self.include_tags.clone_from(&settings.include_tags.value);
+        self.exclude_tags.clone_from(&settings.exclude_tags.value);
+        self.require_release = settings.require_release.value;
+        self.format = Some(settings.format.value);
+        self.no_cache = !settings.cache_enabled.value;
+        self.forge_hosts = settings.forge_hosts();
+        self.cache_ttl = Duration::from_secs(settings.cache_ttl_secs.value);
+        self.remote.clone_from(&settings.remote.value);
+        self.changelog = settings.changelog_path().map(Path::to_path_buf);
+    }
+
+    /// Whether there is nothing to do (no input at all).
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.inputs.is_empty() && !self.stdin
+    }
+
+    /// The release to check against: `--release`, or the positional RELEASE outside batch mode.
+    #[must_use]
+    pub fn release(&self) -> Option<&str> {
+        self.release.as_deref().or_else(|| {
+            if self.is_batch() {
+                None
+            } else {
+                self.inputs.get(1).map(String::as_str)
+            }
+        })
+    }
+
+    /// The tag name patterns from `--include-tag` and `--exclude-tag`.
+    pub(crate) fn tag_patterns(&self) -> WtgResult<TagPatterns> {
+        TagPatterns::new(&self.include_tags, &self.exclude_tags)
+    }
+
+    /// The branch filter, if branch containment was requested.
+    pub(crate) fn branch_filter(&self) -> WtgResult<Option<BranchFilter>> {
+        if !self.branches && self.branch_patterns.is_empty() {
+            return Ok(None);
+        }
+        BranchFilter::new(&self.branch_patterns).map(Some)
+    }
+
+    /// The page of file history to show, if file history was requested.
+    #[must_use]
+    pub fn history_page(&self) -> Option<HistoryPage> {
+        if !self.history && self.page.is_none() {
+            return None;
+        }
+        Some(HistoryPage {
+            page: self.page.map_or(1, NonZeroUsize::get),
+            per_page: self.page_size.get(),
+        })
+    }
+
+    /// The query to resolve (the first input; the second one names a release)
+    pub(crate) fn query_input(&self) -> WtgResult<&str> {
+        if self.inputs.len() > 2 {
+            return Err(WtgError::Cli {
+                message: "Too many arguments: expected a query and an optional release. \
+                          Use --batch to resolve several queries at once."
+                    .to_string(),
+                code: 2,
+            });
+        }
+        self.inputs
+            .first()
+            .map(String::as_str)
+            .ok_or(WtgError::EmptyInput)
+    }
+
+    /// Collect batch queries: positional arguments first, then stdin lines if `--stdin`.
+    pub(crate) fn batch_inputs(&self) -> WtgResult<Vec<String>> {
+        let mut inputs = self.inputs.clone();
+        if self.stdin {
+            for line in io::stdin().lock().lines() {
+                let line = line?;
+                let query = line.trim();
+                if !query.is_empty() && !query.starts_with('#') {
+                    inputs.push(query.to_string());
+                }
+            }
+        }
+        Ok(inputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(&[], None)]
+    #[case(&["--skip-prereleases"], Some(true))]
+    #[case(&["--no-skip-prereleases"], Some(false))]
+    #[case(&["-S", "--no-skip-prereleases"], Some(false))]
+    #[case(&["--no-skip-prereleases", "-S"], Some(true))]
+    fn last_of_flag_and_negation_wins(#[case] args: &[&str], #[case] expected: Option<bool>) {
+        let cli = Cli::parse_from(std::iter::once(&"wtg").chain(args));
+        let overrides = cli.overrides();
+        assert_eq!(overrides.skip_prereleases.map(|s| s.value), expected);
+    }
 
-        try_parse_input(input, self.repo.as_deref())
+    #[test]
+    fn negations_override_config() {
+        let cli = Cli::parse_from(["wtg", "--no-require-release", "--cache"]);
+        let overrides = cli.overrides();
+        assert_eq!(
+            overrides.require_release,
+            Some(Setting::new(false, Source::Flag))
+        );
+        assert_eq!(overrides.no_cache, Some(Setting::new(false, Source::Flag)));
     }
 }
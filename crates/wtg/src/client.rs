@@ -0,0 +1,362 @@
+//! Library entry point: resolve queries the way the CLI does.
+//!
+//! [`Wtg`] holds everything a query needs besides the input itself (which
+//! repository, how to reach GitHub, which releases count), so embedding wtg
+//! doesn't mean wiring up parsing, backends and resolution by hand:
+//!
+//! ```no_run
+//! # async fn example() -> wtg_cli::WtgResult<()> {
+//! use wtg_cli::{IdentifiedThing, ReleaseFilter, Wtg};
+//!
+//! let wtg = Wtg::builder()
+//!     .repo("owner/repo")
+//!     .release_filter(ReleaseFilter::default().skip_prereleases(true))
+//!     .build()?;
+//!
+//! if let IdentifiedThing::Enriched(info) = wtg.resolve("#123").await? {
+//!     println!("{:?}", info.release.map(|release| release.name));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::num::NonZeroUsize;
+use std::panic;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use futures::Stream;
+
+use crate::backend::{Backend, BackendOptions, resolve_backend_with_options};
+use crate::batch::{BatchEntry, resolve_batch_parsed_with};
+use crate::branch_filter::BranchFilter;
+use crate::error::{WtgError, WtgResult};
+use crate::github::GhRepoInfo;
+use crate::notice::NoticeCallback;
+use crate::parse_input::{
+    Candidate, ParsedInput, Query, RemoteRepo, try_parse_input_in, try_parse_input_with_hosts,
+};
+use crate::recording::Recording;
+use crate::release_filter::ReleaseFilter;
+use crate::remote::ForgeHosts;
+use crate::resolution::{
+    HistoryPage, IdentifiedThing, resolve, resolve_branches, resolve_file_history,
+};
+
+/// Decides what ambiguous input meant: gets the input and every way to read
+/// it, and returns the chosen query or an error (usually
+/// [`WtgError::MultipleMatches`] with the candidates handed back).
+pub type AmbiguityCallback = Arc<dyn Fn(&str, Vec<Candidate>) -> WtgResult<Query> + Send + Sync>;
+
+/// Resolves commits, issues, PRs, files and tags to the releases that shipped them.
+///
+/// Built with [`Wtg::builder`]. One client can resolve any number of queries,
+/// concurrently too.
+pub struct Wtg {
+    /// Repository every query targets; `None` reads it from the input or the local repo
+    repo: Option<RemoteRepo>,
+    backend: BackendOptions,
+    filter: ReleaseFilter,
+    branch_filter: Option<BranchFilter>,
+    on_ambiguous: Option<AmbiguityCallback>,
+    /// Held while the ambiguity callback runs, so concurrent queries ask one at a time
+    ambiguity_lock: Arc<Mutex<()>>,
+}
+
+impl Wtg {
+    /// Start configuring a client. Without any settings, queries run against
+    /// the repository in the working directory, like `wtg <query>`.
+    #[must_use]
+    pub fn builder() -> WtgBuilder {
+        WtgBuilder::default()
+    }
+
+    /// The release filter every query uses.
+    #[must_use]
+    pub const fn release_filter(&self) -> &ReleaseFilter {
+        &self.filter
+    }
+
+    /// Identify `input` and find the release that shipped it.
+    ///
+    /// `input` is anything the CLI accepts: a commit hash, `#123`, a file path,
+    /// a tag, a forge URL, ...
+    pub async fn resolve(&self, input: &str) -> WtgResult<IdentifiedThing> {
+        let (backend, query) = self.query(input).await?;
+
+        log::debug!("Resolving query");
+        let mut result = resolve(backend.as_ref(), &query, &self.filter).await?;
+        if let Some(branch_filter) = &self.branch_filter {
+            resolve_branches(backend.as_ref(), &mut result, branch_filter).await;
+        }
+        log::debug!("Resolution complete");
+        Ok(result)
+    }
+
+    /// One page of the history of the file `input` names.
+    pub async fn file_history(&self, input: &str, page: HistoryPage) -> WtgResult<IdentifiedThing> {
+        let (backend, query) = self.query(input).await?;
+        let Query::FilePath { branch, path } = &query else {
+            return Err(WtgError::Cli {
+                message: "--history only works with file queries".to_string(),
+                code: 2,
+            });
+        };
+        resolve_file_history(
+            backend.as_ref(),
+            branch,
+            &path.to_string_lossy(),
+            page,
+            &self.filter,
+        )
+        .await
+    }
+
+    /// Resolve many queries, at most `jobs` at a time, yielding results in input order.
+    ///
+    /// Queries targeting the same repository share one backend. Ambiguous input
+    /// fails its entry instead of going to the ambiguity callback.
+    pub fn resolve_batch(
+        &self,
+        inputs: Vec<String>,
+        jobs: NonZeroUsize,
+    ) -> impl Stream<Item = BatchEntry> + '_ {
+        resolve_batch_parsed_with(
+            inputs,
+            &|input| self.parse(input),
+            &self.filter,
+            self.branch_filter.as_ref(),
+            jobs,
+            &mut |parsed| self.backend_for(parsed),
+        )
+    }
+
+    /// Parse `input` against the configured repository, if any.
+    pub fn parse(&self, input: &str) -> WtgResult<ParsedInput> {
+        self.repo.as_ref().map_or_else(
+            || try_parse_input_with_hosts(input, None, &self.backend.hosts),
+            |repo| try_parse_input_in(input, repo),
+        )
+    }
+
+    /// The backend for the repository `parsed` targets.
+    pub fn backend_for(&self, parsed: &ParsedInput) -> WtgResult<Box<dyn Backend>> {
+        log::debug!("Resolving backend (fetch={})", self.backend.allow_fetch);
+        resolve_backend_with_options(parsed, &self.backend)
+    }
+
+    /// Parse `input`, pick its backend and work out what it refers to.
+    async fn query(&self, input: &str) -> WtgResult<(Box<dyn Backend>, Query)> {
+        let parsed = self.parse(input)?;
+        log::debug!("Parsed input: {parsed:?}");
+        let backend = self.backend_for(&parsed)?;
+
+        log::debug!("Disambiguating query: {:?}", parsed.query());
+        let outcome = backend.disambiguate_query(parsed.query()).await;
+        let query = match (outcome, &self.on_ambiguous) {
+            (Err(WtgError::MultipleMatches(candidates)), Some(choose)) => {
+                self.choose(choose, input, candidates).await?
+            }
+            (outcome, _) => outcome?,
+        };
+        log::debug!("Disambiguated to: {query:?}");
+        Ok((backend, query))
+    }
+
+    /// Run the ambiguity callback off the async workers, since it may block on
+    /// a prompt, and never two at once.
+    async fn choose(
+        &self,
+        choose: &AmbiguityCallback,
+        input: &str,
+        candidates: Vec<Candidate>,
+    ) -> WtgResult<Query> {
+        let choose = Arc::clone(choose);
+        let lock = Arc::clone(&self.ambiguity_lock);
+        let input = input.to_string();
+        tokio::task::spawn_blocking(move || {
+            let _guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
+            choose(&input, candidates)
+        })
+        .await
+        .unwrap_or_else(|err| panic::resume_unwind(err.into_panic()))
+    }
+}
+
+/// Settings for a [`Wtg`] client.
+#[derive(Default)]
+pub struct WtgBuilder {
+    repo: Option<RepoSpec>,
+    backend: BackendOptions,
+    filter: ReleaseFilter,
+    branch_filter: Option<BranchFilter>,
+    on_ambiguous: Option<AmbiguityCallback>,
+}
+
+/// A repository as given to the builder; URLs are parsed by `build`.
+enum RepoSpec {
+    Url(String),
+    Remote(RemoteRepo),
+}
+
+impl WtgBuilder {
+    /// Query a repository by URL, or `owner/repo` for GitHub, like `-r`.
+    ///
+    /// It's cloned into the cache directory if needed. Without a repository,
+    /// forge URLs in the input pick it, and anything else goes to the local repository.
+    #[must_use]
+    pub fn repo(mut self, url: impl Into<String>) -> Self {
+        self.repo = Some(RepoSpec::Url(url.into()));
+        self
+    }
+
+    /// Query a GitHub (or GitHub Enterprise Server) repository.
+    #[must_use]
+    pub fn github_repo(mut self, repo_info: GhRepoInfo) -> Self {
+        self.repo = Some(RepoSpec::Remote(RemoteRepo::GitHub(repo_info)));
+        self
+    }
+
+    /// Look for the local repository at `path` instead of the working directory.
+    ///
+    /// `path` can be anywhere inside the working tree.
+    #[must_use]
+    pub fn repo_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.backend.repo_path = Some(path.into());
+        self
+    }
+
+    /// GitHub token, used instead of `GITHUB_TOKEN` and the gh CLI config.
+    #[must_use]
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        self.backend.github_token = Some(token.into());
+        self
+    }
+
+    /// Send GitHub API requests to `url` instead of the repository host's API
+    /// (e.g. a proxy, or a fake server in tests).
+    ///
+    /// Only the token given to [`WtgBuilder::token`] is sent to it.
+    #[must_use]
+    pub fn github_api_url(mut self, url: impl Into<String>) -> Self {
+        self.backend.github_api_url = Some(url.into());
+        self
+    }
+
+    /// Self-hosted GitHub Enterprise and Gitea/Forgejo instances to recognize in
+    /// URLs and remotes. Defaults to [`ForgeHosts::from_env`].
+    #[must_use]
+    pub fn forge_hosts(mut self, hosts: ForgeHosts) -> Self {
+        self.backend.hosts = hosts;
+        self
+    }
+
+    /// Prefer the local remote called `name` over upstream and origin.
+    #[must_use]
+    pub fn preferred_remote(mut self, name: impl Into<String>) -> Self {
+        self.backend.preferred_remote = Some(name.into());
+        self
+    }
+
+    /// Read local changelogs from `path`, relative to the repository root,
+    /// instead of looking for CHANGELOG.md.
+    #[must_use]
+    pub fn changelog_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.backend.changelog_path = Some(path.into());
+        self
+    }
+
+    /// Set whether remote repositories (`repo`, cross-project PRs) are cloned
+    /// into the cache directory. Without clones, they're queried through the API only.
+    #[must_use]
+    pub const fn clone_remotes(mut self, clone: bool) -> Self {
+        self.backend.clone_remotes = clone;
+        self
+    }
+
+    /// Record GitHub API traffic to a directory, or replay it from one
+    /// (`--record` / `--replay`). A replay needs the [`WtgBuilder::clone_remotes`]
+    /// setting of the recorded run, or [`WtgBuilder::build`] fails.
+    #[must_use]
+    pub fn recording(mut self, recording: Arc<Recording>) -> Self {
+        self.backend.recording = Some(recording);
+        self
+    }
+
+    /// Set whether the local repository may be fetched to find missing commits
+    /// (`--fetch`). Cloned repositories are always fetched.
+    #[must_use]
+    pub const fn fetch(mut self, allow: bool) -> Self {
+        self.backend.allow_fetch = allow;
+        self
+    }
+
+    /// Which tags count as releases.
+    #[must_use]
+    pub fn release_filter(mut self, filter: ReleaseFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Also list the branches containing a commit (`--branches`).
+    #[must_use]
+    pub fn branch_filter(mut self, filter: BranchFilter) -> Self {
+        self.branch_filter = Some(filter);
+        self
+    }
+
+    /// Receive notices (rate limits, clones, fallbacks) while resolving.
+    /// They're dropped by default.
+    #[must_use]
+    pub fn notice_callback(mut self, callback: NoticeCallback) -> Self {
+        self.backend.notice_cb = callback;
+        self
+    }
+
+    /// Decide what ambiguous input meant, e.g. by asking the user.
+    /// Without one, ambiguous input fails with [`WtgError::MultipleMatches`].
+    ///
+    /// The callback runs on a blocking thread (so it needs a Tokio runtime),
+    /// one call at a time across concurrent queries.
+    #[must_use]
+    pub fn on_ambiguous(mut self, callback: AmbiguityCallback) -> Self {
+        self.on_ambiguous = Some(callback);
+        self
+    }
+
+    /// Set whether GitHub API responses are cached on disk. On by default.
+    #[must_use]
+    pub const fn cache(mut self, enabled: bool) -> Self {
+        self.backend.cache.enabled = enabled;
+        self
+    }
+
+    /// Serve cached responses younger than `ttl` without revalidating them.
+    #[must_use]
+    pub const fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.backend.cache.ttl = ttl;
+        self
+    }
+
+    /// Create the client. Fails if the repository URL can't be parsed, or if a
+    /// replay doesn't clone remote repositories the way the recorded run did.
+    pub fn build(self) -> WtgResult<Wtg> {
+        let repo = match self.repo {
+            Some(RepoSpec::Url(url)) => Some(RemoteRepo::parse(&url, &self.backend.hosts)?),
+            Some(RepoSpec::Remote(repo)) => Some(repo),
+            None => None,
+        };
+        if let Some(recording) = &self.backend.recording {
+            recording.use_clone_remotes(self.backend.clone_remotes)?;
+        }
+        Ok(Wtg {
+            repo,
+            backend: self.backend,
+            filter: self.filter,
+            branch_filter: self.branch_filter,
+            on_ambiguous: self.on_ambiguous,
+            ambiguity_lock: Arc::default(),
+        })
+    }
+}
@@ -0,0 +1,617 @@
+//! Configuration files.
+//!
+//! Defaults can live in a user config (`~/.config/wtg/config.toml`, or
+//! `$XDG_CONFIG_HOME/wtg/config.toml`) and a repository config (`.wtg.toml` at
+//! the root of the repository wtg runs in). Every setting is resolved with the
+//! precedence flag > environment variable > repo config > user config > default.
+//!
+//! Host lists are the exception: they decide where forge tokens are sent, so
+//! only the user config may set them, and its hosts are added to the ones known
+//! from `WTG_GITHUB_HOSTS`/`WTG_GITEA_HOSTS` and gh's `hosts.yml`. A repo config
+//! listing hosts is rejected: a cloned repository can't pick who gets your tokens.
+
+use std::{
+    env, fmt, fs,
+    io::ErrorKind,
+    path::{Component, Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    error::{WtgError, WtgResult},
+    output::OutputFormat,
+    remote::ForgeHosts,
+};
+
+/// Name of the repository config file.
+pub const REPO_CONFIG_FILE: &str = ".wtg.toml";
+
+/// Environment variable pointing at an alternative user config file.
+pub const CONFIG_ENV: &str = "WTG_CONFIG";
+
+/// Environment variable naming the preferred remote.
+pub const REMOTE_ENV: &str = "WTG_REMOTE";
+
+/// Environment variable with the changelog path, relative to the repository root.
+pub const CHANGELOG_ENV: &str = "WTG_CHANGELOG";
+
+/// Environment variable with the API cache TTL in seconds.
+pub const CACHE_TTL_ENV: &str = "WTG_CACHE_TTL";
+
+/// Contents of a config file. Every setting is optional.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigFile {
+    /// Skip pre-releases when finding releases
+    pub skip_prereleases: Option<bool>,
+    /// Only consider tags of one component, like `--tag-prefix`
+    pub tag_prefix: Option<String>,
+    /// Only consider tags matching one of these patterns, like `--include-tag`
+    pub include_tags: Option<Vec<String>>,
+    /// Never consider tags matching these patterns, like `--exclude-tag`
+    pub exclude_tags: Option<Vec<String>>,
+    /// Only count tags that have a forge release
+    pub require_release: Option<bool>,
+    /// Remote to prefer when several point at the same forge, e.g. `upstream`
+    pub remote: Option<String>,
+    /// Output format: `human` or `json`
+    pub format: Option<OutputFormat>,
+    /// Changelog location relative to the repository root
+    pub changelog: Option<PathBuf>,
+    /// Additional GitHub Enterprise Server hosts (user config only)
+    pub github_hosts: Vec<String>,
+    /// Additional Gitea/Forgejo hosts (user config only)
+    pub gitea_hosts: Vec<String>,
+    /// GitHub API cache settings
+    pub cache: CacheConfig,
+}
+
+/// The `[cache]` table of a config file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    /// Whether to use the on-disk GitHub API cache
+    pub enabled: Option<bool>,
+    /// Serve mutable responses younger than this many seconds without revalidating them
+    pub ttl_secs: Option<u64>,
+}
+
+impl ConfigFile {
+    /// Parse config file contents; `origin` names the file in errors.
+    pub fn parse(contents: &str, origin: &Path) -> WtgResult<Self> {
+        toml::from_str(contents).map_err(|e| WtgError::Config {
+            origin: origin.display().to_string(),
+            message: e.message().to_string(),
+        })
+    }
+
+    /// Parse a repo config, which may not set the host lists.
+    pub fn parse_repo(contents: &str, origin: &Path) -> WtgResult<Self> {
+        let file = Self::parse(contents, origin)?;
+        if let Some(key) = file.host_list_key() {
+            return Err(WtgError::Config {
+                origin: origin.display().to_string(),
+                message: format!(
+                    "`{key}` is only read from the user config, since forge tokens are sent to these hosts"
+                ),
+            });
+        }
+        Ok(file)
+    }
+
+    /// The first host list key that's set, if any.
+    const fn host_list_key(&self) -> Option<&'static str> {
+        if !self.github_hosts.is_empty() {
+            Some("github_hosts")
+        } else if !self.gitea_hosts.is_empty() {
+            Some("gitea_hosts")
+        } else {
+            None
+        }
+    }
+}
+
+/// A config file that was found and parsed.
+#[derive(Debug, Clone)]
+pub struct ConfigLayer {
+    pub path: PathBuf,
+    pub file: ConfigFile,
+}
+
+/// The config files in effect for this run.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    /// Where the user config is looked for (`None` without a home directory)
+    pub user_path: Option<PathBuf>,
+    pub user: Option<ConfigLayer>,
+    pub repo: Option<ConfigLayer>,
+}
+
+impl Config {
+    /// Load the user config and the config of the repository containing the current directory.
+    pub fn load() -> WtgResult<Self> {
+        let user_path = user_config_path();
+        let user = user_path
+            .as_deref()
+            .map(|path| load_layer(path, ConfigFile::parse))
+            .transpose()?
+            .flatten();
+        let repo = env::current_dir()
+            .ok()
+            .and_then(|dir| find_repo_config(&dir))
+            .map(|path| load_layer(&path, ConfigFile::parse_repo))
+            .transpose()?
+            .flatten();
+
+        Ok(Self {
+            user_path,
+            user,
+            repo,
+        })
+    }
+
+    /// The first value set by the repo config, then the user config.
+    fn value<T>(&self, get: impl Fn(&ConfigFile) -> Option<T>) -> Option<Setting<T>> {
+        let from = |layer: &Option<ConfigLayer>, source| {
+            layer
+                .as_ref()
+                .and_then(|layer| get(&layer.file))
+                .map(|value| Setting { value, source })
+        };
+        from(&self.repo, Source::RepoConfig).or_else(|| from(&self.user, Source::UserConfig))
+    }
+
+    /// Values of a host list, from the user config only, without duplicates.
+    fn hosts(&self, get: impl Fn(&ConfigFile) -> &[String]) -> Vec<Setting<String>> {
+        let mut values: Vec<Setting<String>> = Vec::new();
+        for value in self.user.iter().flat_map(|layer| get(&layer.file)) {
+            if !values.iter().any(|known| &known.value == value) {
+                values.push(Setting::new(value.clone(), Source::UserConfig));
+            }
+        }
+        values
+    }
+}
+
+/// User config location: `WTG_CONFIG`, else `$XDG_CONFIG_HOME/wtg/config.toml`,
+/// else `~/.config/wtg/config.toml`.
+fn user_config_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os(CONFIG_ENV).filter(|path| !path.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+    env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".config")))
+        .map(|dir| dir.join("wtg").join("config.toml"))
+}
+
+/// Find `.wtg.toml` in `start` or its parents, stopping at the repository root.
+fn find_repo_config(start: &Path) -> Option<PathBuf> {
+    for dir in start.ancestors() {
+        let candidate = dir.join(REPO_CONFIG_FILE);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        if dir.join(".git").exists() {
+            break;
+        }
+    }
+    None
+}
+
+/// Read and parse a config file; a missing file is not an error.
+fn load_layer(
+    path: &Path,
+    parse: fn(&str, &Path) -> WtgResult<ConfigFile>,
+) -> WtgResult<Option<ConfigLayer>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    log::debug!("Loaded config from {}", path.display());
+
+    Ok(Some(ConfigLayer {
+        path: path.to_path_buf(),
+        file: parse(&contents, path)?,
+    }))
+}
+
+/// Where an effective setting came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    Flag,
+    Env,
+    RepoConfig,
+    UserConfig,
+    Default,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Flag => "flag",
+            Self::Env => "env",
+            Self::RepoConfig => "repo config",
+            Self::UserConfig => "user config",
+            Self::Default => "default",
+        })
+    }
+}
+
+/// A setting value and where it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setting<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+impl<T> Setting<T> {
+    #[must_use]
+    pub const fn new(value: T, source: Source) -> Self {
+        Self { value, source }
+    }
+
+    /// Wrap the value in `Some`, keeping its source.
+    #[must_use]
+    pub fn some(self) -> Setting<Option<T>> {
+        Setting::new(Some(self.value), self.source)
+    }
+}
+
+/// Values given as flags or environment variables, which beat config files.
+#[derive(Debug, Clone, Default)]
+pub struct Overrides {
+    pub skip_prereleases: Option<Setting<bool>>,
+    pub tag_prefix: Option<Setting<String>>,
+    pub include_tags: Option<Setting<Vec<String>>>,
+    pub exclude_tags: Option<Setting<Vec<String>>>,
+    pub require_release: Option<Setting<bool>>,
+    pub format: Option<Setting<OutputFormat>>,
+    pub no_cache: Option<Setting<bool>>,
+    pub cache_ttl_secs: Option<Setting<u64>>,
+    pub remote: Option<Setting<String>>,
+    pub changelog: Option<Setting<PathBuf>>,
+}
+
+impl Overrides {
+    /// Fill in the settings that only have an environment variable.
+    pub fn with_env(mut self) -> WtgResult<Self> {
+        let var = |name| env::var(name).ok().filter(|value| !value.trim().is_empty());
+
+        if let Some(remote) = var(REMOTE_ENV) {
+            self.remote = Some(Setting::new(remote, Source::Env));
+        }
+        if let Some(path) = var(CHANGELOG_ENV) {
+            self.changelog = Some(Setting::new(PathBuf::from(path), Source::Env));
+        }
+        if let Some(ttl) = var(CACHE_TTL_ENV) {
+            let ttl = ttl.trim().parse().map_err(|_| WtgError::Config {
+                origin: CACHE_TTL_ENV.to_string(),
+                message: format!("expected a number of seconds, got `{ttl}`"),
+            })?;
+            self.cache_ttl_secs = Some(Setting::new(ttl, Source::Env));
+        }
+        Ok(self)
+    }
+}
+
+/// Effective settings after applying the precedence rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    pub skip_prereleases: Setting<bool>,
+    pub tag_prefix: Setting<Option<String>>,
+    pub include_tags: Setting<Vec<String>>,
+    pub exclude_tags: Setting<Vec<String>>,
+    pub require_release: Setting<bool>,
+    pub format: Setting<OutputFormat>,
+    pub cache_enabled: Setting<bool>,
+    pub cache_ttl_secs: Setting<u64>,
+    pub remote: Setting<Option<String>>,
+    pub changelog: Setting<Option<PathBuf>>,
+    pub github_hosts: Vec<Setting<String>>,
+    pub gitea_hosts: Vec<Setting<String>>,
+}
+
+impl Settings {
+    /// Resolve every setting: overrides first, then the repo and user configs, then defaults.
+    #[must_use]
+    pub fn resolve(config: &Config, overrides: Overrides) -> Self {
+        fn pick<T>(
+            overridden: Option<Setting<T>>,
+            configured: Option<Setting<T>>,
+            default: T,
+        ) -> Setting<T> {
+            overridden
+                .or(configured)
+                .unwrap_or_else(|| Setting::new(default, Source::Default))
+        }
+
+        Self {
+            skip_prereleases: pick(
+                overrides.skip_prereleases,
+                config.value(|file| file.skip_prereleases),
+                false,
+            ),
+            tag_prefix: pick(
+                overrides.tag_prefix.map(Setting::some),
+                config
+                    .value(|file| file.tag_prefix.clone())
+                    .map(Setting::some),
+                None,
+            ),
+            include_tags: pick(
+                overrides.include_tags,
+                config.value(|file| file.include_tags.clone()),
+                Vec::new(),
+            ),
+            exclude_tags: pick(
+                overrides.exclude_tags,
+                config.value(|file| file.exclude_tags.clone()),
+                Vec::new(),
+            ),
+            require_release: pick(
+                overrides.require_release,
+                config.value(|file| file.require_release),
+                false,
+            ),
+            format: pick(
+                overrides.format,
+                config.value(|file| file.format),
+                OutputFormat::Human,
+            ),
+            cache_enabled: pick(
+                overrides
+                    .no_cache
+                    .map(|setting| Setting::new(!setting.value, setting.source)),
+                config.value(|file| file.cache.enabled),
+                true,
+            ),
+            cache_ttl_secs: pick(
+                overrides.cache_ttl_secs,
+                config.value(|file| file.cache.ttl_secs),
+                0,
+            ),
+            remote: pick(
+                overrides.remote.map(Setting::some),
+                config.value(|file| file.remote.clone()).map(Setting::some),
+                None,
+            ),
+            changelog: pick(
+                overrides.changelog.map(Setting::some),
+                config
+                    .value(|file| file.changelog.clone())
+                    .map(Setting::some),
+                None,
+            ),
+            github_hosts: config.hosts(|file| &file.github_hosts),
+            gitea_hosts: config.hosts(|file| &file.gitea_hosts),
+        }
+    }
+
+    /// The self-hosted forges known from the environment plus the user config's host lists.
+    #[must_use]
+    pub fn forge_hosts(&self) -> ForgeHosts {
+        let mut hosts = ForgeHosts::from_env();
+        for host in &self.github_hosts {
+            hosts.add_github_enterprise_host(&host.value);
+        }
+        for host in &self.gitea_hosts {
+            hosts.add_gitea_host(&host.value);
+        }
+        hosts
+    }
+
+    /// The configured changelog path, unless it escapes the repository root.
+    #[must_use]
+    pub fn changelog_path(&self) -> Option<&Path> {
+        let path = self.changelog.value.as_deref()?;
+        if is_repo_relative(path) {
+            Some(path)
+        } else {
+            log::warn!(
+                "Ignoring changelog path {} - it must be relative to the repository root",
+                path.display()
+            );
+            None
+        }
+    }
+}
+
+/// Whether a path stays inside the directory it's joined to.
+fn is_repo_relative(path: &Path) -> bool {
+    path.components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(contents: &str) -> ConfigLayer {
+        let path = PathBuf::from("config.toml");
+        ConfigLayer {
+            file: ConfigFile::parse(contents, &path).expect("valid config"),
+            path,
+        }
+    }
+
+    #[test]
+    fn parses_every_setting() {
+        let file = ConfigFile::parse(
+            r#"
+skip_prereleases = true
+tag_prefix = "py"
+include_tags = ["v*"]
+exclude_tags = ["nightly-*", "/^deploy-/"]
+require_release = true
+remote = "upstream"
+format = "json"
+changelog = "docs/CHANGES.md"
+github_hosts = ["github.example.com"]
+gitea_hosts = ["git.example.com"]
+
+[cache]
+enabled = false
+ttl_secs = 600
+"#,
+            Path::new("config.toml"),
+        )
+        .expect("valid config");
+
+        assert_eq!(file.skip_prereleases, Some(true));
+        assert_eq!(file.tag_prefix.as_deref(), Some("py"));
+        assert_eq!(file.include_tags, Some(vec!["v*".to_string()]));
+        assert_eq!(
+            file.exclude_tags,
+            Some(vec!["nightly-*".to_string(), "/^deploy-/".to_string()])
+        );
+        assert_eq!(file.require_release, Some(true));
+        assert_eq!(file.remote.as_deref(), Some("upstream"));
+        assert_eq!(file.format, Some(OutputFormat::Json));
+        assert_eq!(file.changelog, Some(PathBuf::from("docs/CHANGES.md")));
+        assert_eq!(file.github_hosts, ["github.example.com"]);
+        assert_eq!(file.gitea_hosts, ["git.example.com"]);
+        assert_eq!(file.cache.enabled, Some(false));
+        assert_eq!(file.cache.ttl_secs, Some(600));
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let err = ConfigFile::parse("skip_prerelease = true", Path::new("/tmp/.wtg.toml"))
+            .expect_err("typo should be rejected");
+        let WtgError::Config { origin, message } = err else {
+            panic!("expected a config error, got {err:?}");
+        };
+        assert_eq!(origin, "/tmp/.wtg.toml");
+        assert!(message.contains("skip_prerelease"), "{message}");
+    }
+
+    #[test]
+    fn applies_precedence() {
+        let config = Config {
+            user_path: None,
+            user: Some(layer(
+                r#"
+skip_prereleases = true
+tag_prefix = "rust"
+format = "json"
+remote = "upstream"
+github_hosts = ["ghe.example.com"]
+"#,
+            )),
+            repo: Some(layer(
+                r#"
+tag_prefix = "py"
+remote = "fork"
+"#,
+            )),
+        };
+        let overrides = Overrides {
+            format: Some(Setting::new(OutputFormat::Human, Source::Flag)),
+            remote: Some(Setting::new("origin".to_string(), Source::Env)),
+            ..Overrides::default()
+        };
+
+        let settings = Settings::resolve(&config, overrides);
+
+        assert_eq!(
+            settings.format,
+            Setting::new(OutputFormat::Human, Source::Flag)
+        );
+        assert_eq!(
+            settings.remote,
+            Setting::new(Some("origin".to_string()), Source::Env)
+        );
+        assert_eq!(
+            settings.tag_prefix,
+            Setting::new(Some("py".to_string()), Source::RepoConfig)
+        );
+        assert_eq!(
+            settings.skip_prereleases,
+            Setting::new(true, Source::UserConfig)
+        );
+        assert_eq!(settings.cache_enabled, Setting::new(true, Source::Default));
+        assert_eq!(
+            settings.github_hosts,
+            [Setting::new(
+                "ghe.example.com".to_string(),
+                Source::UserConfig
+            )]
+        );
+    }
+
+    #[test]
+    fn repo_config_cannot_add_hosts() {
+        let origin = Path::new("/repo/.wtg.toml");
+        for contents in [
+            r#"github_hosts = ["attacker.example.com"]"#,
+            r#"gitea_hosts = ["attacker.example.com"]"#,
+        ] {
+            let err = ConfigFile::parse_repo(contents, origin).expect_err("hosts must be rejected");
+            let WtgError::Config { origin, message } = err else {
+                panic!("expected a config error, got {err:?}");
+            };
+            assert_eq!(origin, "/repo/.wtg.toml");
+            assert!(message.contains("_hosts"), "{message}");
+        }
+        assert!(ConfigFile::parse_repo("tag_prefix = \"py\"", origin).is_ok());
+
+        // Even a repo layer built by hand doesn't contribute hosts
+        let config = Config {
+            repo: Some(layer(
+                r#"
+github_hosts = ["attacker.example.com"]
+gitea_hosts = ["attacker.example.com"]
+"#,
+            )),
+            ..Config::default()
+        };
+        let settings = Settings::resolve(&config, Overrides::default());
+        assert!(settings.github_hosts.is_empty());
+        assert!(settings.gitea_hosts.is_empty());
+    }
+
+    #[test]
+    fn no_cache_flag_beats_config() {
+        let config = Config {
+            user: Some(layer("[cache]\nenabled = true")),
+            ..Config::default()
+        };
+        let overrides = Overrides {
+            no_cache: Some(Setting::new(true, Source::Flag)),
+            ..Overrides::default()
+        };
+
+        let settings = Settings::resolve(&config, overrides);
+        assert_eq!(settings.cache_enabled, Setting::new(false, Source::Flag));
+    }
+
+    #[test]
+    fn finds_repo_config_up_to_repo_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path().join("repo");
+        let nested = repo.join("src/deep");
+        fs::create_dir_all(&nested).unwrap();
+        fs::create_dir(repo.join(".git")).unwrap();
+
+        assert_eq!(find_repo_config(&nested), None);
+
+        // A config above the repository root doesn't count
+        fs::write(dir.path().join(REPO_CONFIG_FILE), "").unwrap();
+        assert_eq!(find_repo_config(&nested), None);
+
+        fs::write(repo.join(REPO_CONFIG_FILE), "").unwrap();
+        assert_eq!(find_repo_config(&nested), Some(repo.join(REPO_CONFIG_FILE)));
+    }
+
+    #[test]
+    fn changelog_path_must_stay_in_repo() {
+        assert!(is_repo_relative(Path::new("docs/CHANGELOG.md")));
+        assert!(is_repo_relative(Path::new("./CHANGES.md")));
+        assert!(!is_repo_relative(Path::new("../CHANGELOG.md")));
+        assert!(!is_repo_relative(Path::new("/etc/passwd")));
+    }
+}
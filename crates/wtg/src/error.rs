@@ -1,31 +1,61 @@
+use chrono::{DateTime, Utc};
 use crossterm::style::Stylize;
 use http::StatusCode;
 use octocrab::Error as OctoError;
 use std::fmt;
 
+use crate::parse_input::{Candidate, LineRange};
+
 pub type WtgResult<T> = std::result::Result<T, WtgError>;
 
-#[derive(Debug, strum::EnumIs)]
+#[derive(Debug, strum::EnumIs, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
 pub enum WtgError {
     EmptyInput,
     NotInGitRepo,
     NotFound(String),
     TagNotFound(String),
+    /// No release had shipped yet at the given time
+    NoReleaseAt(DateTime<Utc>),
     Unsupported(String),
+    /// The requested lines start past the end of the file
+    LinesOutOfRange {
+        path: String,
+        lines: LineRange,
+        line_count: usize,
+    },
     Git(git2::Error),
     GhConnectionLost,
     GhRateLimit(OctoError),
     GhSaml(OctoError),
     GhBadCredentials(OctoError),
     GitHub(OctoError),
-    MultipleMatches(Vec<String>),
+    Http(reqwest::Error),
+    /// The input could mean several things and nobody was around to pick one
+    MultipleMatches(Vec<Candidate>),
     Io(std::io::Error),
-    Cli { message: String, code: i32 },
+    Cli {
+        message: String,
+        code: i32,
+    },
     Timeout,
     NotGitHubUrl(String),
     MalformedGitHubUrl(String),
+    /// A URL on a forge other than GitHub that doesn't point at anything wtg knows
+    MalformedUrl(String),
     SecurityRejection(String),
     GitHubClientFailed,
+    /// A config file or setting is invalid
+    Config {
+        /// The file or environment variable holding the bad value
+        origin: String,
+        message: String,
+    },
+    /// Some queries of a batch failed (each was already reported)
+    BatchFailed {
+        failed: usize,
+        total: usize,
+    },
 }
 
 impl fmt::Display for WtgError {
@@ -73,6 +103,20 @@ impl fmt::Display for WtgError {
                 writeln!(f)?;
                 writeln!(f, "   {}", "Check your spelling! 🔍".yellow())
             }
+            Self::NoReleaseAt(at) => {
+                writeln!(
+                    f,
+                    "{}",
+                    "📆 Nothing had shipped yet back then!".yellow().bold()
+                )?;
+                writeln!(f)?;
+                writeln!(
+                    f,
+                    "   {}: {}",
+                    "No release on or before".yellow(),
+                    at.format("%Y-%m-%d %H:%M:%S UTC").to_string().cyan()
+                )
+            }
             Self::Unsupported(operation) => {
                 writeln!(f, "{}", "🚫 Can't do that here!".yellow().bold())?;
                 writeln!(f)?;
@@ -82,6 +126,25 @@ impl fmt::Display for WtgError {
                     operation.as_str().cyan()
                 )
             }
+            Self::LinesOutOfRange {
+                path,
+                lines,
+                line_count,
+            } => {
+                writeln!(
+                    f,
+                    "{}",
+                    "📏 That file isn't nearly that long!".yellow().bold()
+                )?;
+                writeln!(f)?;
+                writeln!(
+                    f,
+                    "   {} has {} lines, you asked for {}.",
+                    path.as_str().cyan(),
+                    line_count,
+                    lines.to_string().cyan()
+                )
+            }
             Self::Git(e) => write!(f, "Git error: {e}"),
             Self::GhConnectionLost => {
                 writeln!(
@@ -164,7 +227,8 @@ impl fmt::Display for WtgError {
                 )
             }
             Self::GitHub(e) => write!(f, "GitHub error: {e}"),
-            Self::MultipleMatches(types) => {
+            Self::Http(e) => write!(f, "HTTP error: {e}"),
+            Self::MultipleMatches(candidates) => {
                 writeln!(f, "{}", "💥 OH MY, YOU BLEW ME UP!".red().bold())?;
                 writeln!(f)?;
                 writeln!(
@@ -174,12 +238,30 @@ impl fmt::Display for WtgError {
                 )?;
                 writeln!(f)?;
                 writeln!(f, "   {}", "Matches:".yellow())?;
-                for t in types {
-                    writeln!(f, "   {} {}", "✓".green(), t)?;
+                for candidate in candidates {
+                    writeln!(
+                        f,
+                        "   {} {} {}",
+                        "✓".green(),
+                        format!("{}:", candidate.kind).cyan(),
+                        candidate.preview
+                    )?;
                 }
-                panic!("💥 BOOM! You broke me!");
+                writeln!(f)?;
+                writeln!(
+                    f,
+                    "   {}",
+                    "Run me in a terminal to pick one, or be more specific.".yellow()
+                )
             }
             Self::Io(e) => write!(f, "I/O error: {e}"),
+            Self::BatchFailed { failed, total } => writeln!(
+                f,
+                "{}",
+                format!("❌ {failed} of {total} queries came up empty-handed.")
+                    .red()
+                    .bold()
+            ),
             Self::Cli { message, .. } => write!(f, "{message}"),
             Self::Timeout => {
                 writeln!(
@@ -225,6 +307,23 @@ impl fmt::Display for WtgError {
                 )?;
                 writeln!(f, "   {}", "But this? This is just sad. 😢".red())
             }
+            Self::MalformedUrl(url) => {
+                writeln!(
+                    f,
+                    "{}",
+                    "😵 That URL is more broken than my ex's promises!"
+                        .red()
+                        .bold()
+                )?;
+                writeln!(f)?;
+                writeln!(f, "   {}: {}", "You gave me".red(), url.clone().cyan())?;
+                writeln!(f)?;
+                writeln!(
+                    f,
+                    "   {}",
+                    "Expected a link to a commit, issue, pull request or file".yellow()
+                )
+            }
             Self::SecurityRejection(reason) => {
                 writeln!(f, "{}", "🚨 Whoa there! Security alert!".red().bold())?;
                 writeln!(f)?;
@@ -257,6 +356,16 @@ impl fmt::Display for WtgError {
                     "Try giving me something to work with, please!".yellow()
                 )
             }
+            Self::Config { origin, message } => {
+                writeln!(
+                    f,
+                    "{}",
+                    "🛠️  Your config is giving me mixed signals!".red().bold()
+                )?;
+                writeln!(f)?;
+                writeln!(f, "   {}: {}", "In".red(), origin.as_str().cyan())?;
+                writeln!(f, "   {message}")
+            }
             Self::GitHubClientFailed => {
                 writeln!(
                     f,
@@ -319,6 +428,15 @@ impl From<OctoError> for WtgError {
     }
 }
 
+impl From<reqwest::Error> for WtgError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            return Self::Timeout;
+        }
+        Self::Http(err)
+    }
+}
+
 impl From<std::io::Error> for WtgError {
     fn from(err: std::io::Error) -> Self {
         Self::Io(err)
@@ -326,6 +444,12 @@ impl From<std::io::Error> for WtgError {
 }
 
 impl WtgError {
+    /// Stable `snake_case` name of the error, e.g. `not_found` (used in JSON output).
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        self.into()
+    }
+
     pub const fn exit_code(&self) -> i32 {
         match self {
             Self::Cli { code, .. } => *code,
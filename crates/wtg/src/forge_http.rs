@@ -0,0 +1,152 @@
+//! HTTP plumbing shared by the GitLab, Gitea/Forgejo and Bitbucket API clients.
+//!
+//! Each client only decides its API root and how the token is sent; requests,
+//! timeouts, status handling and path encoding are the same everywhere.
+
+use std::time::Duration;
+
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use reqwest::{RequestBuilder, StatusCode};
+use serde::de::DeserializeOwned;
+
+use crate::error::{WtgError, WtgResult};
+
+const CONNECT_TIMEOUT_SECS: u64 = 5;
+const REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Escaped in path segments: everything but letters, digits and the unreserved
+/// `-`, `.` and `_`, so tags like `v1.0.0` stay readable in URLs.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_');
+
+/// How a forge API client authenticates.
+pub(crate) enum ForgeAuth {
+    /// The token as the value of a header (GitLab's `PRIVATE-TOKEN`)
+    Header { name: &'static str, token: String },
+    /// `Authorization: token <token>` (Gitea/Forgejo)
+    Token(String),
+    /// `Authorization: Bearer <token>`
+    Bearer(String),
+    /// HTTP basic auth (Bitbucket app passwords)
+    Basic { username: String, password: String },
+}
+
+impl ForgeAuth {
+    fn apply(&self, request: RequestBuilder) -> RequestBuilder {
+        match self {
+            Self::Header { name, token } => request.header(*name, token),
+            Self::Token(token) => request.header("Authorization", format!("token {token}")),
+            Self::Bearer(token) => request.bearer_auth(token),
+            Self::Basic { username, password } => request.basic_auth(username, Some(password)),
+        }
+    }
+}
+
+/// GET-only HTTP client for one forge API root.
+pub(crate) struct ForgeHttp {
+    http: reqwest::Client,
+    /// API root, e.g. `https://gitlab.com/api/v4`
+    api_url: String,
+    auth: Option<ForgeAuth>,
+}
+
+impl ForgeHttp {
+    /// Create a client for the API at `api_url`. Returns `None` if the HTTP
+    /// client cannot be built.
+    pub(crate) fn new(api_url: &str, auth: Option<ForgeAuth>) -> Option<Self> {
+        let http = reqwest::Client::builder()
+            .user_agent(concat!("wtg/", env!("CARGO_PKG_VERSION")))
+            .connect_timeout(Duration::from_secs(CONNECT_TIMEOUT_SECS))
+            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+            .build()
+            .ok()?;
+
+        Some(Self {
+            http,
+            api_url: api_url.trim_end_matches('/').to_string(),
+            auth,
+        })
+    }
+
+    /// Send a GET for an API path (relative to the API root), failing on error statuses.
+    pub(crate) async fn get(&self, path: &str) -> WtgResult<reqwest::Response> {
+        let mut request = self.http.get(format!("{}/{path}", self.api_url));
+        if let Some(auth) = &self.auth {
+            request = auth.apply(request);
+        }
+
+        let response = request.send().await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(WtgError::NotFound(path.to_string()));
+        }
+
+        Ok(response.error_for_status()?)
+    }
+
+    /// GET an API path (relative to the API root) and decode the JSON body.
+    pub(crate) async fn get_json<T: DeserializeOwned>(&self, path: &str) -> WtgResult<T> {
+        Ok(self.get(path).await?.json().await?)
+    }
+
+    /// GET an API path (relative to the API root) as text.
+    pub(crate) async fn get_text(&self, path: &str) -> WtgResult<String> {
+        Ok(self.get(path).await?.text().await?)
+    }
+}
+
+/// Percent-encode a single path segment (e.g. a ref name containing `/`).
+pub(crate) fn encode(segment: &str) -> String {
+    utf8_percent_encode(segment, PATH_SEGMENT).to_string()
+}
+
+/// Fake forge API for the forge backend tests.
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::ops::Deref;
+
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Merge commit of the pull requests the fake forges answer with.
+    pub(crate) const MERGE_SHA: &str = "0123456789abcdef0123456789abcdef01234567";
+
+    /// A mock server answering GETs under one repository's API path, and only
+    /// when they carry the expected auth header.
+    pub(crate) struct ForgeMock {
+        server: MockServer,
+        /// API path of the repository, e.g. `/api/v1/repos/owner/repo`
+        repo_path: &'static str,
+        /// Auth header every request must carry
+        auth: (&'static str, &'static str),
+    }
+
+    impl ForgeMock {
+        pub(crate) async fn start(
+            repo_path: &'static str,
+            auth: (&'static str, &'static str),
+        ) -> Self {
+            Self {
+                server: MockServer::start().await,
+                repo_path,
+                auth,
+            }
+        }
+
+        /// Answer GETs of `route` (relative to the repository) with `body`.
+        pub(crate) async fn mock_get(&self, route: &str, body: serde_json::Value) {
+            Mock::given(method("GET"))
+                .and(path(format!("{}{route}", self.repo_path)))
+                .and(header(self.auth.0, self.auth.1))
+                .respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .mount(&self.server)
+                .await;
+        }
+    }
+
+    impl Deref for ForgeMock {
+        type Target = MockServer;
+
+        fn deref(&self) -> &MockServer {
+            &self.server
+        }
+    }
+}
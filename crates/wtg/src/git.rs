@@ -1,22 +1,34 @@
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     fs,
     io::{Error as IoError, ErrorKind},
     path::{Path, PathBuf},
     process::{Command, Stdio},
-    sync::{Arc, Mutex},
+    sync::{Arc, LazyLock, Mutex},
 };
 
-use chrono::{DateTime, TimeZone, Utc};
-use git2::{Commit, FetchOptions, Oid, RemoteCallbacks, Repository};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use git2::{
+    BlameOptions, Commit, Delta, DiffFindOptions, DiffOptions, FetchOptions, ObjectType, Oid,
+    RemoteCallbacks, Repository, Sort, TreeWalkMode, TreeWalkResult,
+};
+use globset::{GlobBuilder, GlobMatcher};
+use regex::Regex;
+use serde::{Serialize, Serializer, ser::SerializeSeq};
 
 use crate::error::{WtgError, WtgResult};
 use crate::github::{GhRepoInfo, ReleaseInfo};
 use crate::notice::{Notice, NoticeCallback, no_notices};
-use crate::parse_input::parse_github_repo_url;
-use crate::remote::{RemoteHost, RemoteInfo, RemoteKind};
+use crate::parse_input::{LineRange, parse_github_repo_url};
+use crate::remote::{ForgeHosts, RemoteHost, RemoteInfo, RemoteKind};
 pub use crate::semver::{SemverInfo, parse_semver};
 
+/// Trailer added by `git cherry-pick -x`: `(cherry picked from commit <sha>)`
+static CHERRY_PICK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?m)^\(cherry picked from commit (?P<sha>[0-9a-fA-F]{7,40})\)\s*$")
+        .expect("Invalid cherry-pick trailer regex")
+});
+
 /// Tracks what data has been synchronized from remote.
 ///
 /// This helps avoid redundant network calls:
@@ -40,15 +52,25 @@ pub struct GitRepo {
     remote_url: Option<String>,
     /// GitHub repository info (owner/repo) if explicitly set
     gh_repo_info: Option<GhRepoInfo>,
+    /// Self-hosted forges recognized in remote URLs
+    hosts: ForgeHosts,
+    /// Remote that beats upstream and origin, from the config
+    preferred_remote: Option<String>,
+    /// Changelog location relative to the repository root, from the config
+    changelog_path: Option<PathBuf>,
     /// Whether fetching is allowed
     allow_fetch: bool,
     /// Tracks what's been synced from remote
     fetch_state: Mutex<FetchState>,
+    /// Peeled tags, computed on first use and dropped whenever we fetch
+    tags_cache: Mutex<Option<Vec<TagInfo>>>,
+    /// Tag tips containing each commit searched for copies, dropped with the tags
+    tag_tips_containing: Mutex<HashMap<Oid, Arc<HashSet<Oid>>>>,
     /// Callback for emitting notices
     notice_cb: NoticeCallback,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CommitInfo {
     pub hash: String,
     pub short_hash: String,
@@ -62,14 +84,96 @@ pub struct CommitInfo {
     pub date: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FileInfo {
     pub path: String,
     pub last_commit: CommitInfo,
+    #[serde(serialize_with = "serialize_previous_authors")]
     pub previous_authors: Vec<(String, String, String)>, // (hash, name, email)
+    /// Names the file had before, most recent rename first
+    pub renames: Vec<FileRename>,
+}
+
+/// How far back from the latest change under a directory or glob contributors are counted.
+pub const CONTRIBUTOR_WINDOW_DAYS: i64 = 90;
+
+/// How many contributors to report for a directory or glob.
+const TOP_CONTRIBUTORS: usize = 5;
+
+/// Someone who changed files under a directory or glob.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Contributor {
+    pub name: String,
+    pub email: String,
+    /// Number of commits in the window
+    pub commits: usize,
+}
+
+/// Recent activity under a directory or among the files matching a glob.
+#[derive(Debug, Clone, Serialize)]
+pub struct PathsInfo {
+    /// The glob files were matched with (`dir/**` for a directory)
+    pub pattern: String,
+    pub last_commit: CommitInfo,
+    /// Matching files the last commit changed
+    pub changed_paths: Vec<String>,
+    /// Most active authors in the window, most commits first
+    pub top_contributors: Vec<Contributor>,
+    /// Start of the window: `CONTRIBUTOR_WINDOW_DAYS` before the last change
+    pub window_start: DateTime<Utc>,
+}
+
+/// A rename found while following a file's history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileRename {
+    pub from: String,
+    pub to: String,
+    /// The commit that renamed the file
+    pub commit_hash: String,
 }
 
-#[derive(Debug, Clone)]
+/// A commit that changed a file, with the path the file had in that commit.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileChange {
+    pub commit: CommitInfo,
+    /// Differs from the queried path for commits made before a rename
+    pub path: String,
+}
+
+/// Consecutive lines of a file that were last changed by the same commit.
+#[derive(Debug, Clone, Serialize)]
+pub struct BlameHunk {
+    pub commit: CommitInfo,
+    /// First line of the hunk (1-based)
+    pub start_line: usize,
+    /// Last line of the hunk (inclusive)
+    pub end_line: usize,
+    /// Path of the file in the commit that introduced the lines
+    pub path: String,
+    /// The lines themselves, as of the queried revision
+    pub lines: Vec<String>,
+}
+
+/// Serialize `(hash, name, email)` tuples as objects so the JSON schema is self-describing.
+fn serialize_previous_authors<S: Serializer>(
+    authors: &[(String, String, String)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct Author<'a> {
+        hash: &'a str,
+        name: &'a str,
+        email: &'a str,
+    }
+
+    let mut seq = serializer.serialize_seq(Some(authors.len()))?;
+    for (hash, name, email) in authors {
+        seq.serialize_element(&Author { hash, name, email })?;
+    }
+    seq.end()
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct TagInfo {
     pub name: String,
     pub commit_hash: String,
@@ -82,6 +186,28 @@ pub struct TagInfo {
     pub tag_url: Option<String>, // URL to view the tag (tree for plain tags, release page for releases)
 }
 
+/// A branch that contains some commit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BranchInfo {
+    /// Branch name; remote-tracking branches keep their remote prefix (`origin/main`)
+    pub name: String,
+    pub is_remote: bool,
+}
+
+impl BranchInfo {
+    /// Branch name without the remote prefix (same as `name` for local branches).
+    #[must_use]
+    pub fn short_name(&self) -> &str {
+        if self.is_remote {
+            self.name
+                .split_once('/')
+                .map_or(self.name.as_str(), |(_, name)| name)
+        } else {
+            &self.name
+        }
+    }
+}
+
 impl TagInfo {
     /// Whether this is a semver tag
     #[must_use]
@@ -106,16 +232,28 @@ impl GitRepo {
     /// Open the git repository from the current directory.
     /// Fetch is disabled by default for local repos.
     pub fn open() -> WtgResult<Self> {
-        let repo = Repository::discover(".").map_err(|_| WtgError::NotInGitRepo)?;
+        Self::discover(Path::new("."))
+    }
+
+    /// Open the git repository containing `path` (like running git from there).
+    /// Fetch is disabled by default for local repos.
+    pub fn discover(path: &Path) -> WtgResult<Self> {
+        let repo = Repository::discover(path).map_err(|_| WtgError::NotInGitRepo)?;
         let path = repo.path().to_path_buf();
-        let remote_url = Self::extract_remote_url(&repo);
+        let hosts = ForgeHosts::from_env();
+        let remote_url = Self::extract_remote_url(&repo, &hosts, None);
         Ok(Self {
             repo: Arc::new(Mutex::new(repo)),
             path,
             remote_url,
             gh_repo_info: None,
+            hosts,
+            preferred_remote: None,
+            changelog_path: None,
             allow_fetch: false,
             fetch_state: Mutex::new(FetchState::default()),
+            tags_cache: Mutex::new(None),
+            tag_tips_containing: Mutex::default(),
             notice_cb: no_notices(),
         })
     }
@@ -125,14 +263,20 @@ impl GitRepo {
     pub fn from_path(path: &Path) -> WtgResult<Self> {
         let repo = Repository::open(path).map_err(|_| WtgError::NotInGitRepo)?;
         let repo_path = repo.path().to_path_buf();
-        let remote_url = Self::extract_remote_url(&repo);
+        let hosts = ForgeHosts::from_env();
+        let remote_url = Self::extract_remote_url(&repo, &hosts, None);
         Ok(Self {
             repo: Arc::new(Mutex::new(repo)),
             path: repo_path,
             remote_url,
             gh_repo_info: None,
+            hosts,
+            preferred_remote: None,
+            changelog_path: None,
             allow_fetch: false,
             fetch_state: Mutex::new(FetchState::default()),
+            tags_cache: Mutex::new(None),
+            tag_tips_containing: Mutex::default(),
             notice_cb: no_notices(),
         })
     }
@@ -152,8 +296,18 @@ impl GitRepo {
         let emit = |n: Notice| (notice_cb)(n);
 
         let cache_dir = get_cache_dir()?;
-        let repo_cache_path =
-            cache_dir.join(format!("{}/{}", gh_repo_info.owner(), gh_repo_info.repo()));
+        // github.com clones keep the historical `owner/repo` layout,
+        // Enterprise hosts get their own subdirectory
+        let repo_cache_path = if gh_repo_info.is_github_com() {
+            cache_dir.join(format!("{}/{}", gh_repo_info.owner(), gh_repo_info.repo()))
+        } else {
+            cache_dir.join(format!(
+                "{}/{}/{}",
+                gh_repo_info.host(),
+                gh_repo_info.owner(),
+                gh_repo_info.repo()
+            ))
+        };
 
         // Check if already cloned
         let full_metadata_synced =
@@ -170,33 +324,29 @@ impl GitRepo {
                 }
             } else {
                 // Clone it (with filter=blob:none for efficiency)
-                clone_remote_repo(
-                    gh_repo_info.owner(),
-                    gh_repo_info.repo(),
-                    &repo_cache_path,
-                    &emit,
-                )?;
+                clone_remote_repo(&gh_repo_info, &repo_cache_path, &emit)?;
                 true // Fresh clone has all metadata
             };
 
         let repo = Repository::open(&repo_cache_path).map_err(|_| WtgError::NotInGitRepo)?;
         let path = repo.path().to_path_buf();
-        let remote_url = Some(format!(
-            "https://github.com/{}/{}.git",
-            gh_repo_info.owner(),
-            gh_repo_info.repo()
-        ));
+        let remote_url = Some(clone_url(&gh_repo_info));
 
         Ok(Self {
             repo: Arc::new(Mutex::new(repo)),
             path,
             remote_url,
             gh_repo_info: Some(gh_repo_info),
+            hosts: ForgeHosts::from_env(),
+            preferred_remote: None,
+            changelog_path: None,
             allow_fetch: true,
             fetch_state: Mutex::new(FetchState {
                 full_metadata_synced,
                 ..Default::default()
             }),
+            tags_cache: Mutex::new(None),
+            tag_tips_containing: Mutex::default(),
             notice_cb,
         })
     }
@@ -224,6 +374,41 @@ impl GitRepo {
         self.allow_fetch = allow;
     }
 
+    /// Set the self-hosted forges recognized in remote URLs. Local repositories
+    /// start out with [`ForgeHosts::from_env`].
+    pub fn set_forge_hosts(&mut self, hosts: ForgeHosts) {
+        self.hosts = hosts;
+        self.refresh_remote_url();
+    }
+
+    /// Prefer the remote called `name` over upstream and origin.
+    pub fn set_preferred_remote(&mut self, name: Option<String>) {
+        self.preferred_remote = name;
+        self.refresh_remote_url();
+    }
+
+    /// Read the changelog from `path` (relative to the repository root) instead
+    /// of looking for CHANGELOG.md.
+    pub fn set_changelog_path(&mut self, path: Option<PathBuf>) {
+        self.changelog_path = path;
+    }
+
+    /// The configured changelog location, if any.
+    #[must_use]
+    pub fn changelog_path(&self) -> Option<&Path> {
+        self.changelog_path.as_deref()
+    }
+
+    /// Re-pick the fetch URL after the remote ordering inputs changed.
+    fn refresh_remote_url(&mut self) {
+        if self.gh_repo_info.is_none() {
+            let remote_url = self.with_repo(|repo| {
+                Self::extract_remote_url(repo, &self.hosts, self.preferred_remote.as_deref())
+            });
+            self.remote_url = remote_url;
+        }
+    }
+
     /// Set the notice callback for emitting operational messages.
     pub fn set_notice_callback(&mut self, cb: NoticeCallback) {
         self.notice_cb = cb;
@@ -246,7 +431,11 @@ impl GitRepo {
     }
 
     /// Collect all remotes from a repository as `RemoteInfo` structs.
-    fn collect_remotes(repo: &Repository) -> Vec<RemoteInfo> {
+    fn collect_remotes(
+        repo: &Repository,
+        hosts: &ForgeHosts,
+        preferred: Option<&str>,
+    ) -> Vec<RemoteInfo> {
         let remote_names: Vec<String> = repo
             .remotes()
             .map(|names| names.iter().flatten().map(str::to_string).collect())
@@ -259,8 +448,12 @@ impl GitRepo {
                 let url = remote.url()?.to_string();
                 Some(RemoteInfo {
                     name: name.clone(),
-                    kind: RemoteKind::from_name(&name),
-                    host: RemoteHost::from_url(&url),
+                    kind: if preferred == Some(name.as_str()) {
+                        RemoteKind::Preferred
+                    } else {
+                        RemoteKind::from_name(&name)
+                    },
+                    host: RemoteHost::from_url_with_hosts(&url, hosts),
                     url,
                 })
             })
@@ -268,8 +461,12 @@ impl GitRepo {
     }
 
     /// Extract remote URL from repository, preferring upstream over origin.
-    fn extract_remote_url(repo: &Repository) -> Option<String> {
-        let mut remotes = Self::collect_remotes(repo);
+    fn extract_remote_url(
+        repo: &Repository,
+        hosts: &ForgeHosts,
+        preferred: Option<&str>,
+    ) -> Option<String> {
+        let mut remotes = Self::collect_remotes(repo, hosts, preferred);
         remotes.sort_by_key(RemoteInfo::priority);
         remotes.into_iter().next().map(|r| r.url)
     }
@@ -323,6 +520,7 @@ impl GitRepo {
 
         // 7. Fetch the specific commit
         fetch_commit(&self.path, remote_url, hash_str)?;
+        self.invalidate_tags_cache();
 
         // 8. Mark as fetched
         self.fetch_state
@@ -371,10 +569,54 @@ impl GitRepo {
         })
     }
 
+    /// Whether `path` is a directory at HEAD.
+    pub fn is_dir_at_head(&self, path: &str) -> bool {
+        self.with_repo(|repo| {
+            repo.head()
+                .and_then(|head| head.peel_to_tree())
+                .and_then(|tree| tree.get_path(Path::new(path)))
+                .is_ok_and(|entry| entry.kind() == Some(ObjectType::Tree))
+        })
+    }
+
+    /// Whether any file at HEAD matches the glob `pattern`.
+    pub fn has_paths_matching_at_head(&self, pattern: &str) -> bool {
+        let Some(matcher) = PathMatcher::new(pattern) else {
+            return false;
+        };
+        self.with_repo(|repo| {
+            let Ok(tree) = repo.head().and_then(|head| head.peel_to_tree()) else {
+                return false;
+            };
+
+            let mut found = false;
+            // Aborting the walk reports an error, which just means we're done
+            let _ = tree.walk(TreeWalkMode::PreOrder, |root, entry| {
+                if entry.kind() == Some(ObjectType::Blob)
+                    && matcher.is_match(&format!("{root}{}", entry.name().unwrap_or_default()))
+                {
+                    found = true;
+                    return TreeWalkResult::Abort;
+                }
+                TreeWalkResult::Ok
+            });
+            found
+        })
+    }
+
     pub fn has_tag_named(&self, name: &str) -> bool {
         self.get_tags().into_iter().any(|tag| tag.name == name)
     }
 
+    /// The commit a local branch points at.
+    pub fn branch_tip(&self, name: &str) -> Option<CommitInfo> {
+        self.with_repo(|repo| {
+            let branch = repo.find_branch(name, git2::BranchType::Local).ok()?;
+            let commit = branch.get().peel_to_commit().ok()?;
+            Some(Self::commit_to_info(&commit))
+        })
+    }
+
     pub fn find_branch_path_match(&self, segments: &[String]) -> Option<(String, Vec<String>)> {
         // Collect candidates inside the closure to avoid lifetime issues with References
         let candidates: Vec<(String, Vec<String>)> = self.with_repo(|repo| {
@@ -440,8 +682,24 @@ impl GitRepo {
     }
 
     /// Find a file in the repository
+    ///
+    /// Renames are followed, so authors from before the file got its current
+    /// name are included. History is only walked until enough previous authors
+    /// are found, so only the renames met on the way are reported; see
+    /// [`GitRepo::find_file_with_rename_chain`] for all of them.
     #[must_use]
     pub fn find_file_on_branch(&self, branch: &str, path: &str) -> Option<FileInfo> {
+        self.find_file(branch, path, false)
+    }
+
+    /// Like [`GitRepo::find_file_on_branch`], but walks the file's history back
+    /// to its creation to report every earlier name.
+    #[must_use]
+    pub fn find_file_with_rename_chain(&self, branch: &str, path: &str) -> Option<FileInfo> {
+        self.find_file(branch, path, true)
+    }
+
+    fn find_file(&self, branch: &str, path: &str, full_rename_chain: bool) -> Option<FileInfo> {
         self.with_repo(|repo| {
             let obj = repo.revparse_single(branch).ok()?;
             let commit = obj.peel_to_commit().ok()?;
@@ -454,15 +712,20 @@ impl GitRepo {
 
                 if commit_touches_file(&commit, path) {
                     let commit_info = Self::commit_to_info(&commit);
-                    let previous_authors =
-                        Self::get_previous_authors_from(repo, path, &commit, 4, |revwalk| {
-                            revwalk.push(commit.id())
-                        });
+                    let (previous_authors, renames) = Self::get_previous_authors_from(
+                        repo,
+                        path,
+                        &commit,
+                        4,
+                        full_rename_chain,
+                        |revwalk| revwalk.push(commit.id()),
+                    );
 
                     return Some(FileInfo {
                         path: path.to_string(),
                         last_commit: commit_info,
                         previous_authors,
+                        renames,
                     });
                 }
             }
@@ -471,26 +734,228 @@ impl GitRepo {
         })
     }
 
+    /// Find the latest change to files matching the glob `pattern`, and who
+    /// changed them most in the `CONTRIBUTOR_WINDOW_DAYS` before it.
+    ///
+    /// Merge commits are skipped, like `git log` does for path-limited history.
+    #[must_use]
+    pub fn find_paths_on_branch(&self, branch: &str, pattern: &str) -> Option<PathsInfo> {
+        let matcher = PathMatcher::new(pattern)?;
+        self.with_repo(|repo| {
+            let obj = repo.revparse_single(branch).ok()?;
+            let commit = obj.peel_to_commit().ok()?;
+            let mut revwalk = repo.revwalk().ok()?;
+            revwalk.set_sorting(Sort::TOPOLOGICAL | Sort::TIME).ok()?;
+            revwalk.push(commit.id()).ok()?;
+
+            let mut last: Option<(CommitInfo, Vec<String>)> = None;
+            let mut window_start = None;
+            let mut contributors: Vec<Contributor> = Vec::new();
+
+            for oid in revwalk {
+                let Ok(oid) = oid else { continue };
+                let Ok(commit) = repo.find_commit(oid) else {
+                    continue;
+                };
+
+                // Commits come newest first, so everything from here on is too old
+                if window_start.is_some_and(|start| git_time_to_datetime(commit.time()) < start) {
+                    break;
+                }
+
+                let changed = changed_paths(repo, &commit, &matcher);
+                if changed.is_empty() {
+                    continue;
+                }
+
+                if last.is_none() {
+                    let info = Self::commit_to_info(&commit);
+                    window_start = Some(info.date - Duration::days(CONTRIBUTOR_WINDOW_DAYS));
+                    last = Some((info, changed));
+                }
+
+                let author = commit.author();
+                let name = author.name().unwrap_or("Unknown");
+                let email = author.email().unwrap_or("");
+                match contributors
+                    .iter_mut()
+                    .find(|c| c.name == name && c.email == email)
+                {
+                    Some(contributor) => contributor.commits += 1,
+                    None => contributors.push(Contributor {
+                        name: name.to_string(),
+                        email: email.to_string(),
+                        commits: 1,
+                    }),
+                }
+            }
+
+            let (last_commit, changed_paths) = last?;
+            // Stable sort: ties go to whoever changed things most recently
+            contributors.sort_by_key(|c| std::cmp::Reverse(c.commits));
+            contributors.truncate(TOP_CONTRIBUTORS);
+
+            Some(PathsInfo {
+                pattern: pattern.to_string(),
+                last_commit,
+                changed_paths,
+                top_contributors: contributors,
+                window_start: window_start?,
+            })
+        })
+    }
+
+    /// Commits that changed a file, most recent first, following renames.
+    ///
+    /// Skips the `skip` most recent changes and returns at most `limit` of the
+    /// rest. Returns `None` if no commit on `branch` ever touched the file.
+    #[must_use]
+    pub fn file_history(
+        &self,
+        branch: &str,
+        path: &str,
+        skip: usize,
+        limit: usize,
+    ) -> Option<Vec<FileChange>> {
+        self.with_repo(|repo| {
+            let obj = repo.revparse_single(branch).ok()?;
+            let commit = obj.peel_to_commit().ok()?;
+            let mut revwalk = repo.revwalk().ok()?;
+            revwalk.push(commit.id()).ok()?;
+
+            let mut path = path.to_string();
+            let mut changes = Vec::new();
+            let mut seen = 0;
+
+            for oid in revwalk {
+                if changes.len() >= limit {
+                    break;
+                }
+
+                let Ok(oid) = oid else { continue };
+                let Ok(commit) = repo.find_commit(oid) else {
+                    continue;
+                };
+
+                if !commit_touches_file(&commit, &path) {
+                    continue;
+                }
+
+                if seen >= skip {
+                    changes.push(FileChange {
+                        commit: Self::commit_to_info(&commit),
+                        path: path.clone(),
+                    });
+                }
+                seen += 1;
+
+                // Older commits know the file by its old name
+                if let Some(old_path) = rename_source(repo, &commit, &path) {
+                    path = old_path;
+                }
+            }
+
+            (seen > 0).then_some(changes)
+        })
+    }
+
+    /// Blame `lines` of a file as of `branch`, one hunk per run of lines from the same commit.
+    pub fn blame_lines(
+        &self,
+        branch: &str,
+        path: &str,
+        lines: LineRange,
+    ) -> WtgResult<Vec<BlameHunk>> {
+        self.with_repo(|repo| {
+            let commit = repo.revparse_single(branch)?.peel_to_commit()?;
+            let entry = commit
+                .tree()?
+                .get_path(Path::new(path))
+                .map_err(|_| WtgError::NotFound(path.to_string()))?;
+            let blob = entry.to_object(repo)?.peel_to_blob()?;
+            let content = String::from_utf8_lossy(blob.content());
+            let file_lines: Vec<&str> = content.lines().collect();
+
+            if lines.start > file_lines.len() {
+                return Err(WtgError::LinesOutOfRange {
+                    path: path.to_string(),
+                    lines,
+                    line_count: file_lines.len(),
+                });
+            }
+            let end = lines.end.min(file_lines.len());
+
+            let mut options = BlameOptions::new();
+            options
+                .newest_commit(commit.id())
+                .min_line(lines.start)
+                .max_line(end);
+            let blame = repo.blame_file(Path::new(path), Some(&mut options))?;
+
+            let mut hunks = Vec::new();
+            for hunk in blame.iter() {
+                // Hunks may start before or run past the requested lines
+                let start = hunk.final_start_line().max(lines.start);
+                let last = (hunk.final_start_line() + hunk.lines_in_hunk())
+                    .saturating_sub(1)
+                    .min(end);
+                if start > last {
+                    continue;
+                }
+
+                let origin = repo.find_commit(hunk.final_commit_id())?;
+                hunks.push(BlameHunk {
+                    commit: Self::commit_to_info(&origin),
+                    start_line: start,
+                    end_line: last,
+                    path: hunk
+                        .path()
+                        .map_or_else(|| path.to_string(), |p| p.to_string_lossy().into_owned()),
+                    lines: file_lines[start - 1..last]
+                        .iter()
+                        .map(|line| (*line).to_string())
+                        .collect(),
+                });
+            }
+
+            Ok(hunks)
+        })
+    }
+
+    /// Up to `limit` distinct authors who touched the file before `last_commit`,
+    /// and the renames found on the way.
+    ///
+    /// Follows renames, so the walk goes on under the file's old name. It stops
+    /// once `limit` authors are found, unless `full_rename_chain` asks for every
+    /// rename back to the commit that created the file.
     fn get_previous_authors_from(
         repo: &Repository,
         path: &str,
         last_commit: &Commit,
         limit: usize,
+        full_rename_chain: bool,
         seed_revwalk: impl FnOnce(&mut git2::Revwalk) -> Result<(), git2::Error>,
-    ) -> Vec<(String, String, String)> {
+    ) -> (Vec<(String, String, String)>, Vec<FileRename>) {
         let mut authors = Vec::new();
+        let mut renames = Vec::new();
         let Ok(mut revwalk) = repo.revwalk() else {
-            return authors;
+            return (authors, renames);
         };
 
         if seed_revwalk(&mut revwalk).is_err() {
-            return authors;
+            return (authors, renames);
+        }
+
+        // The last commit may have renamed the file itself
+        let mut path = path.to_string();
+        if follow_rename(repo, last_commit, &mut path, &mut renames) {
+            return (authors, renames);
         }
 
         let mut found_last = false;
 
         for oid in revwalk {
-            if authors.len() >= limit {
+            if authors.len() >= limit && !full_rename_chain {
                 break;
             }
 
@@ -507,26 +972,50 @@ impl GitRepo {
                 continue;
             }
 
-            if !commit_touches_file(&commit, path) {
+            if !commit_touches_file(&commit, &path) {
                 continue;
             }
 
-            let author = commit.author();
-            let name = author.name().unwrap_or("Unknown").to_string();
-            let email = author.email().unwrap_or("").to_string();
+            if authors.len() < limit {
+                let author = commit.author();
+                let name = author.name().unwrap_or("Unknown").to_string();
+                let email = author.email().unwrap_or("").to_string();
 
-            // Skip duplicates
-            if !authors.iter().any(|(_, n, e)| *n == name && *e == email) {
-                authors.push((commit.id().to_string(), name, email));
+                // Skip duplicates
+                if !authors.iter().any(|(_, n, e)| *n == name && *e == email) {
+                    authors.push((commit.id().to_string(), name, email));
+                }
+            }
+
+            if follow_rename(repo, &commit, &mut path, &mut renames) {
+                break;
             }
         }
 
-        authors
+        (authors, renames)
     }
 
     /// Get all tags in the repository.
+    ///
+    /// Tags are peeled once and cached until the next fetch, so repeated
+    /// release lookups (e.g. in batch mode) don't rescan every tag.
     #[must_use]
     pub fn get_tags(&self) -> Vec<TagInfo> {
+        let mut cache = self.tags_cache.lock().expect("tags cache mutex poisoned");
+        cache.get_or_insert_with(|| self.scan_tags()).clone()
+    }
+
+    /// Drop cached tags after the repository may have gained new ones.
+    fn invalidate_tags_cache(&self) {
+        *self.tags_cache.lock().expect("tags cache mutex poisoned") = None;
+        self.tag_tips_containing
+            .lock()
+            .expect("tag tips cache mutex poisoned")
+            .clear();
+    }
+
+    /// Peel every tag in the repository to its commit.
+    fn scan_tags(&self) -> Vec<TagInfo> {
         self.with_repo(|repo| {
             let mut tags = Vec::new();
 
@@ -630,6 +1119,7 @@ impl GitRepo {
         };
 
         fetch_tags(&self.path, remote_url)?;
+        self.invalidate_tags_cache();
 
         self.fetch_state
             .lock()
@@ -678,47 +1168,30 @@ impl GitRepo {
     /// Returns None if no tags contain the commit
     /// Performance: Filters by timestamp before doing expensive ancestry checks
     fn find_tags_containing_commit(&self, commit_oid: Oid) -> Option<Vec<TagInfo>> {
+        let tags = self.get_tags();
+
         self.with_repo(|repo| {
             let target_commit = repo.find_commit(commit_oid).ok()?;
             let target_timestamp = target_commit.time().seconds();
 
-            let mut containing_tags = Vec::new();
-            let tag_names = repo.tag_names(None).ok()?;
-
-            for tag_name in tag_names.iter().flatten() {
-                if let Ok(obj) = repo.revparse_single(tag_name)
-                    && let Ok(commit) = obj.peel_to_commit()
-                {
-                    let tag_oid = commit.id();
-
+            let containing_tags: Vec<TagInfo> = tags
+                .into_iter()
+                .filter(|tag| {
                     // Performance: Skip tags with commits older than target
                     // (they cannot possibly contain the target commit)
-                    if commit.time().seconds() < target_timestamp {
-                        continue;
+                    if tag.created_at.timestamp() < target_timestamp {
+                        return false;
                     }
 
                     // Check if this tag points to the commit or if the tag is a descendant
-                    if tag_oid == commit_oid
-                        || repo
-                            .graph_descendant_of(tag_oid, commit_oid)
-                            .unwrap_or(false)
-                    {
-                        let semver_info = parse_semver(tag_name);
-
-                        containing_tags.push(TagInfo {
-                            name: tag_name.to_string(),
-                            commit_hash: tag_oid.to_string(),
-                            semver_info,
-                            created_at: git_time_to_datetime(commit.time()),
-                            is_release: false,
-                            release_name: None,
-                            release_url: None,
-                            published_at: None,
-                            tag_url: None,
-                        });
-                    }
-                }
-            }
+                    Oid::from_str(&tag.commit_hash).is_ok_and(|tag_oid| {
+                        tag_oid == commit_oid
+                            || repo
+                                .graph_descendant_of(tag_oid, commit_oid)
+                                .unwrap_or(false)
+                    })
+                })
+                .collect();
 
             if containing_tags.is_empty() {
                 None
@@ -728,13 +1201,165 @@ impl GitRepo {
         })
     }
 
+    /// Find local and remote-tracking branches whose tip contains the commit.
+    ///
+    /// Local branches come first, each group sorted by name. Symbolic refs like
+    /// `origin/HEAD` are skipped.
+    #[must_use]
+    pub fn branches_containing_commit(&self, commit_hash: &str) -> Vec<BranchInfo> {
+        let Ok(commit_oid) = Oid::from_str(commit_hash) else {
+            return Vec::new();
+        };
+
+        let mut branches: Vec<BranchInfo> = self.with_repo(|repo| {
+            let Ok(iter) = repo.branches(None) else {
+                return Vec::new();
+            };
+
+            iter.filter_map(Result::ok)
+                .filter(|(branch, _)| branch.get().symbolic_target().is_none())
+                .filter_map(|(branch, kind)| {
+                    let tip = branch.get().target()?;
+                    let contains = tip == commit_oid
+                        || repo.graph_descendant_of(tip, commit_oid).unwrap_or(false);
+                    if !contains {
+                        return None;
+                    }
+                    Some(BranchInfo {
+                        name: branch.name().ok().flatten()?.to_string(),
+                        is_remote: kind == git2::BranchType::Remote,
+                    })
+                })
+                .collect()
+        });
+
+        branches.sort_by(|a, b| (a.is_remote, &a.name).cmp(&(b.is_remote, &b.name)));
+        branches
+    }
+
+    /// Find commits carrying the same change as `commit_hash`: cherry-picks and backports.
+    ///
+    /// Two commits are equivalent when one names the other in a
+    /// `(cherry picked from commit ...)` trailer, or when they have the same
+    /// patch-id. Only copies that shipped matter, so the walk starts from the
+    /// tags that don't contain the commit and stops at the commit's author date:
+    /// copies are committed after the change was written. Patch-ids are only
+    /// compared for commits with the same author and author date, which
+    /// cherry-picks and rebases preserve.
+    #[must_use]
+    pub fn find_equivalent_commits(&self, commit_hash: &str) -> Vec<CommitInfo> {
+        let Ok(commit_oid) = Oid::from_str(commit_hash) else {
+            return Vec::new();
+        };
+        let tips = self.tag_tips();
+        let containing = self.tag_tips_containing(commit_oid, &tips);
+
+        self.with_repo(|repo| {
+            let Ok(target) = repo.find_commit(commit_oid) else {
+                return Vec::new();
+            };
+            let target_hash = target.id().to_string();
+            let mut equivalent: Vec<Commit> = Vec::new();
+
+            // The commit may itself be a cherry-pick of some original
+            for sha in cherry_pick_sources(target.message().unwrap_or_default()) {
+                if let Ok(origin) = repo
+                    .revparse_single(sha)
+                    .and_then(|obj| obj.peel_to_commit())
+                    && origin.id() != commit_oid
+                {
+                    equivalent.push(origin);
+                }
+            }
+
+            let Ok(mut walk) = repo.revwalk() else {
+                return Vec::new();
+            };
+            let _ = walk.set_sorting(Sort::TIME);
+            for tip in tips.difference(&containing) {
+                let _ = walk.push(*tip);
+            }
+            let _ = walk.hide(commit_oid);
+
+            let target_author = target.author();
+            let written_at = target_author.when().seconds();
+            let mut target_patch_id = None;
+            for oid in walk.filter_map(Result::ok) {
+                let Ok(candidate) = repo.find_commit(oid) else {
+                    continue;
+                };
+                if candidate.time().seconds() < written_at {
+                    break; // Newest first: everything left predates the change
+                }
+                if candidate.parent_count() > 1 {
+                    continue; // Merges aren't cherry-picks
+                }
+
+                let picked = cherry_pick_sources(candidate.message().unwrap_or_default())
+                    .any(|sha| target_hash.starts_with(&sha.to_ascii_lowercase()));
+
+                let same_change = !picked && {
+                    let author = candidate.author();
+                    author.email_bytes() == target_author.email_bytes()
+                        && author.when() == target_author.when()
+                        && patch_id(repo, &candidate).is_some_and(|id| {
+                            *target_patch_id.get_or_insert_with(|| patch_id(repo, &target))
+                                == Some(id)
+                        })
+                };
+
+                if picked || same_change {
+                    equivalent.push(candidate);
+                }
+            }
+
+            let mut seen = HashSet::new();
+            equivalent
+                .iter()
+                .filter(|commit| seen.insert(commit.id()))
+                .map(Self::commit_to_info)
+                .collect()
+        })
+    }
+
+    /// Commits the tags point at, without duplicates.
+    fn tag_tips(&self) -> HashSet<Oid> {
+        self.get_tags()
+            .iter()
+            .filter_map(|tag| Oid::from_str(&tag.commit_hash).ok())
+            .collect()
+    }
+
+    /// The `tips` that contain the commit, cached until the next fetch so a
+    /// commit queried again (in a batch, or as a copy) skips the ancestry checks.
+    fn tag_tips_containing(&self, commit_oid: Oid, tips: &HashSet<Oid>) -> Arc<HashSet<Oid>> {
+        let cache = || {
+            self.tag_tips_containing
+                .lock()
+                .expect("tag tips cache mutex poisoned")
+        };
+        if let Some(containing) = cache().get(&commit_oid) {
+            return Arc::clone(containing);
+        }
+
+        let containing: Arc<HashSet<Oid>> = Arc::new(self.with_repo(|repo| {
+            tips.iter()
+                .copied()
+                .filter(|&tip| {
+                    tip == commit_oid || repo.graph_descendant_of(tip, commit_oid).unwrap_or(false)
+                })
+                .collect()
+        }));
+        cache().insert(commit_oid, Arc::clone(&containing));
+        containing
+    }
+
     /// Get commit timestamp for sorting (helper)
     pub(crate) fn get_commit_timestamp(&self, commit_hash: &str) -> i64 {
         self.with_repo(|repo| {
             Oid::from_str(commit_hash)
                 .and_then(|oid| repo.find_commit(oid))
-                .map(|c| c.time().seconds())
-                .unwrap_or(0)
+                .map_or(0, |c| c.time().seconds())
         })
     }
 
@@ -749,7 +1374,10 @@ impl GitRepo {
     /// Iterate over all remotes in the repository.
     /// Returns an iterator of `RemoteInfo`.
     pub fn remotes(&self) -> impl Iterator<Item = RemoteInfo> {
-        self.with_repo(Self::collect_remotes).into_iter()
+        self.with_repo(|repo| {
+            Self::collect_remotes(repo, &self.hosts, self.preferred_remote.as_deref())
+        })
+        .into_iter()
     }
 
     /// Get the GitHub remote info.
@@ -771,7 +1399,7 @@ impl GitRepo {
         remotes
             .into_iter()
             .find(|r| r.host == Some(RemoteHost::GitHub))
-            .and_then(|r| parse_github_repo_url(&r.url))
+            .and_then(|r| parse_github_repo_url(&r.url, &self.hosts))
     }
 
     /// Convert a `git2::Commit` to `CommitInfo`
@@ -802,6 +1430,26 @@ pub(crate) fn looks_like_commit_hash(input: &str) -> bool {
     trimmed.len() >= 7 && trimmed.len() <= 40 && trimmed.chars().all(|ch| ch.is_ascii_hexdigit())
 }
 
+/// Commit hashes named in `(cherry picked from commit ...)` trailers.
+fn cherry_pick_sources(message: &str) -> impl Iterator<Item = &str> {
+    CHERRY_PICK_REGEX
+        .captures_iter(message)
+        .filter_map(|caps| caps.name("sha").map(|sha| sha.as_str()))
+}
+
+/// Stable patch-id of a commit's change (`None` for empty commits).
+fn patch_id(repo: &Repository, commit: &Commit) -> Option<Oid> {
+    let tree = commit.tree().ok()?;
+    let parent_tree = commit.parent(0).ok().and_then(|parent| parent.tree().ok());
+    let diff = repo
+        .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
+        .ok()?;
+    if diff.deltas().len() == 0 {
+        return None; // Every empty commit would match every other
+    }
+    diff.patchid(None).ok()
+}
+
 /// Check if a commit touches a specific file
 fn commit_touches_file(commit: &Commit, path: &str) -> bool {
     let Ok(tree) = commit.tree() else {
@@ -830,6 +1478,127 @@ fn commit_touches_file(commit: &Commit, path: &str) -> bool {
     false
 }
 
+/// Files matched by a glob, plus the directory before its first wildcard,
+/// which narrows down the diffs that have to be computed.
+struct PathMatcher {
+    glob: GlobMatcher,
+    prefix: String,
+}
+
+impl PathMatcher {
+    fn new(pattern: &str) -> Option<Self> {
+        let glob = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .ok()?
+            .compile_matcher();
+        let literal = &pattern[..pattern.find(['*', '?', '[', '{']).unwrap_or(pattern.len())];
+        let prefix = literal.rsplit_once('/').map_or("", |(dir, _)| dir);
+        Some(Self {
+            glob,
+            prefix: prefix.to_string(),
+        })
+    }
+
+    fn is_match(&self, path: &str) -> bool {
+        self.glob.is_match(path)
+    }
+}
+
+/// Files matching `matcher` that `commit` changed compared to its parent.
+///
+/// Merge commits change nothing of their own and report no paths.
+fn changed_paths(repo: &Repository, commit: &Commit, matcher: &PathMatcher) -> Vec<String> {
+    if commit.parent_count() > 1 {
+        return Vec::new();
+    }
+    let Ok(tree) = commit.tree() else {
+        return Vec::new();
+    };
+    let parent_tree = commit.parent(0).ok().and_then(|parent| parent.tree().ok());
+
+    let mut options = DiffOptions::new();
+    if !matcher.prefix.is_empty() {
+        options.pathspec(&matcher.prefix);
+    }
+    let Ok(diff) = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut options))
+    else {
+        return Vec::new();
+    };
+
+    diff.deltas()
+        .filter_map(|delta| delta.new_file().path().or_else(|| delta.old_file().path()))
+        .map(|path| path.to_string_lossy().into_owned())
+        .filter(|path| matcher.is_match(path))
+        .collect()
+}
+
+/// Switch `path` to the file's old name if `commit` renamed it, recording the rename.
+///
+/// Returns whether `commit` created the file (added it without renaming it),
+/// which means there's no older history to follow.
+fn follow_rename(
+    repo: &Repository,
+    commit: &Commit,
+    path: &mut String,
+    renames: &mut Vec<FileRename>,
+) -> bool {
+    if let Some(old_path) = rename_source(repo, commit, path) {
+        renames.push(FileRename {
+            from: old_path.clone(),
+            to: std::mem::replace(path, old_path),
+            commit_hash: commit.id().to_string(),
+        });
+        return false;
+    }
+    file_added_in(commit, path)
+}
+
+/// Whether `path` is new in `commit`: present in it but in none of its parents.
+fn file_added_in(commit: &Commit, path: &str) -> bool {
+    let target_path = Path::new(path);
+    let Ok(tree) = commit.tree() else {
+        return false;
+    };
+    tree.get_path(target_path).is_ok()
+        && commit.parents().all(|parent| {
+            parent
+                .tree()
+                .is_ok_and(|parent_tree| parent_tree.get_path(target_path).is_err())
+        })
+}
+
+/// The path a file was renamed from in `commit`, if the commit renamed it to `path`.
+///
+/// Renames are detected by content similarity, as `git log --follow` does.
+/// Merge commits are skipped: a rename shows up in the commit that made it.
+fn rename_source(repo: &Repository, commit: &Commit, path: &str) -> Option<String> {
+    if commit.parent_count() != 1 {
+        return None;
+    }
+    let tree = commit.tree().ok()?;
+    let parent_tree = commit.parent(0).ok()?.tree().ok()?;
+    let target_path = Path::new(path);
+
+    // Only a file that's new in this commit can have been renamed here
+    if tree.get_path(target_path).is_err() || parent_tree.get_path(target_path).is_ok() {
+        return None;
+    }
+
+    let mut diff = repo
+        .diff_tree_to_tree(Some(&parent_tree), Some(&tree), None)
+        .ok()?;
+    diff.find_similar(Some(DiffFindOptions::new().renames(true)))
+        .ok()?;
+
+    diff.deltas()
+        .find(|delta| {
+            delta.status() == Delta::Renamed && delta.new_file().path() == Some(target_path)
+        })
+        .and_then(|delta| delta.old_file().path())
+        .map(|old_path| old_path.to_string_lossy().into_owned())
+}
+
 fn tree_entries_differ(
     current: Option<&git2::TreeEntry<'_>>,
     previous: Option<&git2::TreeEntry<'_>>,
@@ -873,10 +1642,19 @@ fn get_cache_dir() -> WtgResult<PathBuf> {
     Ok(cache_dir)
 }
 
+/// HTTPS clone URL for a GitHub repository on its host
+fn clone_url(gh_repo_info: &GhRepoInfo) -> String {
+    format!(
+        "https://{}/{}/{}.git",
+        gh_repo_info.host(),
+        gh_repo_info.owner(),
+        gh_repo_info.repo()
+    )
+}
+
 /// Clone a remote repository using subprocess with filter=blob:none, falling back to git2 if needed
 fn clone_remote_repo(
-    owner: &str,
-    repo: &str,
+    gh_repo_info: &GhRepoInfo,
     target_path: &Path,
     emit: &dyn Fn(Notice),
 ) -> WtgResult<()> {
@@ -885,7 +1663,7 @@ fn clone_remote_repo(
         fs::create_dir_all(parent)?;
     }
 
-    let repo_url = format!("https://github.com/{owner}/{repo}.git");
+    let repo_url = clone_url(gh_repo_info);
 
     emit(Notice::CloningRepo {
         url: repo_url.clone(),
@@ -1148,6 +1926,14 @@ mod tests {
             renamed_info.last_commit.hash,
             post_rename_commit.to_string()
         );
+        assert_eq!(
+            renamed_info.renames,
+            vec![FileRename {
+                from: ORIGINAL_PATH.to_string(),
+                to: RENAMED_PATH.to_string(),
+                commit_hash: rename_commit.to_string(),
+            }]
+        );
 
         let original_info = git_repo
             .find_file_on_branch("HEAD", ORIGINAL_PATH)
@@ -1165,6 +1951,342 @@ mod tests {
         assert_eq!(deleted_info.last_commit.hash, delete_commit.to_string());
     }
 
+    #[test]
+    fn file_history_follows_renames() {
+        let temp = tempdir().expect("temp dir");
+        let repo = Repository::init(temp.path()).expect("git repo");
+
+        commit_file(&repo, "README.md", "noise", "add distraction");
+        let seed = commit_file(&repo, "old/config.json", "{\"version\":1}", "seed config");
+        let tweak = commit_file(&repo, "old/config.json", "{\"version\":2}", "config tweak");
+        let rename = rename_file(&repo, "old/config.json", "new/config.json", "move config");
+        let update = commit_file(&repo, "new/config.json", "{\"version\":3}", "update config");
+
+        let git_repo = GitRepo::from_path(temp.path()).expect("git repo wrapper");
+        let summary = |changes: Vec<FileChange>| -> Vec<(String, String)> {
+            changes
+                .into_iter()
+                .map(|change| (change.commit.hash, change.path))
+                .collect()
+        };
+
+        let history = git_repo
+            .file_history("HEAD", "new/config.json", 0, 10)
+            .expect("history");
+        assert_eq!(
+            summary(history),
+            vec![
+                (update.to_string(), "new/config.json".to_string()),
+                (rename.to_string(), "new/config.json".to_string()),
+                (tweak.to_string(), "old/config.json".to_string()),
+                (seed.to_string(), "old/config.json".to_string()),
+            ]
+        );
+
+        let page = git_repo
+            .file_history("HEAD", "new/config.json", 1, 2)
+            .expect("second page");
+        assert_eq!(
+            summary(page),
+            vec![
+                (rename.to_string(), "new/config.json".to_string()),
+                (tweak.to_string(), "old/config.json".to_string()),
+            ]
+        );
+
+        assert!(
+            git_repo
+                .file_history("HEAD", "missing.txt", 0, 10)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn find_file_follows_renames() {
+        let temp = tempdir().expect("temp dir");
+        let repo = Repository::init(temp.path()).expect("git repo");
+        let notes = (1..=10)
+            .map(|n| format!("note {n}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // Written by someone else under the old name
+        let workdir = repo.workdir().expect("workdir");
+        fs::create_dir_all(workdir.join("old")).expect("create dir");
+        fs::write(workdir.join("old/notes.md"), &notes).expect("write file");
+        let mut index = repo.index().expect("index");
+        index.add_path(Path::new("old/notes.md")).expect("add path");
+        let original_author =
+            git2::Signature::now("Original Author", "original@example.com").expect("sig");
+        write_tree_and_commit_as(&repo, &mut index, "add notes", &original_author);
+
+        // Moved and touched up in one go, so only a similarity check finds the rename
+        let moved = rename_and_edit_file(
+            &repo,
+            "old/notes.md",
+            "docs/notes.md",
+            &notes.replace("note 10", "note ten"),
+            "move notes",
+        );
+        let renamed = rename_file(&repo, "docs/notes.md", "docs/NOTES.md", "shout");
+        let last = commit_file(&repo, "docs/NOTES.md", "rewritten\n", "rewrite notes");
+
+        let git_repo = GitRepo::from_path(temp.path()).expect("git repo wrapper");
+        let info = git_repo
+            .find_file_on_branch("HEAD", "docs/NOTES.md")
+            .expect("file info");
+
+        assert_eq!(info.last_commit.hash, last.to_string());
+        assert_eq!(
+            info.renames,
+            vec![
+                FileRename {
+                    from: "docs/notes.md".to_string(),
+                    to: "docs/NOTES.md".to_string(),
+                    commit_hash: renamed.to_string(),
+                },
+                FileRename {
+                    from: "old/notes.md".to_string(),
+                    to: "docs/notes.md".to_string(),
+                    commit_hash: moved.to_string(),
+                },
+            ]
+        );
+        let authors: Vec<&str> = info
+            .previous_authors
+            .iter()
+            .map(|(_, name, _)| name.as_str())
+            .collect();
+        assert_eq!(authors, vec!["Test User", "Original Author"]);
+    }
+
+    #[test]
+    fn find_file_stops_once_enough_authors_are_found() {
+        let temp = tempdir().expect("temp dir");
+        let repo = Repository::init(temp.path()).expect("git repo");
+
+        commit_file(&repo, "old.md", "a\nb\nc\nd\ne\nf\n", "add old.md");
+        let renamed = rename_file(&repo, "old.md", "new.md", "rename");
+        let workdir = repo.workdir().expect("workdir");
+        for n in 1..=5 {
+            fs::write(workdir.join("new.md"), format!("a\nb\nc\nd\ne\nf\n{n}\n"))
+                .expect("write file");
+            let mut index = repo.index().expect("index");
+            index.add_path(Path::new("new.md")).expect("add path");
+            let author = git2::Signature::now(&format!("Author {n}"), &format!("a{n}@example.com"))
+                .expect("sig");
+            write_tree_and_commit_as(&repo, &mut index, &format!("edit {n}"), &author);
+        }
+
+        let git_repo = GitRepo::from_path(temp.path()).expect("git repo wrapper");
+
+        // Four previous authors are found before the walk reaches the rename
+        let info = git_repo
+            .find_file_on_branch("HEAD", "new.md")
+            .expect("file info");
+        assert_eq!(info.previous_authors.len(), 4);
+        assert!(info.renames.is_empty());
+
+        let info = git_repo
+            .find_file_with_rename_chain("HEAD", "new.md")
+            .expect("file info");
+        assert_eq!(info.previous_authors.len(), 4);
+        assert_eq!(
+            info.renames,
+            vec![FileRename {
+                from: "old.md".to_string(),
+                to: "new.md".to_string(),
+                commit_hash: renamed.to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn find_paths_matches_directories_and_globs() {
+        let temp = tempdir().expect("temp dir");
+        let repo = Repository::init(temp.path()).expect("git repo");
+        let workdir = repo.workdir().expect("workdir");
+
+        fs::create_dir_all(workdir.join("crates/a")).expect("create dir");
+        fs::write(workdir.join("crates/a/Cargo.toml"), "[package]\n").expect("write file");
+        let mut index = repo.index().expect("index");
+        index
+            .add_path(Path::new("crates/a/Cargo.toml"))
+            .expect("add path");
+        let other = git2::Signature::now("Other Author", "other@example.com").expect("sig");
+        write_tree_and_commit_as(&repo, &mut index, "add crate a", &other);
+
+        let manifest = commit_file(&repo, "crates/b/Cargo.toml", "[package]\n", "add crate b");
+        commit_file(&repo, "crates/b/src/lib.rs", "// b\n", "add b source");
+        let backend = commit_file(&repo, "src/backend/git.rs", "// git\n", "add backend");
+        commit_file(&repo, "README.md", "# readme\n", "add readme");
+
+        let git_repo = GitRepo::from_path(temp.path()).expect("git repo wrapper");
+        assert!(git_repo.is_dir_at_head("src/backend"));
+        assert!(!git_repo.is_dir_at_head("README.md"));
+        assert!(git_repo.has_paths_matching_at_head("crates/*/Cargo.toml"));
+        assert!(!git_repo.has_paths_matching_at_head("*.toml"));
+
+        let glob = git_repo
+            .find_paths_on_branch("HEAD", "crates/*/Cargo.toml")
+            .expect("glob info");
+        assert_eq!(glob.last_commit.hash, manifest.to_string());
+        assert_eq!(glob.changed_paths, vec!["crates/b/Cargo.toml"]);
+        let contributors: Vec<(&str, usize)> = glob
+            .top_contributors
+            .iter()
+            .map(|c| (c.name.as_str(), c.commits))
+            .collect();
+        assert_eq!(contributors, vec![("Test User", 1), ("Other Author", 1)]);
+
+        let dir = git_repo
+            .find_paths_on_branch("HEAD", "src/backend/**")
+            .expect("directory info");
+        assert_eq!(dir.last_commit.hash, backend.to_string());
+        assert_eq!(dir.changed_paths, vec!["src/backend/git.rs"]);
+
+        assert!(git_repo.find_paths_on_branch("HEAD", "docs/**").is_none());
+    }
+
+    #[test]
+    fn blames_line_ranges() {
+        let temp = tempdir().expect("temp dir");
+        let repo = Repository::init(temp.path()).expect("git repo");
+
+        let first = commit_file(&repo, "src/lib.rs", "a\nb\nc\n", "initial");
+        let second = commit_file(&repo, "src/lib.rs", "a\nB\nc\nd\n", "change b, add d");
+
+        let git_repo = GitRepo::from_path(temp.path()).expect("git repo wrapper");
+        let blame = |start, end| {
+            git_repo
+                .blame_lines("HEAD", "src/lib.rs", LineRange { start, end })
+                .map(|hunks| {
+                    hunks
+                        .into_iter()
+                        .map(|h| (h.commit.hash, h.start_line, h.end_line, h.lines))
+                        .collect::<Vec<_>>()
+                })
+        };
+
+        assert_eq!(
+            blame(2, 3).expect("blame"),
+            vec![
+                (second.to_string(), 2, 2, vec!["B".to_string()]),
+                (first.to_string(), 3, 3, vec!["c".to_string()]),
+            ]
+        );
+        // Ranges past the end of the file are cut short
+        assert_eq!(
+            blame(4, 99).expect("blame"),
+            vec![(second.to_string(), 4, 4, vec!["d".to_string()])]
+        );
+        assert!(blame(10, 12).unwrap_err().is_lines_out_of_range());
+    }
+
+    #[test]
+    fn finds_cherry_picked_copies() {
+        let temp = tempdir().expect("temp dir");
+        let repo = Repository::init(temp.path()).expect("git repo");
+
+        let base = commit_file(&repo, "README.md", "hello", "initial");
+        let fix = commit_file(&repo, "src/lib.rs", "fixed", "fix: important");
+        let docs = commit_file(&repo, "docs.md", "docs", "docs: unrelated");
+        let base = repo.find_commit(base).expect("base commit");
+        repo.branch("release/1.x", &base, false)
+            .expect("release branch");
+
+        // A plain cherry-pick (same patch-id) and a trailer-only backport (different diff)
+        let picked = cherry_pick(&repo, "release/1.x", fix, "fix: important (1.x)");
+        let ported = cherry_pick(
+            &repo,
+            "release/1.x",
+            docs,
+            &format!("fix: important, the 1.x way\n\n(cherry picked from commit {fix})"),
+        );
+        let released = repo.find_object(ported, None).expect("backport commit");
+        repo.tag_lightweight("v1.0.1", &released, false)
+            .expect("release tag");
+
+        // Copies that never shipped don't count
+        repo.branch("wip", &base, false).expect("wip branch");
+        cherry_pick(&repo, "wip", fix, "fix: important (wip)");
+
+        let git_repo = GitRepo::from_path(temp.path()).expect("git repo wrapper");
+
+        let copies: HashSet<String> = git_repo
+            .find_equivalent_commits(&fix.to_string())
+            .into_iter()
+            .map(|commit| commit.hash)
+            .collect();
+        assert_eq!(
+            copies,
+            HashSet::from([picked.to_string(), ported.to_string()])
+        );
+
+        // Works the other way around too: the backport knows its origin
+        let origins: Vec<String> = git_repo
+            .find_equivalent_commits(&ported.to_string())
+            .into_iter()
+            .map(|commit| commit.hash)
+            .collect();
+        assert!(origins.contains(&fix.to_string()));
+        assert!(!origins.contains(&picked.to_string()));
+    }
+
+    #[test]
+    fn parses_cherry_pick_trailers() {
+        let message = "Fix thing\n\n(cherry picked from commit 0123456789abcdef0123456789abcdef01234567)\n\
+                       (cherry picked from commit abcdef1)\nnot (cherry picked from commit 1234567)";
+        assert_eq!(
+            cherry_pick_sources(message).collect::<Vec<_>>(),
+            vec!["0123456789abcdef0123456789abcdef01234567", "abcdef1"]
+        );
+    }
+
+    #[test]
+    fn preferred_remote_beats_upstream() {
+        let temp = tempdir().expect("temp dir");
+        let repo = Repository::init(temp.path()).expect("git repo");
+        repo.remote("upstream", "https://github.com/owner/repo")
+            .expect("upstream");
+        repo.remote("fork", "https://github.com/me/repo")
+            .expect("fork");
+
+        let mut git_repo = GitRepo::from_path(temp.path()).expect("open repo");
+        assert_eq!(git_repo.remote_url(), Some("https://github.com/owner/repo"));
+
+        git_repo.set_preferred_remote(Some("fork".to_string()));
+        assert_eq!(git_repo.remote_url(), Some("https://github.com/me/repo"));
+        let remote = git_repo.github_remote().expect("GitHub remote");
+        assert_eq!(remote.owner(), "me");
+    }
+
+    /// Apply `commit` on top of `branch`, keeping its author like `git cherry-pick` does.
+    fn cherry_pick(repo: &Repository, branch: &str, commit: git2::Oid, message: &str) -> git2::Oid {
+        let commit = repo.find_commit(commit).expect("commit to pick");
+        let branch_ref = format!("refs/heads/{branch}");
+        let onto = repo
+            .find_reference(&branch_ref)
+            .and_then(|reference| reference.peel_to_commit())
+            .expect("branch tip");
+
+        let mut index = repo
+            .cherrypick_commit(&commit, &onto, 0, None)
+            .expect("cherry-pick");
+        let tree_oid = index.write_tree_to(repo).expect("tree oid");
+        let tree = repo.find_tree(tree_oid).expect("tree");
+
+        repo.commit(
+            Some(&branch_ref),
+            &commit.author(),
+            &test_signature(),
+            message,
+            &tree,
+            &[&onto],
+        )
+        .expect("commit")
+    }
+
     fn commit_file(repo: &Repository, path: &str, contents: &str, message: &str) -> git2::Oid {
         let workdir = repo.workdir().expect("workdir");
         let file_path = workdir.join(path);
@@ -1178,6 +2300,28 @@ mod tests {
         write_tree_and_commit(repo, &mut index, message)
     }
 
+    /// Rename a file and rewrite its contents in the same commit.
+    fn rename_and_edit_file(
+        repo: &Repository,
+        from: &str,
+        to: &str,
+        contents: &str,
+        message: &str,
+    ) -> git2::Oid {
+        let workdir = repo.workdir().expect("workdir");
+        fs::remove_file(workdir.join(from)).expect("remove old file");
+        let to_path = workdir.join(to);
+        if let Some(parent) = to_path.parent() {
+            fs::create_dir_all(parent).expect("create dir");
+        }
+        fs::write(&to_path, contents).expect("write file");
+
+        let mut index = repo.index().expect("index");
+        index.remove_path(Path::new(from)).expect("remove old path");
+        index.add_path(Path::new(to)).expect("add new path");
+        write_tree_and_commit(repo, &mut index, message)
+    }
+
     fn rename_file(repo: &Repository, from: &str, to: &str, message: &str) -> git2::Oid {
         let workdir = repo.workdir().expect("workdir");
         let from_path = workdir.join(from);
@@ -1228,11 +2372,19 @@ mod tests {
         repo: &Repository,
         index: &mut git2::Index,
         message: &str,
+    ) -> git2::Oid {
+        write_tree_and_commit_as(repo, index, message, &test_signature())
+    }
+
+    fn write_tree_and_commit_as(
+        repo: &Repository,
+        index: &mut git2::Index,
+        message: &str,
+        sig: &git2::Signature<'_>,
     ) -> git2::Oid {
         index.write().expect("write index");
         let tree_oid = index.write_tree().expect("tree oid");
         let tree = repo.find_tree(tree_oid).expect("tree");
-        let sig = test_signature();
 
         let parents = repo
             .head()
@@ -1243,7 +2395,7 @@ mod tests {
             .collect::<Vec<_>>();
         let parent_refs = parents.iter().collect::<Vec<_>>();
 
-        repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parent_refs)
+        repo.commit(Some("HEAD"), sig, sig, message, &tree, &parent_refs)
             .expect("commit")
     }
 
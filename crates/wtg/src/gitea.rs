@@ -0,0 +1,724 @@
+//! Gitea / Forgejo REST API (v1) client.
+//!
+//! Forgejo is a Gitea fork and keeps its API, so one client serves both.
+//! Covers commits, pull requests, issues (with the pull requests that closed
+//! them), tags, releases and raw file content. Self-hosted instances are
+//! recognized through `WTG_GITEA_HOSTS` or [`ForgeHosts`](crate::remote::ForgeHosts).
+
+use std::env;
+
+use chrono::{DateTime, Utc};
+use octocrab::models::IssueState;
+use serde::{Deserialize, Serialize};
+
+use crate::error::LogError;
+use crate::forge_http::{ForgeAuth, ForgeHttp, encode};
+use crate::git::{CommitInfo, TagInfo, parse_semver};
+use crate::github::{ExtendedIssueInfo, PullRequestInfo, ReleaseInfo, normalize_host};
+
+const RELEASES_PER_PAGE: usize = 50;
+
+/// Environment variable with a comma-separated list of Gitea/Forgejo hosts.
+pub const GITEA_HOSTS_ENV: &str = "WTG_GITEA_HOSTS";
+
+/// Public instances that are always recognized.
+pub(crate) const BUILTIN_HOSTS: [&str; 2] = ["codeberg.org", "gitea.com"];
+
+/// Gitea/Forgejo hosts from `WTG_GITEA_HOSTS`.
+pub(crate) fn gitea_hosts_from_env() -> Vec<String> {
+    let from_env = env::var(GITEA_HOSTS_ENV).unwrap_or_default();
+    let mut hosts: Vec<String> = from_env
+        .split(',')
+        .map(normalize_host)
+        .filter(|host| !host.is_empty())
+        .collect();
+    hosts.sort();
+    hosts.dedup();
+    hosts
+}
+
+/// A Gitea repository: the instance it lives on plus owner and name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct GtRepoInfo {
+    /// Web base URL of the instance, without trailing slash (e.g. `https://codeberg.org`)
+    base_url: String,
+    owner: String,
+    repo: String,
+}
+
+impl GtRepoInfo {
+    #[must_use]
+    pub fn new(base_url: &str, owner: &str, repo: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            owner: owner.to_string(),
+            repo: repo.trim_end_matches(".git").to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    #[must_use]
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+
+    #[must_use]
+    pub fn repo(&self) -> &str {
+        &self.repo
+    }
+
+    /// Parse a git remote URL pointing at a Gitea repository.
+    ///
+    /// Supports `https://host/owner/repo(.git)`, `git@host:owner/repo(.git)`
+    /// and `ssh://git@host[:port]/owner/repo(.git)`.
+    #[must_use]
+    pub fn from_remote_url(url: &str) -> Option<Self> {
+        let url = url.trim();
+
+        let (base_url, path) = if let Some(rest) = url.strip_prefix("git@") {
+            let (host, path) = rest.split_once(':')?;
+            (format!("https://{host}"), path.to_string())
+        } else {
+            let parsed = url::Url::parse(url).ok()?;
+            let host = parsed.host_str()?;
+            // Keep the explicit port for http(s) remotes only - SSH ports don't serve the API
+            let base_url = match (parsed.scheme(), parsed.port()) {
+                ("http", port) => format!("http://{host}{}", port_suffix(port)),
+                ("https", port) => format!("https://{host}{}", port_suffix(port)),
+                _ => format!("https://{host}"),
+            };
+            (base_url, parsed.path().to_string())
+        };
+
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let [owner, repo] = segments.as_slice() else {
+            return None;
+        };
+        Some(Self::new(&base_url, owner, repo))
+    }
+
+    /// Repository route under the API root.
+    fn api_path(&self, resource: &str) -> String {
+        format!(
+            "repos/{}/{}/{resource}",
+            encode(&self.owner),
+            encode(&self.repo)
+        )
+    }
+
+    /// Web URL of the repository.
+    fn web_url(&self) -> String {
+        format!("{}/{}/{}", self.base_url, self.owner, self.repo)
+    }
+}
+
+fn port_suffix(port: Option<u16>) -> String {
+    port.map(|p| format!(":{p}")).unwrap_or_default()
+}
+
+// ============================================
+// API response types
+// ============================================
+
+#[derive(Debug, Deserialize)]
+struct GtUser {
+    login: String,
+    html_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtCommitUser {
+    name: Option<String>,
+    email: Option<String>,
+    date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtCommitDetails {
+    message: Option<String>,
+    author: Option<GtCommitUser>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtCommit {
+    sha: String,
+    html_url: Option<String>,
+    commit: GtCommitDetails,
+    author: Option<GtUser>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtLabel {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtPullRequest {
+    number: u64,
+    title: String,
+    body: Option<String>,
+    state: String,
+    html_url: String,
+    #[serde(default)]
+    merged: bool,
+    merge_commit_sha: Option<String>,
+    user: Option<GtUser>,
+    created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    labels: Vec<GtLabel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtIssue {
+    number: u64,
+    title: String,
+    body: Option<String>,
+    state: String,
+    html_url: String,
+    user: Option<GtUser>,
+    created_at: Option<DateTime<Utc>>,
+    /// Set when the issue is really a pull request
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtIssueRef {
+    number: u64,
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtTimelineEvent {
+    #[serde(rename = "type")]
+    kind: String,
+    ref_issue: Option<GtIssueRef>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtRelease {
+    tag_name: String,
+    name: Option<String>,
+    body: Option<String>,
+    html_url: String,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    prerelease: bool,
+    created_at: Option<DateTime<Utc>>,
+    published_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtTagCommit {
+    sha: String,
+    created: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtTag {
+    name: String,
+    commit: GtTagCommit,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtCompare {
+    total_commits: u64,
+}
+
+impl From<GtCommit> for CommitInfo {
+    fn from(commit: GtCommit) -> Self {
+        let full_message = commit.commit.message.unwrap_or_default();
+        let author = commit.commit.author;
+
+        Self {
+            short_hash: commit.sha[..7.min(commit.sha.len())].to_string(),
+            message: full_message.lines().next().unwrap_or("").to_string(),
+            message_lines: full_message.lines().count(),
+            commit_url: commit.html_url,
+            author_name: author
+                .as_ref()
+                .and_then(|a| a.name.clone())
+                .unwrap_or_else(|| "Unknown".to_string()),
+            author_email: author.as_ref().and_then(|a| a.email.clone()),
+            author_login: commit.author.as_ref().map(|u| u.login.clone()),
+            author_url: commit.author.and_then(|u| u.html_url),
+            date: author.and_then(|a| a.date).unwrap_or_else(Utc::now),
+            hash: commit.sha,
+        }
+    }
+}
+
+impl From<GtPullRequest> for PullRequestInfo {
+    fn from(pr: GtPullRequest) -> Self {
+        Self {
+            number: pr.number,
+            repo_info: None,
+            title: pr.title,
+            body: pr.body.filter(|b| !b.is_empty()),
+            state: pr.state,
+            url: pr.html_url,
+            merged: pr.merged,
+            merge_commit_sha: pr.merge_commit_sha.filter(|_| pr.merged),
+            author: pr.user.as_ref().map(|u| u.login.clone()),
+            author_url: pr.user.and_then(|u| u.html_url),
+            created_at: pr.created_at,
+            labels: pr.labels.into_iter().map(|l| l.name).collect(),
+        }
+    }
+}
+
+impl From<GtIssue> for ExtendedIssueInfo {
+    fn from(issue: GtIssue) -> Self {
+        Self {
+            number: issue.number,
+            title: issue.title,
+            body: issue.body.filter(|b| !b.is_empty()),
+            state: if issue.state == "closed" {
+                IssueState::Closed
+            } else {
+                IssueState::Open
+            },
+            url: issue.html_url,
+            author: issue.user.as_ref().map(|u| u.login.clone()),
+            author_url: issue.user.and_then(|u| u.html_url),
+            closing_prs: Vec::new(),
+            created_at: issue.created_at,
+            timeline_may_be_incomplete: false,
+        }
+    }
+}
+
+impl From<GtRelease> for ReleaseInfo {
+    fn from(release: GtRelease) -> Self {
+        Self {
+            tag_name: release.tag_name,
+            name: release.name.filter(|n| !n.is_empty()),
+            body: release.body,
+            url: release.html_url,
+            published_at: release.published_at,
+            created_at: release.created_at,
+            prerelease: release.prerelease,
+        }
+    }
+}
+
+// ============================================
+// Client
+// ============================================
+
+/// Gitea/Forgejo API client for a single instance.
+pub struct GiteaClient {
+    /// Talks to the API root, e.g. `https://codeberg.org/api/v1`
+    http: ForgeHttp,
+}
+
+impl GiteaClient {
+    /// Create a client for the instance at `base_url`.
+    ///
+    /// Authenticates with `GITEA_TOKEN` (or `FORGEJO_TOKEN`) when set; works
+    /// anonymously otherwise. Returns `None` if the HTTP client cannot be built.
+    #[must_use]
+    pub fn new(base_url: &str) -> Option<Self> {
+        let token = ["GITEA_TOKEN", "FORGEJO_TOKEN"]
+            .into_iter()
+            .filter_map(|var| env::var(var).ok())
+            .find(|t| !t.trim().is_empty());
+        Self::with_token(base_url, token)
+    }
+
+    /// Create a client for the instance at `base_url` with an explicit token.
+    #[must_use]
+    pub fn with_token(base_url: &str, token: Option<String>) -> Option<Self> {
+        let api_url = format!("{}/api/v1", base_url.trim_end_matches('/'));
+        Some(Self {
+            http: ForgeHttp::new(&api_url, token.map(ForgeAuth::Token))?,
+        })
+    }
+
+    /// Fetch a commit by SHA or ref name.
+    pub async fn fetch_commit(&self, repo_info: &GtRepoInfo, sha: &str) -> Option<CommitInfo> {
+        let commit: GtCommit = self
+            .http
+            .get_json(&repo_info.api_path(&format!("git/commits/{}", encode(sha))))
+            .await
+            .log_err(&format!(
+                "fetch_commit failed for {}/{} commit {sha}",
+                repo_info.owner(),
+                repo_info.repo()
+            ))?;
+
+        Some(commit.into())
+    }
+
+    /// Fetch a pull request by number.
+    pub async fn fetch_pull_request(
+        &self,
+        repo_info: &GtRepoInfo,
+        number: u64,
+    ) -> Option<PullRequestInfo> {
+        let pr: GtPullRequest = self
+            .http
+            .get_json(&repo_info.api_path(&format!("pulls/{number}")))
+            .await
+            .log_err(&format!(
+                "fetch_pull_request failed for {}/{} PR #{number}",
+                repo_info.owner(),
+                repo_info.repo()
+            ))?;
+
+        Some(pr.into())
+    }
+
+    /// Fetch the merged pull request that introduced a commit.
+    pub async fn fetch_pull_request_for_commit(
+        &self,
+        repo_info: &GtRepoInfo,
+        sha: &str,
+    ) -> Option<PullRequestInfo> {
+        let pr: GtPullRequest = self
+            .http
+            .get_json(&repo_info.api_path(&format!("commits/{}/pull", encode(sha))))
+            .await
+            .log_err(&format!(
+                "fetch_pull_request_for_commit failed for {}/{} commit {sha}",
+                repo_info.owner(),
+                repo_info.repo()
+            ))?;
+
+        Some(PullRequestInfo::from(pr)).filter(|pr| pr.merged)
+    }
+
+    /// Check whether an issue number belongs to a pull request.
+    ///
+    /// Gitea numbers issues and pull requests from the same sequence, like GitHub.
+    pub async fn is_pull_request(&self, repo_info: &GtRepoInfo, number: u64) -> Option<bool> {
+        let issue: GtIssue = self
+            .http
+            .get_json(&repo_info.api_path(&format!("issues/{number}")))
+            .await
+            .log_err(&format!(
+                "issue lookup failed for {}/{} #{number}",
+                repo_info.owner(),
+                repo_info.repo()
+            ))?;
+
+        Some(issue.pull_request.is_some())
+    }
+
+    /// Fetch an issue along with the merged pull requests that closed it.
+    ///
+    /// Returns `None` for pull requests, which share the issue endpoint.
+    pub async fn fetch_issue(
+        &self,
+        repo_info: &GtRepoInfo,
+        number: u64,
+    ) -> Option<ExtendedIssueInfo> {
+        let issue: GtIssue = self
+            .http
+            .get_json(&repo_info.api_path(&format!("issues/{number}")))
+            .await
+            .log_err(&format!(
+                "fetch_issue failed for {}/{} issue #{number}",
+                repo_info.owner(),
+                repo_info.repo()
+            ))?;
+        if issue.pull_request.is_some() {
+            return None;
+        }
+
+        let mut issue_info = ExtendedIssueInfo::from(issue);
+
+        // Only closed issues can have closing PRs
+        if matches!(issue_info.state, IssueState::Closed) {
+            match self
+                .http
+                .get_json::<Vec<GtTimelineEvent>>(
+                    &repo_info.api_path(&format!("issues/{number}/timeline")),
+                )
+                .await
+            {
+                Ok(events) => {
+                    issue_info.closing_prs = self.closing_prs(repo_info, events).await;
+                }
+                Err(e) => {
+                    log::debug!(
+                        "timeline lookup failed for {}/{} #{number}: {e:?}",
+                        repo_info.owner(),
+                        repo_info.repo()
+                    );
+                    issue_info.timeline_may_be_incomplete = true;
+                }
+            }
+        }
+
+        Some(issue_info)
+    }
+
+    /// Merged pull requests referencing an issue, from its timeline.
+    async fn closing_prs(
+        &self,
+        repo_info: &GtRepoInfo,
+        events: Vec<GtTimelineEvent>,
+    ) -> Vec<PullRequestInfo> {
+        let mut numbers: Vec<u64> = events
+            .into_iter()
+            .filter(|event| event.kind == "pull_ref")
+            .filter_map(|event| event.ref_issue)
+            .filter(|issue| issue.pull_request.is_some())
+            .map(|issue| issue.number)
+            .collect();
+        numbers.dedup();
+
+        let mut prs = Vec::new();
+        for number in numbers {
+            if let Some(pr) = self.fetch_pull_request(repo_info, number).await
+                && pr.merged
+            {
+                prs.push(pr);
+            }
+        }
+        prs
+    }
+
+    /// Fetch a release by its tag name.
+    pub async fn fetch_release_by_tag(
+        &self,
+        repo_info: &GtRepoInfo,
+        tag_name: &str,
+    ) -> Option<ReleaseInfo> {
+        let release: GtRelease = self
+            .http
+            .get_json(&repo_info.api_path(&format!("releases/tags/{}", encode(tag_name))))
+            .await
+            .log_err(&format!(
+                "fetch_release_by_tag failed for {}/{} tag {tag_name}",
+                repo_info.owner(),
+                repo_info.repo()
+            ))?;
+
+        Some(release.into())
+    }
+
+    /// Fetch published releases created at or after `since_date`, oldest first.
+    pub async fn fetch_releases_since(
+        &self,
+        repo_info: &GtRepoInfo,
+        since_date: DateTime<Utc>,
+    ) -> Vec<ReleaseInfo> {
+        let mut releases = Vec::new();
+
+        'pages: for page_num in 1u32.. {
+            let Ok(page) = self
+                .http
+                .get_json::<Vec<GtRelease>>(&repo_info.api_path(&format!(
+                    "releases?limit={RELEASES_PER_PAGE}&page={page_num}"
+                )))
+                .await
+            else {
+                break;
+            };
+            let is_last_page = page.len() < RELEASES_PER_PAGE;
+
+            // Releases are listed newest first
+            for release in page {
+                if release.created_at.unwrap_or_default() < since_date {
+                    break 'pages;
+                }
+                if !release.draft {
+                    releases.push(ReleaseInfo::from(release));
+                }
+            }
+
+            if is_last_page {
+                break;
+            }
+        }
+
+        releases.reverse();
+        releases
+    }
+
+    /// Check if a tag or branch contains a specific commit using the compare API.
+    pub async fn ref_contains_commit(
+        &self,
+        repo_info: &GtRepoInfo,
+        git_ref: &str,
+        commit: &str,
+    ) -> bool {
+        // Commits reachable from `commit` but not from `git_ref` - none means it's contained
+        self.http
+            .get_json::<GtCompare>(&repo_info.api_path(&format!(
+                "compare/{}...{}",
+                encode(git_ref),
+                encode(commit)
+            )))
+            .await
+            .is_ok_and(|compare| compare.total_commits == 0)
+    }
+
+    /// Fetch a tag, enriched with release metadata when the tag has a release.
+    pub async fn fetch_tag(&self, repo_info: &GtRepoInfo, tag_name: &str) -> Option<TagInfo> {
+        let tag: GtTag = self
+            .http
+            .get_json(&repo_info.api_path(&format!("tags/{}", encode(tag_name))))
+            .await
+            .log_err(&format!(
+                "fetch_tag failed for {}/{} tag {tag_name}",
+                repo_info.owner(),
+                repo_info.repo()
+            ))?;
+
+        let release = self.fetch_release_by_tag(repo_info, &tag.name).await;
+        Some(Self::tag_info(repo_info, tag, release))
+    }
+
+    /// Fetch raw file content from the default branch.
+    pub async fn fetch_file_content(&self, repo_info: &GtRepoInfo, path: &str) -> Option<String> {
+        let path: Vec<String> = path.split('/').map(encode).collect();
+        self.http
+            .get_text(&repo_info.api_path(&format!("raw/{}", path.join("/"))))
+            .await
+            .ok()
+    }
+
+    fn tag_info(repo_info: &GtRepoInfo, tag: GtTag, release: Option<ReleaseInfo>) -> TagInfo {
+        let release_url = release.as_ref().map(|r| r.url.clone());
+        let tag_url = Some(
+            release_url
+                .clone()
+                .unwrap_or_else(|| Self::tag_url(repo_info, &tag.name)),
+        );
+
+        TagInfo {
+            semver_info: parse_semver(&tag.name),
+            name: tag.name,
+            commit_hash: tag.commit.sha,
+            created_at: tag
+                .commit
+                .created
+                .or_else(|| release.as_ref().and_then(|r| r.created_at))
+                .unwrap_or_else(Utc::now),
+            is_release: release.is_some(),
+            release_name: release.as_ref().and_then(|r| r.name.clone()),
+            release_url,
+            published_at: release.and_then(|r| r.published_at),
+            tag_url,
+        }
+    }
+
+    // ============================================
+    // URL builders
+    // ============================================
+
+    /// Build a commit URL. Uses URL encoding to prevent injection.
+    #[must_use]
+    pub fn commit_url(repo_info: &GtRepoInfo, hash: &str) -> String {
+        format!("{}/commit/{}", repo_info.web_url(), encode(hash))
+    }
+
+    /// Build a tag URL pointing to the source view (for plain git tags).
+    #[must_use]
+    pub fn tag_url(repo_info: &GtRepoInfo, tag: &str) -> String {
+        format!("{}/src/tag/{}", repo_info.web_url(), encode(tag))
+    }
+
+    /// Build a release URL (for tags with releases).
+    #[must_use]
+    pub fn release_tag_url(repo_info: &GtRepoInfo, tag: &str) -> String {
+        format!("{}/releases/tag/{}", repo_info.web_url(), encode(tag))
+    }
+
+    /// Build a profile URL from a Gitea noreply email address (`username@noreply.<host>`).
+    #[must_use]
+    pub fn author_url_from_email(repo_info: &GtRepoInfo, email: &str) -> Option<String> {
+        let (username, domain) = email.split_once('@')?;
+        if !domain.starts_with("noreply.") || username.is_empty() {
+            return None;
+        }
+        Some(format!("{}/{}", repo_info.base_url(), encode(username)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "https://codeberg.org/forgejo/forgejo.git",
+        "https://codeberg.org",
+        "forgejo",
+        "forgejo"
+    )]
+    #[case(
+        "git@codeberg.org:owner/repo.git",
+        "https://codeberg.org",
+        "owner",
+        "repo"
+    )]
+    #[case(
+        "ssh://git@git.example.com:2222/team/app.git",
+        "https://git.example.com",
+        "team",
+        "app"
+    )]
+    #[case(
+        "http://localhost:3000/team/app",
+        "http://localhost:3000",
+        "team",
+        "app"
+    )]
+    fn parses_remote_urls(
+        #[case] url: &str,
+        #[case] base_url: &str,
+        #[case] owner: &str,
+        #[case] repo: &str,
+    ) {
+        let info = GtRepoInfo::from_remote_url(url).expect("should parse");
+        assert_eq!(info.base_url(), base_url);
+        assert_eq!(info.owner(), owner);
+        assert_eq!(info.repo(), repo);
+    }
+
+    #[rstest]
+    #[case("https://codeberg.org/just-an-owner")]
+    #[case("https://codeberg.org/owner/repo/extra")]
+    #[case("git@codeberg.org:")]
+    #[case("not a url")]
+    fn rejects_non_repo_urls(#[case] url: &str) {
+        assert!(GtRepoInfo::from_remote_url(url).is_none());
+    }
+
+    #[test]
+    fn builds_urls() {
+        let info = GtRepoInfo::new("https://codeberg.org/", "owner", "repo");
+        assert_eq!(
+            GiteaClient::commit_url(&info, "abc123"),
+            "https://codeberg.org/owner/repo/commit/abc123"
+        );
+        assert_eq!(
+            GiteaClient::tag_url(&info, "v1"),
+            "https://codeberg.org/owner/repo/src/tag/v1"
+        );
+        assert_eq!(
+            GiteaClient::release_tag_url(&info, "v1"),
+            "https://codeberg.org/owner/repo/releases/tag/v1"
+        );
+    }
+
+    #[rstest]
+    #[case("jdoe@noreply.codeberg.org", Some("https://codeberg.org/jdoe"))]
+    #[case("jdoe@example.com", None)]
+    fn author_url_from_noreply_email(#[case] email: &str, #[case] expected: Option<&str>) {
+        let info = GtRepoInfo::new("https://codeberg.org", "owner", "repo");
+        assert_eq!(
+            GiteaClient::author_url_from_email(&info, email).as_deref(),
+            expected
+        );
+    }
+}
@@ -1,19 +1,43 @@
-use std::{env, fs, future::Future, pin::Pin, sync::LazyLock, sync::OnceLock, time::Duration};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    env, fs,
+    future::Future,
+    ops::ControlFlow,
+    pin::Pin,
+    sync::{
+        Arc, LazyLock, Mutex, OnceLock,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
 
 use chrono::{DateTime, Utc};
+use http::{HeaderValue, Uri, header::USER_AGENT};
 use octocrab::{
-    Octocrab, OctocrabBuilder, Result as OctoResult,
+    AuthState, Octocrab, OctocrabBuilder, Result as OctoResult,
     models::{
-        Event as TimelineEventType, commits::GithubCommitStatus, repos::RepoCommit,
+        Event as TimelineEventType,
+        commits::GithubCommitStatus,
+        issues::Issue,
+        pulls::PullRequest,
+        repos::{Release, RepoCommit},
         timelines::TimelineEvent,
     },
+    service::middleware::{
+        auth_header::AuthHeaderLayer, base_uri::BaseUriLayer, extra_headers::ExtraHeadersLayer,
+    },
 };
-use serde::Deserialize;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
+use crate::api_cache::{ApiCache, CacheSettings, CachedResponse};
 use crate::error::{LogError, WtgError, WtgResult};
 use crate::git::{CommitInfo, TagInfo, parse_semver};
 use crate::notice::{Notice, NoticeCallback};
 use crate::parse_input::parse_github_repo_url;
+use crate::recording::{Recording, RecordingService};
+use crate::remote::ForgeHosts;
 
 impl From<RepoCommit> for CommitInfo {
     fn from(commit: RepoCommit) -> Self {
@@ -63,28 +87,111 @@ impl From<RepoCommit> for CommitInfo {
 const CONNECT_TIMEOUT_SECS: u64 = 5;
 const READ_TIMEOUT_SECS: u64 = 30;
 const REQUEST_TIMEOUT_SECS: u64 = 5;
-
-#[derive(Debug, Deserialize)]
-struct GhConfig {
-    #[serde(rename = "github.com")]
-    github_com: GhHostConfig,
-}
+/// Max page size allowed by the GitHub API.
+const RELEASES_PER_PAGE: u8 = 100;
+const COMPARE_PER_PAGE: u8 = 100;
+const BRANCHES_PER_PAGE: u8 = 100;
+/// Characters left as-is in API routes built by hand (refs may contain `/`).
+const ROUTE_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'/');
+
+/// Host of the public GitHub instance.
+pub const GITHUB_COM: &str = "github.com";
+/// REST API of github.com (octocrab's default).
+const GITHUB_API_URL: &str = "https://api.github.com";
+const GITHUB_UPLOADS_URL: &str = "https://uploads.github.com";
+
+/// Environment variable with a comma-separated list of GitHub Enterprise Server hosts.
+pub const GITHUB_HOSTS_ENV: &str = "WTG_GITHUB_HOSTS";
+
+/// Per-host entries of gh CLI's `hosts.yml`, keyed by host name.
+type GhHostsConfig = HashMap<String, GhHostConfig>;
 
 #[derive(Debug, Deserialize)]
 struct GhHostConfig {
     oauth_token: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+/// GitHub Enterprise Server hosts from `WTG_GITHUB_HOSTS` and every
+/// non-github.com host gh CLI is logged into.
+pub(crate) fn enterprise_hosts_from_env() -> Vec<String> {
+    let from_env = env::var(GITHUB_HOSTS_ENV).unwrap_or_default();
+    let from_gh: Vec<String> = read_gh_hosts_configs()
+        .into_iter()
+        .flat_map(HashMap::into_keys)
+        .collect();
+
+    let mut hosts: Vec<String> = from_env
+        .split(',')
+        .chain(from_gh.iter().map(String::as_str))
+        .map(normalize_host)
+        .filter(|host| !host.is_empty() && host != GITHUB_COM)
+        .collect();
+    hosts.sort();
+    hosts.dedup();
+    hosts
+}
+
+/// Normalize a host name: lowercase, without scheme, `www.` prefix or trailing slash.
+pub(crate) fn normalize_host(host: &str) -> String {
+    let host = host.trim().to_ascii_lowercase();
+    let host = host
+        .strip_prefix("https://")
+        .or_else(|| host.strip_prefix("http://"))
+        .unwrap_or(&host);
+    host.trim_end_matches('/')
+        .trim_start_matches("www.")
+        .to_string()
+}
+
+/// Read all gh CLI `hosts.yml` files (cross-platform).
+fn read_gh_hosts_configs() -> Vec<GhHostsConfig> {
+    // gh CLI follows XDG conventions and stores config in:
+    // - Unix/macOS: ~/.config/gh/hosts.yml
+    // - Windows: %APPDATA%/gh/hosts.yml (but dirs crate handles this)
+    // XDG-style path goes first, then the platform-specific config dir
+    // (~/Library/Application Support/gh/hosts.yml on macOS)
+    let xdg_path = dirs::home_dir().map(|home| home.join(".config").join("gh").join("hosts.yml"));
+    let platform_path = dirs::config_dir().map(|dir| dir.join("gh").join("hosts.yml"));
+
+    [xdg_path, platform_path]
+        .into_iter()
+        .flatten()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .filter_map(|content| serde_yaml::from_str::<GhHostsConfig>(&content).ok())
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct GhRepoInfo {
     owner: String,
     repo: String,
+    /// Web host of the GitHub instance (`github.com` or a GitHub Enterprise Server host)
+    host: Cow<'static, str>,
 }
 
 impl GhRepoInfo {
+    /// Create repo info for a repository on github.com.
     #[must_use]
     pub const fn new(owner: String, repo: String) -> Self {
-        Self { owner, repo }
+        Self {
+            owner,
+            repo,
+            host: Cow::Borrowed(GITHUB_COM),
+        }
+    }
+
+    /// Create repo info for a repository on a specific GitHub host.
+    #[must_use]
+    pub fn with_host(host: &str, owner: String, repo: String) -> Self {
+        Self {
+            owner,
+            repo,
+            host: Cow::Owned(normalize_host(host)),
+        }
     }
 
     #[must_use]
@@ -96,6 +203,17 @@ impl GhRepoInfo {
     pub fn repo(&self) -> &str {
         &self.repo
     }
+
+    #[must_use]
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Whether the repository lives on github.com (as opposed to GitHub Enterprise Server).
+    #[must_use]
+    pub fn is_github_com(&self) -> bool {
+        self.host == GITHUB_COM
+    }
 }
 
 /// Describes which client served an API call and why.
@@ -124,10 +242,16 @@ struct ApiCallResult<'a, T> {
     selection: ClientSelection,
 }
 
+/// Lazily-built backup client; the initializer captures the API host.
+type BackupClient = LazyLock<Option<Octocrab>, Box<dyn FnOnce() -> Option<Octocrab> + Send>>;
+
 /// GitHub API client wrapper.
 ///
 /// - Provides a simplified interface for common GitHub operations used in wtg over direct octocrab usage.
-/// - Handles authentication via `GITHUB_TOKEN` env var or gh CLI config.
+/// - Talks to github.com or a GitHub Enterprise Server host (`https://<host>/api/v3`),
+///   or another API URL given to [`GitHubClient::with_api_url`].
+/// - Handles authentication via `GITHUB_TOKEN` (github.com), `GH_ENTERPRISE_TOKEN` /
+///   `GITHUB_ENTERPRISE_TOKEN` (Enterprise hosts) env vars or per-host gh CLI config.
 /// - Supports fallback to anonymous requests on SAML errors via backup client.
 /// - Converts known octocrab errors into `WtgError` variants.
 /// - Returns `None` from `new()` if no client can be created.
@@ -135,16 +259,24 @@ pub struct GitHubClient {
     main_client: Octocrab,
     /// Backup client for SAML fallback. Only populated when `main_client` is authenticated.
     /// When `main_client` is anonymous, there's no point in falling back to another anonymous client.
-    backup_client: LazyLock<Option<Octocrab>>,
+    backup_client: BackupClient,
     /// Whether `main_client` is authenticated (vs anonymous).
     is_authenticated: bool,
+    /// Web host this client talks to (e.g. `github.com`).
+    host: String,
+    /// Release lookups by `(owner, repo, tag)`, including misses, so tags shared
+    /// by several queries are only looked up once.
+    release_cache: Mutex<HashMap<(String, String, String), Option<ReleaseInfo>>>,
+    /// Persistent cache of API responses; `None` with `--no-cache`.
+    api_cache: Option<ApiCache>,
     /// Callback for emitting notices (e.g., rate limit hit).
     /// Uses `OnceLock` since callback is set at most once after construction.
     notice_callback: OnceLock<NoticeCallback>,
+    /// Set once a request hits the rate limit, so optional lookups can stop early.
+    rate_limited: AtomicBool,
 }
-
 /// Information about a Pull Request
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PullRequestInfo {
     pub number: u64,
     pub repo_info: Option<GhRepoInfo>,
@@ -157,6 +289,9 @@ pub struct PullRequestInfo {
     pub author: Option<String>,
     pub author_url: Option<String>,
     pub created_at: Option<DateTime<Utc>>, // When the PR was created
+    /// Label names, used to group release notes
+    #[serde(default)]
+    pub labels: Vec<String>,
 }
 
 impl From<octocrab::models::pulls::PullRequest> for PullRequestInfo {
@@ -167,16 +302,26 @@ impl From<octocrab::models::pulls::PullRequest> for PullRequestInfo {
 
         Self {
             number: pr.number,
-            repo_info: parse_github_repo_url(pr.url.as_str()),
+            repo_info: parse_github_repo_url(pr.url.as_str(), &ForgeHosts::new()),
             title: pr.title.unwrap_or_default(),
             body: pr.body,
-            state: format!("{:?}", pr.state),
+            state: pr
+                .state
+                .map(|state| format!("{state:?}").to_lowercase())
+                .unwrap_or_default(),
             url: pr.html_url.map(|u| u.to_string()).unwrap_or_default(),
-            merged: pr.merged.unwrap_or(false),
+            // PR lists omit `merged`, but still carry the merge timestamp
+            merged: pr.merged.unwrap_or_else(|| pr.merged_at.is_some()),
             merge_commit_sha: pr.merge_commit_sha,
             author,
             author_url,
             created_at,
+            labels: pr
+                .labels
+                .unwrap_or_default()
+                .into_iter()
+                .map(|label| label.name)
+                .collect(),
         }
     }
 }
@@ -237,63 +382,179 @@ pub struct ReleaseInfo {
     pub prerelease: bool,
 }
 
+impl From<Release> for ReleaseInfo {
+    fn from(release: Release) -> Self {
+        Self {
+            tag_name: release.tag_name,
+            name: release.name,
+            body: release.body,
+            url: release.html_url.to_string(),
+            published_at: release.published_at,
+            created_at: release.created_at,
+            prerelease: release.prerelease,
+        }
+    }
+}
+
 impl GitHubClient {
-    /// Create a new GitHub client.
+    /// Create a new GitHub client for github.com.
     ///
     /// Returns `None` if no client (neither authenticated nor anonymous) can be created.
     /// If authentication succeeds, an anonymous backup client is created for SAML fallback.
     /// If authentication fails, the anonymous client becomes the main client with no backup.
     #[must_use]
     pub fn new() -> Option<Self> {
+        Self::for_host(GITHUB_COM)
+    }
+
+    /// Create a new GitHub client for a specific host (github.com or GitHub Enterprise Server).
+    ///
+    /// Same authentication and fallback behavior as [`GitHubClient::new`].
+    #[must_use]
+    pub fn for_host(host: &str) -> Option<Self> {
+        let host = normalize_host(host);
+        let api_url = Self::default_api_url(&host);
+
         // Try authenticated client first
-        if let Some(auth) = Self::build_auth_client() {
+        if let Some(auth) = Self::build_auth_client(&host, api_url.as_deref()) {
             // Auth succeeded - create anonymous as lazy backup for SAML fallback
-            return Some(Self {
-                main_client: auth,
-                backup_client: LazyLock::new(Self::build_anonymous_client),
-                is_authenticated: true,
-                notice_callback: OnceLock::new(),
-            });
+            let backup = Self::lazy_anonymous_client(api_url);
+            return Some(Self::from_clients(host, auth, backup, true));
         }
 
         // Auth failed - try anonymous as main
         // No backup needed: falling back to anonymous when already anonymous is pointless
-        let anonymous = Self::build_anonymous_client()?;
-        Some(Self {
-            main_client: anonymous,
-            backup_client: LazyLock::new(|| None),
-            is_authenticated: false,
-            notice_callback: OnceLock::new(),
-        })
+        let anonymous = Self::build_client(api_url.as_deref(), None)?;
+        Some(Self::from_clients(
+            host,
+            anonymous,
+            Self::no_backup_client(),
+            false,
+        ))
     }
 
-    /// Create a GitHub client with a specific token.
+    /// Create a github.com client with a specific token.
     ///
     /// Builds an authenticated client using the given token, with an anonymous
     /// backup for fallback on SAML or bad credentials errors.
     /// Returns `None` if the client cannot be built.
     #[must_use]
     pub fn new_with_token(token: String) -> Option<Self> {
+        Self::for_host_with_token(GITHUB_COM, token)
+    }
+
+    /// Create a client for a specific host with a specific token.
+    ///
+    /// See [`GitHubClient::new_with_token`].
+    #[must_use]
+    pub fn for_host_with_token(host: &str, token: String) -> Option<Self> {
         if token.trim().is_empty() {
             return None;
         }
 
-        let connect_timeout = Some(Self::connect_timeout());
-        let read_timeout = Some(Self::read_timeout());
+        let host = normalize_host(host);
+        let api_url = Self::default_api_url(&host);
+        let auth = Self::build_client(api_url.as_deref(), Some(token))?;
+        let backup = Self::lazy_anonymous_client(api_url);
+        Some(Self::from_clients(host, auth, backup, true))
+    }
 
-        let auth = OctocrabBuilder::new()
-            .personal_token(token)
-            .set_connect_timeout(connect_timeout)
-            .set_read_timeout(read_timeout)
-            .build()
-            .ok()?;
+    /// Create a client for repositories on `host` that talks to the REST API at
+    /// `api_url` instead (e.g. a proxy, or a fake server in tests).
+    ///
+    /// Only `token` is used for authentication: the environment and gh CLI config
+    /// aren't consulted. With a token, failed requests fall back to anonymous ones
+    /// against the same URL, like [`GitHubClient::for_host_with_token`].
+    #[must_use]
+    pub fn with_api_url(host: &str, api_url: &str, token: Option<String>) -> Option<Self> {
+        let host = normalize_host(host);
+        let api_url = Some(api_url.trim_end_matches('/').to_string());
+        let token = token.filter(|token| !token.trim().is_empty());
+        let is_authenticated = token.is_some();
+
+        let main = Self::build_client(api_url.as_deref(), token)?;
+        let backup = if is_authenticated {
+            Self::lazy_anonymous_client(api_url)
+        } else {
+            Self::no_backup_client()
+        };
+        Some(Self::from_clients(host, main, backup, is_authenticated))
+    }
+
+    /// Create a client whose API traffic goes through `recording`: recorded
+    /// while it's sent, or answered from the recording without the network.
+    ///
+    /// `token` is used instead of the environment and gh CLI config, which
+    /// aren't consulted at all with an explicit `api_url` (see
+    /// [`GitHubClient::with_api_url`]). A replay is authenticated if the recorded
+    /// run was. Responses aren't cached on disk, so recordings capture every request.
+    #[must_use]
+    pub fn with_recording(
+        host: &str,
+        api_url: Option<&str>,
+        token: Option<String>,
+        recording: Arc<Recording>,
+    ) -> Option<Self> {
+        let host = normalize_host(host);
+        let token = if recording.is_replay() {
+            // Only whether a token was sent is recorded, not the token
+            recording
+                .was_authenticated()
+                .then(|| "replayed-token".to_string())
+        } else {
+            token
+                .filter(|token| !token.trim().is_empty())
+                .or_else(|| api_url.is_none().then(|| Self::find_token(&host)).flatten())
+        };
+        let api_url = api_url.map_or_else(
+            || Self::default_api_url(&host),
+            |url| Some(url.trim_end_matches('/').to_string()),
+        );
+        let is_authenticated = token.is_some();
+
+        let main = Self::build_recorded_client(api_url.as_deref(), token, &recording)?;
+        let backup: BackupClient = if is_authenticated {
+            LazyLock::new(Box::new(move || {
+                Self::build_recorded_client(api_url.as_deref(), None, &recording)
+            }))
+        } else {
+            Self::no_backup_client()
+        };
+        let mut client = Self::from_clients(host, main, backup, is_authenticated);
+        client.api_cache = None;
+        Some(client)
+    }
 
-        Some(Self {
-            main_client: auth,
-            backup_client: LazyLock::new(Self::build_anonymous_client),
-            is_authenticated: true,
+    /// Wrap already-built octocrab clients.
+    fn from_clients(
+        host: String,
+        main_client: Octocrab,
+        backup_client: BackupClient,
+        is_authenticated: bool,
+    ) -> Self {
+        Self {
+            main_client,
+            backup_client,
+            is_authenticated,
+            api_cache: ApiCache::for_host(&host),
+            host,
+            release_cache: Mutex::default(),
             notice_callback: OnceLock::new(),
-        })
+            rate_limited: AtomicBool::new(false),
+        }
+    }
+
+    /// Use the API cache with `settings` instead of the defaults.
+    #[must_use]
+    pub fn with_cache_settings(mut self, settings: CacheSettings) -> Self {
+        self.api_cache = ApiCache::for_host_with(&self.host, settings);
+        self
+    }
+
+    /// Web host this client talks to (e.g. `github.com`).
+    #[must_use]
+    pub fn host(&self) -> &str {
+        &self.host
     }
 
     /// Set the notice callback for this client.
@@ -311,91 +572,117 @@ impl GitHubClient {
         }
     }
 
-    /// Build an authenticated octocrab client.
+    /// Whether a request has hit the rate limit, so further ones will likely fail too.
+    pub(crate) fn is_rate_limited(&self) -> bool {
+        self.rate_limited.load(Ordering::Relaxed)
+    }
+
+    /// Build an authenticated octocrab client for `host`.
     /// Returns `None` if `WTG_GH_NO_AUTH` is set or no valid token is found.
-    fn build_auth_client() -> Option<Octocrab> {
+    fn build_auth_client(host: &str, api_url: Option<&str>) -> Option<Octocrab> {
+        Self::build_client(api_url, Some(Self::find_token(host)?))
+    }
+
+    /// Token for `host` from the environment or gh CLI config.
+    /// Returns `None` if `WTG_GH_NO_AUTH` is set or no valid token is found.
+    fn find_token(host: &str) -> Option<String> {
         // Undocumented: skip all authentication when WTG_GH_NO_AUTH is set
         if env::var("WTG_GH_NO_AUTH").is_ok() {
             log::debug!("WTG_GH_NO_AUTH set, skipping GitHub authentication");
             return None;
         }
 
+        // Env vars first (skip empty/whitespace-only values), mirroring gh CLI:
+        // GITHUB_TOKEN for github.com, GH_ENTERPRISE_TOKEN/GITHUB_ENTERPRISE_TOKEN otherwise
+        let env_vars: &[&str] = if host == GITHUB_COM {
+            &["GITHUB_TOKEN"]
+        } else {
+            &["GH_ENTERPRISE_TOKEN", "GITHUB_ENTERPRISE_TOKEN"]
+        };
+        env_vars
+            .iter()
+            .filter_map(|var| env::var(var).ok())
+            .find(|token| !token.trim().is_empty())
+            // Then the host's entry in gh CLI config
+            .or_else(|| Self::read_gh_config(host))
+    }
+
+    /// Anonymous client built on first use, for fallback from an authenticated one.
+    fn lazy_anonymous_client(api_url: Option<String>) -> BackupClient {
+        LazyLock::new(Box::new(move || {
+            Self::build_client(api_url.as_deref(), None)
+        }))
+    }
+
+    /// Backup for an anonymous client: falling back to another anonymous client is pointless.
+    fn no_backup_client() -> BackupClient {
+        LazyLock::new(Box::new(|| None))
+    }
+
+    /// REST API root for `host`; `None` for github.com, octocrab's default.
+    fn default_api_url(host: &str) -> Option<String> {
+        // GitHub Enterprise Server serves its REST API under /api/v3
+        (host != GITHUB_COM).then(|| format!("https://{host}/api/v3"))
+    }
+
+    /// Build an octocrab client for the API at `api_url` (github.com if `None`),
+    /// authenticated if a token is given.
+    fn build_client(api_url: Option<&str>, token: Option<String>) -> Option<Octocrab> {
         // Set reasonable timeouts: 5s connect, 30s read/write
-        let connect_timeout = Some(Self::connect_timeout());
-        let read_timeout = Some(Self::read_timeout());
+        let mut builder = OctocrabBuilder::new()
+            .set_connect_timeout(Some(Self::connect_timeout()))
+            .set_read_timeout(Some(Self::read_timeout()));
 
-        // Try GITHUB_TOKEN env var first (skip empty/whitespace-only values)
-        if let Ok(token) = env::var("GITHUB_TOKEN")
-            && !token.trim().is_empty()
-        {
-            return OctocrabBuilder::new()
-                .personal_token(token)
-                .set_connect_timeout(connect_timeout)
-                .set_read_timeout(read_timeout)
-                .build()
-                .ok();
+        if let Some(token) = token {
+            builder = builder.personal_token(token);
         }
 
-        // Try reading from gh CLI config
-        if let Some(token) = Self::read_gh_config() {
-            return OctocrabBuilder::new()
-                .personal_token(token)
-                .set_connect_timeout(connect_timeout)
-                .set_read_timeout(read_timeout)
-                .build()
-                .ok();
+        if let Some(api_url) = api_url {
+            builder = builder.base_uri(api_url).ok()?;
         }
 
-        None
+        builder.build().ok()
     }
 
-    /// Build an anonymous octocrab client (no authentication)
-    fn build_anonymous_client() -> Option<Octocrab> {
-        let connect_timeout = Some(Self::connect_timeout());
-        let read_timeout = Some(Self::read_timeout());
-
-        OctocrabBuilder::new()
-            .set_connect_timeout(connect_timeout)
-            .set_read_timeout(read_timeout)
+    /// Build an octocrab client like [`GitHubClient::build_client`] whose
+    /// requests are recorded to or replayed from `recording`.
+    fn build_recorded_client(
+        api_url: Option<&str>,
+        token: Option<String>,
+        recording: &Arc<Recording>,
+    ) -> Option<Octocrab> {
+        let http = reqwest::Client::builder()
+            .connect_timeout(Self::connect_timeout())
+            .read_timeout(Self::read_timeout())
+            .build()
+            .ok()?;
+        let base_uri: Uri = api_url.unwrap_or(GITHUB_API_URL).parse().ok()?;
+        let upload_uri: Uri = GITHUB_UPLOADS_URL.parse().ok()?;
+        let auth_header = token
+            .map(|token| HeaderValue::from_str(&format!("Bearer {token}")))
+            .transpose()
+            .ok()?;
+        // Same stack as octocrab's default client, with the recording instead of hyper
+        let user_agent = vec![(USER_AGENT, HeaderValue::from_static("octocrab"))];
+
+        OctocrabBuilder::new_empty()
+            .with_service(RecordingService::new(Arc::clone(recording), http))
+            .with_layer(&ExtraHeadersLayer::new(Arc::new(user_agent)))
+            .with_layer(&BaseUriLayer::new(base_uri.clone()))
+            .with_layer(&AuthHeaderLayer::new(auth_header, base_uri, upload_uri))
+            .with_auth(AuthState::None)
             .build()
             .ok()
     }
 
-    /// Read GitHub token from gh CLI config (cross-platform)
-    fn read_gh_config() -> Option<String> {
-        // gh CLI follows XDG conventions and stores config in:
-        // - Unix/macOS: ~/.config/gh/hosts.yml
-        // - Windows: %APPDATA%/gh/hosts.yml (but dirs crate handles this)
-
-        // Try XDG-style path first (~/.config/gh/hosts.yml)
-        if let Some(home) = dirs::home_dir() {
-            let xdg_path = home.join(".config").join("gh").join("hosts.yml");
-            if let Ok(content) = fs::read_to_string(&xdg_path)
-                && let Ok(config) = serde_yaml::from_str::<GhConfig>(&content)
-                && let Some(token) = config.github_com.oauth_token
-                && !token.trim().is_empty()
-            {
-                return Some(token);
-            }
-        }
-
-        // Fall back to platform-specific config dir
-        // (~/Library/Application Support/gh/hosts.yml on macOS)
-        if let Some(mut config_path) = dirs::config_dir() {
-            config_path.push("gh");
-            config_path.push("hosts.yml");
-
-            if let Ok(content) = fs::read_to_string(&config_path)
-                && let Ok(config) = serde_yaml::from_str::<GhConfig>(&content)
-            {
-                return config
-                    .github_com
-                    .oauth_token
-                    .filter(|t| !t.trim().is_empty());
-            }
-        }
-
-        None
+    /// Read the token for `host` from gh CLI config (cross-platform)
+    fn read_gh_config(host: &str) -> Option<String> {
+        read_gh_hosts_configs().into_iter().find_map(|mut config| {
+            config
+                .remove(host)
+                .and_then(|entry| entry.oauth_token)
+                .filter(|token| !token.trim().is_empty())
+        })
     }
 
     /// Fetch full commit information from a specific repository
@@ -405,17 +692,16 @@ impl GitHubClient {
         repo_info: &GhRepoInfo,
         commit_hash: &str,
     ) -> Option<CommitInfo> {
-        let commit = self
-            .call_client_api_with_fallback(move |client| {
-                let hash = commit_hash.to_string();
-                let repo_info = repo_info.clone();
-                Box::pin(async move {
-                    client
-                        .commits(repo_info.owner(), repo_info.repo())
-                        .get(&hash)
-                        .await
-                })
-            })
+        // Commits looked up by full SHA never change; refs (e.g. tags) may move
+        let is_full_sha =
+            commit_hash.len() == 40 && commit_hash.chars().all(|c| c.is_ascii_hexdigit());
+        let commit: RepoCommit = self
+            .get_cached(
+                repo_info,
+                &format!("commits/{commit_hash}"),
+                &Self::repo_route(repo_info, &format!("commits/{}", encode_route(commit_hash))),
+                |_| is_full_sha,
+            )
             .await
             .log_err(&format!(
                 "fetch_commit_full_info failed for {}/{} commit {}",
@@ -429,16 +715,14 @@ impl GitHubClient {
 
     /// Try to fetch a PR
     pub async fn fetch_pr(&self, repo_info: &GhRepoInfo, number: u64) -> Option<PullRequestInfo> {
-        let pr = self
-            .call_client_api_with_fallback(move |client| {
-                let repo_info = repo_info.clone();
-                Box::pin(async move {
-                    client
-                        .pulls(repo_info.owner(), repo_info.repo())
-                        .get(number)
-                        .await
-                })
-            })
+        // Merged PRs are done changing; open or closed ones are revalidated
+        let pr: PullRequest = self
+            .get_cached(
+                repo_info,
+                &format!("pulls/{number}"),
+                &Self::repo_route(repo_info, &format!("pulls/{number}")),
+                |pr: &PullRequest| pr.merged_at.is_some(),
+            )
             .await
             .log_err(&format!(
                 "fetch_pr failed for {}/{} PR #{}",
@@ -447,7 +731,53 @@ impl GitHubClient {
                 number
             ))?;
 
-        Some(pr.into())
+        Some(self.pr_info(pr))
+    }
+
+    /// Convert a PR from this client's API, reading its repository off the API
+    /// URL (which is on this client's host, Enterprise or not).
+    fn pr_info(&self, pr: PullRequest) -> PullRequestInfo {
+        PullRequestInfo {
+            repo_info: self.repo_of_api_url(pr.url.as_str()),
+            ..pr.into()
+        }
+    }
+
+    /// Repository an API URL of this client's host points into.
+    fn repo_of_api_url(&self, url: &str) -> Option<GhRepoInfo> {
+        let mut hosts = ForgeHosts::new();
+        hosts.add_github_enterprise_host(&self.host);
+        parse_github_repo_url(url, &hosts)
+    }
+
+    /// Find the merged PR that introduced a commit.
+    pub async fn fetch_pr_for_commit(
+        &self,
+        repo_info: &GhRepoInfo,
+        commit_hash: &str,
+    ) -> Option<PullRequestInfo> {
+        // New PRs may pick up the commit later, so the list is revalidated
+        let prs: Vec<PullRequest> = self
+            .get_cached(
+                repo_info,
+                &format!("commits/{commit_hash}/pulls"),
+                &Self::repo_route(
+                    repo_info,
+                    &format!("commits/{}/pulls", encode_route(commit_hash)),
+                ),
+                |_| false,
+            )
+            .await
+            .log_err(&format!(
+                "fetch_pr_for_commit failed for {}/{} commit {}",
+                repo_info.owner(),
+                repo_info.repo(),
+                commit_hash
+            ))?;
+
+        prs.into_iter()
+            .map(|pr| self.pr_info(pr))
+            .find(|pr| pr.merged)
     }
 
     /// Try to fetch an issue
@@ -456,16 +786,13 @@ impl GitHubClient {
         repo_info: &GhRepoInfo,
         number: u64,
     ) -> Option<ExtendedIssueInfo> {
-        let issue = self
-            .call_client_api_with_fallback(move |client| {
-                let repo_info = repo_info.clone();
-                Box::pin(async move {
-                    client
-                        .issues(repo_info.owner(), repo_info.repo())
-                        .get(number)
-                        .await
-                })
-            })
+        let issue: Issue = self
+            .get_cached(
+                repo_info,
+                &format!("issues/{number}"),
+                &Self::repo_route(repo_info, &format!("issues/{number}")),
+                |_| false,
+            )
             .await
             .log_err(&format!(
                 "fetch_issue failed for {}/{} issue #{}",
@@ -478,10 +805,19 @@ impl GitHubClient {
 
         // Only fetch timeline for closed issues (open issues can't have closing PRs)
         if matches!(issue_info.state, octocrab::models::IssueState::Closed) {
-            let (closing_prs, timeline_may_be_incomplete) =
-                self.find_closing_prs(repo_info, issue_info.number).await;
-            issue_info.closing_prs = closing_prs;
-            issue_info.timeline_may_be_incomplete = timeline_may_be_incomplete;
+            // The timeline of a closed issue is settled, so a complete answer is kept forever
+            let key = format!("issues/{number}/closing_prs");
+            if let Some(closing_prs) = self.cached_immutable(repo_info, &key) {
+                issue_info.closing_prs = closing_prs;
+            } else {
+                let (closing_prs, timeline_may_be_incomplete) =
+                    self.find_closing_prs(repo_info, issue_info.number).await;
+                if !timeline_may_be_incomplete {
+                    self.cache_immutable(repo_info, &key, &closing_prs);
+                }
+                issue_info.closing_prs = closing_prs;
+                issue_info.timeline_may_be_incomplete = timeline_may_be_incomplete;
+            }
         }
 
         Some(issue_info)
@@ -533,8 +869,7 @@ impl GitHubClient {
                     let issue = &source.issue;
                     if issue.pull_request.is_some() {
                         // Extract repository info from repository_url using existing parser
-                        if let Some(repo_info) =
-                            parse_github_repo_url(issue.repository_url.as_str())
+                        if let Some(repo_info) = self.repo_of_api_url(issue.repository_url.as_str())
                         {
                             let Some(pr_info) =
                                 Box::pin(self.fetch_pr(&repo_info, issue.number)).await
@@ -596,92 +931,146 @@ impl GitHubClient {
     /// Fetch releases from GitHub, optionally filtered by date
     /// If `since_date` is provided, stop fetching releases older than this date
     /// This significantly speeds up lookups for recent PRs/issues
-    #[allow(clippy::too_many_lines)]
     pub async fn fetch_releases_since(
         &self,
         repo_info: &GhRepoInfo,
         since_date: DateTime<Utc>,
     ) -> Vec<ReleaseInfo> {
         let mut releases = Vec::new();
-        let mut page_num = 1u32;
-        let per_page = 100u8; // Max allowed by GitHub API
-
-        // Try to get first page with auth client, fallback to anonymous
-        let Ok(result) = self
-            .call_api_and_get_client(move |client| {
-                let repo_info = repo_info.clone();
-                Box::pin(async move {
-                    client
-                        .repos(repo_info.owner(), repo_info.repo())
-                        .releases()
-                        .list()
-                        .per_page(per_page)
-                        .page(page_num)
-                        .send()
-                        .await
-                })
-            })
-            .await
-        else {
-            return releases;
-        };
-        let mut current_page = result.value;
-        let client = result.client;
-
-        'pagintaion: loop {
-            if current_page.items.is_empty() {
-                break; // No more pages
+        self.for_each_release(repo_info, |release| {
+            // Check if this release is too old
+            if release.created_at.unwrap_or_default() < since_date {
+                return ControlFlow::Break(()); // Stop processing
             }
+            releases.push(release);
+            ControlFlow::Continue(())
+        })
+        .await;
+        releases
+    }
 
-            // Sort releases by created_at descending
-            current_page
-                .items
-                .sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    /// Walk releases newest first (by `created_at`) until `visit` breaks or
+    /// there are no more pages.
+    pub async fn for_each_release(
+        &self,
+        repo_info: &GhRepoInfo,
+        mut visit: impl FnMut(ReleaseInfo) -> ControlFlow<()> + Send,
+    ) {
+        for page_num in 1u32.. {
+            // Release lists change whenever something is published, so pages are revalidated
+            let Ok(mut page) = self
+                .get_cached::<Vec<Release>>(
+                    repo_info,
+                    &format!("releases/page-{page_num}"),
+                    &Self::repo_route(
+                        repo_info,
+                        &format!("releases?per_page={RELEASES_PER_PAGE}&page={page_num}"),
+                    ),
+                    |_| false,
+                )
+                .await
+            else {
+                break; // Stop on error
+            };
+            let is_last_page = page.len() < usize::from(RELEASES_PER_PAGE);
 
-            for release in current_page.items {
-                // Check if this release is too old
-                let release_tag_created_at = release.created_at.unwrap_or_default();
+            // Sort releases by created_at descending
+            page.sort_by_key(|release| std::cmp::Reverse(release.created_at));
 
-                if release_tag_created_at < since_date {
-                    break 'pagintaion; // Stop processing
+            for release in page {
+                if visit(release.into()).is_break() {
+                    return;
                 }
-
-                releases.push(ReleaseInfo {
-                    tag_name: release.tag_name,
-                    name: release.name,
-                    body: release.body,
-                    url: release.html_url.to_string(),
-                    published_at: release.published_at,
-                    created_at: release.created_at,
-                    prerelease: release.prerelease,
-                });
             }
 
-            if current_page.next.is_none() {
+            if is_last_page {
                 break; // No more pages
             }
+        }
+    }
 
-            page_num += 1;
-
-            // Fetch next page
-            current_page = match Self::await_with_timeout_and_error(
-                client
-                    .repos(repo_info.owner(), repo_info.repo())
-                    .releases()
-                    .list()
-                    .per_page(per_page)
-                    .page(page_num)
-                    .send(),
-            )
-            .await
-            .ok()
-            {
-                Some(page) => page,
-                None => break, // Stop on error
-            };
+    /// Fetch the commits reachable from `head` but not from `base`, most recent first.
+    pub async fn fetch_commits_between(
+        &self,
+        repo_info: &GhRepoInfo,
+        base: &str,
+        head: &str,
+    ) -> Option<Vec<CommitInfo>> {
+        #[derive(Deserialize)]
+        struct Comparison {
+            commits: Vec<RepoCommit>,
         }
 
-        releases
+        let mut commits = Vec::new();
+        for page_num in 1u32.. {
+            let page: Comparison = self
+                .get_cached(
+                    repo_info,
+                    &format!("compare/{base}...{head}/page-{page_num}"),
+                    &Self::repo_route(
+                        repo_info,
+                        &format!(
+                            "compare/{}...{}?per_page={COMPARE_PER_PAGE}&page={page_num}",
+                            encode_route(base),
+                            encode_route(head)
+                        ),
+                    ),
+                    |_| false,
+                )
+                .await
+                .log_err(&format!(
+                    "fetch_commits_between failed for {}/{} {base}...{head}",
+                    repo_info.owner(),
+                    repo_info.repo()
+                ))?;
+
+            let is_last_page = page.commits.len() < usize::from(COMPARE_PER_PAGE);
+            commits.extend(page.commits.into_iter().map(CommitInfo::from));
+            if is_last_page {
+                break;
+            }
+        }
+
+        // The compare API lists commits oldest first
+        commits.reverse();
+        Some(commits)
+    }
+
+    /// Fetch the names of all branches of a repository.
+    pub async fn fetch_branch_names(&self, repo_info: &GhRepoInfo) -> Option<Vec<String>> {
+        #[derive(Deserialize)]
+        struct Branch {
+            name: String,
+        }
+
+        let mut names = Vec::new();
+        for page_num in 1u32.. {
+            // Branches come and go, so pages are revalidated
+            let page: Vec<Branch> = self
+                .get_cached(
+                    repo_info,
+                    &format!("branches/page-{page_num}"),
+                    &Self::repo_route(
+                        repo_info,
+                        &format!("branches?per_page={BRANCHES_PER_PAGE}&page={page_num}"),
+                    ),
+                    |_| false,
+                )
+                .await
+                .log_err(&format!(
+                    "fetch_branch_names failed for {}/{}",
+                    repo_info.owner(),
+                    repo_info.repo()
+                ))?;
+
+            let is_last_page = page.len() < usize::from(BRANCHES_PER_PAGE);
+            names.extend(page.into_iter().map(|branch| branch.name));
+            if is_last_page {
+                break;
+            }
+        }
+
+        Some(names)
     }
 
     /// Fetch a GitHub release by tag.
@@ -690,35 +1079,60 @@ impl GitHubClient {
         repo_info: &GhRepoInfo,
         tag: &str,
     ) -> Option<ReleaseInfo> {
+        let key = (
+            repo_info.owner().to_string(),
+            repo_info.repo().to_string(),
+            tag.to_string(),
+        );
+        if let Some(cached) = self.release_cache.lock().ok()?.get(&key) {
+            return cached.clone();
+        }
+
         let release = self
-            .call_client_api_with_fallback(move |client| {
-                let tag = tag.to_string();
-                let repo_info = repo_info.clone();
-                Box::pin(async move {
-                    client
-                        .repos(repo_info.owner(), repo_info.repo())
-                        .releases()
-                        .get_by_tag(tag.as_str())
-                        .await
-                })
-            })
+            .fetch_release(repo_info, tag)
             .await
             .log_err(&format!(
                 "fetch_release_by_tag failed for {}/{} tag {}",
                 repo_info.owner(),
                 repo_info.repo(),
                 tag
-            ))?;
+            ))
+            .map(ReleaseInfo::from);
 
-        Some(ReleaseInfo {
-            tag_name: release.tag_name,
-            name: release.name,
-            body: release.body,
-            url: release.html_url.to_string(),
-            published_at: release.published_at,
-            created_at: release.created_at,
-            prerelease: release.prerelease,
-        })
+        if let Ok(mut cache) = self.release_cache.lock() {
+            cache.insert(key, release.clone());
+        }
+        release
+    }
+
+    /// Fetch the release for `tag`.
+    ///
+    /// Which release a tag belongs to is fixed once published, so only that id is
+    /// cached for good. The release itself (notes, name, pre-release flag) can be
+    /// edited and is revalidated like other mutable resources.
+    async fn fetch_release(&self, repo_info: &GhRepoInfo, tag: &str) -> WtgResult<Release> {
+        let id_key = format!("release-ids/{tag}");
+        if let Some(id) = self.cached_immutable::<u64>(repo_info, &id_key) {
+            return self
+                .get_cached(
+                    repo_info,
+                    &format!("releases/{id}"),
+                    &Self::repo_route(repo_info, &format!("releases/{id}")),
+                    |_| false,
+                )
+                .await;
+        }
+
+        let release: Release = self
+            .get_cached(
+                repo_info,
+                &format!("releases/tags/{tag}"),
+                &Self::repo_route(repo_info, &format!("releases/tags/{}", encode_route(tag))),
+                |_| false,
+            )
+            .await?;
+        self.cache_immutable(repo_info, &id_key, &release.id.0);
+        Ok(release)
     }
 
     /// Fetch tag info for a release by checking if target commit is contained in the tag.
@@ -778,24 +1192,24 @@ impl GitHubClient {
         })
     }
 
-    /// Check if a tag contains a specific commit using the GitHub compare API.
+    /// Check if a tag or branch contains a specific commit using the GitHub compare API.
     ///
-    /// Returns true if the commit is in the tag's history (status is "behind" or "identical").
-    pub async fn tag_contains_commit(
+    /// Returns true if the commit is in the ref's history (status is "behind" or "identical").
+    pub async fn ref_contains_commit(
         &self,
         repo_info: &GhRepoInfo,
-        tag: &str,
+        git_ref: &str,
         commit: &str,
     ) -> bool {
         let compare = self
             .call_client_api_with_fallback(move |client| {
-                let tag = tag.to_string();
+                let git_ref = git_ref.to_string();
                 let commit = commit.to_string();
                 let repo_info = repo_info.clone();
                 Box::pin(async move {
                     client
                         .commits(repo_info.owner(), repo_info.repo())
-                        .compare(&tag, &commit)
+                        .compare(&git_ref, &commit)
                         .per_page(1)
                         .send()
                         .await
@@ -889,7 +1303,8 @@ impl GitHubClient {
     pub fn commit_url(repo_info: &GhRepoInfo, hash: &str) -> String {
         use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
         format!(
-            "https://github.com/{}/{}/commit/{}",
+            "https://{}/{}/{}/commit/{}",
+            repo_info.host(),
             utf8_percent_encode(repo_info.owner(), NON_ALPHANUMERIC),
             utf8_percent_encode(repo_info.repo(), NON_ALPHANUMERIC),
             utf8_percent_encode(hash, NON_ALPHANUMERIC)
@@ -902,7 +1317,8 @@ impl GitHubClient {
     pub fn tag_url(repo_info: &GhRepoInfo, tag: &str) -> String {
         use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
         format!(
-            "https://github.com/{}/{}/tree/{}",
+            "https://{}/{}/{}/tree/{}",
+            repo_info.host(),
             utf8_percent_encode(repo_info.owner(), NON_ALPHANUMERIC),
             utf8_percent_encode(repo_info.repo(), NON_ALPHANUMERIC),
             utf8_percent_encode(tag, NON_ALPHANUMERIC)
@@ -915,7 +1331,8 @@ impl GitHubClient {
     pub fn release_tag_url(repo_info: &GhRepoInfo, tag: &str) -> String {
         use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
         format!(
-            "https://github.com/{}/{}/releases/tag/{}",
+            "https://{}/{}/{}/releases/tag/{}",
+            repo_info.host(),
             utf8_percent_encode(repo_info.owner(), NON_ALPHANUMERIC),
             utf8_percent_encode(repo_info.repo(), NON_ALPHANUMERIC),
             utf8_percent_encode(tag, NON_ALPHANUMERIC)
@@ -925,28 +1342,27 @@ impl GitHubClient {
     /// Build a profile URL (fallback when API data unavailable)
     /// Uses URL encoding to prevent injection
     #[must_use]
-    pub fn profile_url(username: &str) -> String {
+    pub fn profile_url(host: &str, username: &str) -> String {
         use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
         format!(
-            "https://github.com/{}",
+            "https://{}/{}",
+            host,
             utf8_percent_encode(username, NON_ALPHANUMERIC)
         )
     }
 
-    /// Build a profile URL from a GitHub noreply email address.
+    /// Build a profile URL from a GitHub noreply email address on `host`.
     ///
     /// Extracts username from patterns:
-    /// - `username@users.noreply.github.com`
-    /// - `id+username@users.noreply.github.com`
+    /// - `username@users.noreply.<host>`
+    /// - `id+username@users.noreply.<host>`
     #[must_use]
-    pub fn author_url_from_email(email: &str) -> Option<String> {
-        if email.ends_with("@users.noreply.github.com") {
-            let parts: Vec<&str> = email.split('@').collect();
-            if let Some(user_part) = parts.first()
-                && let Some(username) = user_part.split('+').next_back()
-            {
-                return Some(Self::profile_url(username));
-            }
+    pub fn author_url_from_email(host: &str, email: &str) -> Option<String> {
+        let (user_part, domain) = email.rsplit_once('@')?;
+        if domain.eq_ignore_ascii_case(&format!("users.noreply.{host}"))
+            && let Some(username) = user_part.split('+').next_back()
+        {
+            return Some(Self::profile_url(host, username));
         }
         None
     }
@@ -963,6 +1379,135 @@ impl GitHubClient {
         Duration::from_secs(REQUEST_TIMEOUT_SECS)
     }
 
+    /// API route for a repository resource, e.g. `/repos/owner/repo/pulls/1`.
+    fn repo_route(repo_info: &GhRepoInfo, resource: &str) -> String {
+        format!(
+            "/repos/{}/{}/{resource}",
+            encode_route(repo_info.owner()),
+            encode_route(repo_info.repo())
+        )
+    }
+
+    /// GET `route` through the persistent API cache, stored under `key`.
+    ///
+    /// Immutable entries, and mutable ones younger than the cache TTL, are
+    /// returned without a request. Otherwise the request
+    /// carries the cached `ETag`, and a `304 Not Modified` reuses the cached body.
+    /// `is_immutable` decides whether a fresh response can be kept forever.
+    async fn get_cached<T: DeserializeOwned>(
+        &self,
+        repo_info: &GhRepoInfo,
+        key: &str,
+        route: &str,
+        is_immutable: impl FnOnce(&T) -> bool,
+    ) -> WtgResult<T> {
+        if let Some(value) = self.cached_immutable(repo_info, key) {
+            log::debug!("API cache hit for {route}");
+            return Ok(value);
+        }
+
+        let cache = self.api_cache.as_ref();
+        let cached = cache.and_then(|cache| cache.get(repo_info, key));
+        if let Some(cached) = cached
+            .as_ref()
+            .filter(|cached| cache.is_some_and(|cache| cache.is_fresh(cached)))
+            && let Some(value) = serde_json::from_value(cached.body.clone())
+                .map_err(|e| WtgError::Io(e.into()))
+                .log_err(&format!("Ignoring stale API cache entry {key}"))
+        {
+            log::debug!("API cache hit for {route} (within TTL)");
+            return Ok(value);
+        }
+        let etag = cached.as_ref().and_then(|cached| cached.etag.clone());
+
+        let fresh = self
+            .call_client_api_with_fallback(move |client| {
+                let route = route.to_string();
+                let etag = etag.clone();
+                Box::pin(
+                    async move { Self::conditional_get(client, &route, etag.as_deref()).await },
+                )
+            })
+            .await?;
+
+        let Some((etag, body)) = fresh else {
+            // Not modified - only possible when we sent the cached ETag
+            log::debug!("API cache revalidated {route}");
+            let body = cached.map(|cached| cached.body).unwrap_or_default();
+            return serde_json::from_value(body).map_err(|e| WtgError::Io(e.into()));
+        };
+
+        let body: serde_json::Value =
+            serde_json::from_str(&body).map_err(|e| WtgError::Io(e.into()))?;
+        let value = T::deserialize(&body).map_err(|e| WtgError::Io(e.into()))?;
+
+        if let Some(cache) = &self.api_cache {
+            if is_immutable(&value) {
+                cache.put(repo_info, key, &CachedResponse::new(None, body));
+            } else if etag.is_some() {
+                cache.put(repo_info, key, &CachedResponse::new(etag, body));
+            }
+        }
+        Ok(value)
+    }
+
+    /// Send a GET, revalidating with `If-None-Match` when an `ETag` is given.
+    ///
+    /// Returns `None` for `304 Not Modified`, otherwise the response `ETag` and body.
+    #[allow(clippy::used_underscore_items)] // the raw GET is the only way to see 304s and headers
+    async fn conditional_get(
+        client: &Octocrab,
+        route: &str,
+        etag: Option<&str>,
+    ) -> OctoResult<Option<(Option<String>, String)>> {
+        let mut headers = http::HeaderMap::new();
+        if let Some(etag) = etag.and_then(|etag| http::HeaderValue::from_str(etag).ok()) {
+            headers.insert(http::header::IF_NONE_MATCH, etag);
+        }
+
+        let response = client._get_with_headers(route, Some(headers)).await?;
+        if response.status() == http::StatusCode::NOT_MODIFIED {
+            return Ok(None);
+        }
+
+        let response = octocrab::map_github_error(response).await?;
+        let etag = response
+            .headers()
+            .get(http::header::ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(str::to_string);
+        let body = client.body_to_string(response).await?;
+        Ok(Some((etag, body)))
+    }
+
+    /// Look up an immutable entry in the persistent API cache.
+    fn cached_immutable<T: DeserializeOwned>(
+        &self,
+        repo_info: &GhRepoInfo,
+        key: &str,
+    ) -> Option<T> {
+        let cached = self.api_cache.as_ref()?.get(repo_info, key)?;
+        if !cached.is_immutable() {
+            return None;
+        }
+        serde_json::from_value(cached.body)
+            .map_err(|e| WtgError::Io(e.into()))
+            .log_err(&format!("Ignoring stale API cache entry {key}"))
+    }
+
+    /// Store a value that never changes in the persistent API cache.
+    fn cache_immutable<T: Serialize>(&self, repo_info: &GhRepoInfo, key: &str, value: &T) {
+        let Some(cache) = &self.api_cache else {
+            return;
+        };
+        if let Some(body) = serde_json::to_value(value)
+            .map_err(|e| WtgError::Io(e.into()))
+            .log_err(&format!("Failed to serialize API cache entry {key}"))
+        {
+            cache.put(repo_info, key, &CachedResponse::new(None, body));
+        }
+    }
+
     /// Call a GitHub API with fallback from authenticated to anonymous client.
     async fn call_client_api_with_fallback<F, T>(&self, api_call: F) -> WtgResult<T>
     where
@@ -996,6 +1541,7 @@ impl GitHubClient {
                         self.is_authenticated,
                         e
                     );
+                    self.rate_limited.store(true, Ordering::Relaxed);
                     self.emit(Notice::GhRateLimitHit {
                         authenticated: self.is_authenticated,
                     });
@@ -1034,6 +1580,7 @@ impl GitHubClient {
                 log::debug!("GitHub API rate limit hit on backup client: {e:?}");
                 // Emit notice for anonymous fallback (authenticated was true to reach here,
                 // but backup is anonymous)
+                self.rate_limited.store(true, Ordering::Relaxed);
                 self.emit(Notice::GhRateLimitHit {
                     authenticated: false,
                 });
@@ -1062,3 +1609,164 @@ impl GitHubClient {
         }
     }
 }
+
+/// Percent-encode a value for use in an API route.
+fn encode_route(value: &str) -> String {
+    utf8_percent_encode(value, ROUTE_ENCODE_SET).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::plain("ghe.example.com", "ghe.example.com")]
+    #[case::scheme_and_slash("https://GHE.example.com/", "ghe.example.com")]
+    #[case::www("www.github.com", "github.com")]
+    fn normalizes_hosts(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(normalize_host(input), expected);
+    }
+
+    #[test]
+    fn github_com_repo_info_matches_explicit_host() {
+        const PUBLIC: GhRepoInfo = GhRepoInfo::new(String::new(), String::new());
+        assert!(PUBLIC.is_github_com());
+        assert_eq!(
+            GhRepoInfo::new("org".into(), "app".into()),
+            GhRepoInfo::with_host("https://github.com/", "org".into(), "app".into())
+        );
+    }
+
+    #[test]
+    fn builds_urls_for_repo_host() {
+        let info = GhRepoInfo::with_host("ghe.example.com", "org".into(), "app".into());
+        assert!(!info.is_github_com());
+        assert_eq!(
+            GitHubClient::commit_url(&info, "abc"),
+            "https://ghe.example.com/org/app/commit/abc"
+        );
+        assert_eq!(
+            GitHubClient::release_tag_url(&info, "v1"),
+            "https://ghe.example.com/org/app/releases/tag/v1"
+        );
+
+        let public = GhRepoInfo::new("org".into(), "app".into());
+        assert_eq!(
+            GitHubClient::tag_url(&public, "v1"),
+            "https://github.com/org/app/tree/v1"
+        );
+    }
+
+    #[rstest]
+    #[case::github_com(
+        "github.com",
+        "123+octocat@users.noreply.github.com",
+        Some("https://github.com/octocat")
+    )]
+    #[case::enterprise(
+        "ghe.example.com",
+        "octocat@users.noreply.ghe.example.com",
+        Some("https://ghe.example.com/octocat")
+    )]
+    #[case::other_host("ghe.example.com", "octocat@users.noreply.github.com", None)]
+    #[case::regular_email("github.com", "octocat@example.com", None)]
+    fn builds_author_url_from_noreply_email(
+        #[case] host: &str,
+        #[case] email: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(
+            GitHubClient::author_url_from_email(host, email).as_deref(),
+            expected
+        );
+    }
+
+    fn release(id: u64, body: &str) -> serde_json::Value {
+        serde_json::json!({
+            "url": format!("https://api.github.com/repos/o/r/releases/{id}"),
+            "assets_url": format!("https://api.github.com/repos/o/r/releases/{id}/assets"),
+            "upload_url": format!("https://uploads.github.com/repos/o/r/releases/{id}/assets"),
+            "html_url": "https://github.com/o/r/releases/tag/v1.0.0",
+            "id": id,
+            "node_id": "RE_1",
+            "tag_name": "v1.0.0",
+            "target_commitish": "main",
+            "name": "v1.0.0",
+            "draft": false,
+            "prerelease": false,
+            "created_at": "2026-03-10T12:00:00Z",
+            "published_at": "2026-03-10T12:00:00Z",
+            "assets": [],
+            "body": body
+        })
+    }
+
+    #[tokio::test]
+    async fn revalidates_cached_releases() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let cache_dir = tempfile::tempdir().unwrap();
+        let repo_info = GhRepoInfo::new("o".into(), "r".into());
+        let client = || {
+            let mut client =
+                GitHubClient::with_api_url(GITHUB_COM, &server.uri(), None).expect("client");
+            client.api_cache = Some(ApiCache::at(cache_dir.path().to_path_buf()));
+            client
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/repos/o/r/releases/tags/v1.0.0"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"first\"")
+                    .set_body_json(release(7, "First notes")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let first = client().fetch_release_by_tag(&repo_info, "v1.0.0").await;
+        assert_eq!(first.and_then(|r| r.body).as_deref(), Some("First notes"));
+
+        // Edited notes show up: the tag's release id is cached, its contents aren't
+        Mock::given(method("GET"))
+            .and(path("/repos/o/r/releases/7"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"edited\"")
+                    .set_body_json(release(7, "Edited notes")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let edited = client().fetch_release_by_tag(&repo_info, "v1.0.0").await;
+        assert_eq!(edited.and_then(|r| r.body).as_deref(), Some("Edited notes"));
+    }
+
+    #[tokio::test]
+    async fn remembers_hitting_the_rate_limit() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let mut client =
+            GitHubClient::with_api_url(GITHUB_COM, &server.uri(), None).expect("client");
+        client.api_cache = None;
+        let repo_info = GhRepoInfo::new("o".into(), "r".into());
+
+        Mock::given(method("GET"))
+            .and(path("/repos/o/r/pulls/1"))
+            .respond_with(ResponseTemplate::new(429).set_body_json(serde_json::json!({
+                "message": "API rate limit exceeded",
+                "documentation_url": "https://docs.github.com/rest"
+            })))
+            .mount(&server)
+            .await;
+
+        assert!(!client.is_rate_limited());
+        assert!(client.fetch_pr(&repo_info, 1).await.is_none());
+        assert!(client.is_rate_limited());
+    }
+}
@@ -0,0 +1,668 @@
+//! GitLab REST API (v4) client.
+//!
+//! Mirrors the subset of `GitHubClient` that wtg needs: commits, merge requests,
+//! issues (with the merge requests that closed them), tags and releases.
+//! Works against gitlab.com and self-managed instances alike - the client only
+//! needs the instance base URL.
+
+use std::env;
+
+use chrono::{DateTime, Utc};
+use octocrab::models::IssueState;
+use serde::{Deserialize, Serialize};
+
+use crate::error::LogError;
+use crate::forge_http::{ForgeAuth, ForgeHttp, encode};
+use crate::git::{CommitInfo, TagInfo, parse_semver};
+use crate::github::{ExtendedIssueInfo, PullRequestInfo, ReleaseInfo};
+
+/// Default GitLab instance.
+pub const GITLAB_COM: &str = "https://gitlab.com";
+
+/// A GitLab project: the instance it lives on and its full namespace path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GlRepoInfo {
+    /// Web base URL of the instance, without trailing slash (e.g. `https://gitlab.com`)
+    base_url: String,
+    /// Full project path including subgroups (e.g. `group/subgroup/project`)
+    path: String,
+}
+
+impl GlRepoInfo {
+    #[must_use]
+    pub fn new(base_url: &str, path: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            path: path.trim_matches('/').to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Parse a git remote URL pointing at a GitLab project.
+    ///
+    /// Supports `https://host/group/project(.git)`, `git@host:group/project(.git)`
+    /// and `ssh://git@host[:port]/group/project(.git)`. Subgroups are preserved.
+    #[must_use]
+    pub fn from_remote_url(url: &str) -> Option<Self> {
+        let url = url.trim();
+
+        let (host, path) = if let Some(rest) = url.strip_prefix("git@") {
+            rest.split_once(':')?
+        } else {
+            let parsed = url::Url::parse(url).ok()?;
+            let host = parsed.host_str()?;
+            // Keep the explicit port for http(s) remotes only - SSH ports don't serve the API
+            let host = match (parsed.scheme(), parsed.port()) {
+                ("http" | "https", Some(port)) => format!("{host}:{port}"),
+                _ => host.to_string(),
+            };
+            let scheme = if parsed.scheme() == "http" {
+                "http"
+            } else {
+                "https"
+            };
+            let path = parsed.path().trim_matches('/');
+            let path = path.strip_suffix(".git").unwrap_or(path);
+            if path.split('/').filter(|s| !s.is_empty()).count() < 2 {
+                return None;
+            }
+            return Some(Self::new(&format!("{scheme}://{host}"), path));
+        };
+
+        let path = path.trim_matches('/');
+        let path = path.strip_suffix(".git").unwrap_or(path);
+        if host.is_empty() || path.split('/').filter(|s| !s.is_empty()).count() < 2 {
+            return None;
+        }
+
+        Some(Self::new(&format!("https://{host}"), path))
+    }
+
+    /// Project identifier as expected by the API (URL-encoded full path).
+    fn api_id(&self) -> String {
+        encode(&self.path)
+    }
+
+    /// Web URL of the project.
+    fn web_url(&self) -> String {
+        format!("{}/{}", self.base_url, self.path)
+    }
+}
+
+// ============================================
+// API response types
+// ============================================
+
+#[derive(Debug, Deserialize)]
+struct GlUser {
+    username: String,
+    web_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlCommit {
+    id: String,
+    short_id: Option<String>,
+    message: Option<String>,
+    title: Option<String>,
+    author_name: Option<String>,
+    author_email: Option<String>,
+    authored_date: Option<DateTime<Utc>>,
+    committed_date: Option<DateTime<Utc>>,
+    web_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlMergeRequest {
+    iid: u64,
+    title: String,
+    description: Option<String>,
+    state: String,
+    web_url: String,
+    sha: Option<String>,
+    merge_commit_sha: Option<String>,
+    squash_commit_sha: Option<String>,
+    author: Option<GlUser>,
+    created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    labels: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlIssue {
+    iid: u64,
+    title: String,
+    description: Option<String>,
+    state: String,
+    web_url: String,
+    author: Option<GlUser>,
+    created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlReleaseLinks {
+    #[serde(rename = "self")]
+    self_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlRelease {
+    tag_name: String,
+    name: Option<String>,
+    description: Option<String>,
+    created_at: Option<DateTime<Utc>>,
+    released_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    upcoming_release: bool,
+    #[serde(rename = "_links")]
+    links: Option<GlReleaseLinks>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlTagRelease {
+    tag_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlTag {
+    name: String,
+    commit: GlCommit,
+    release: Option<GlTagRelease>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlCommitRef {
+    #[serde(rename = "type")]
+    kind: String,
+    name: String,
+}
+
+impl From<GlCommit> for CommitInfo {
+    fn from(commit: GlCommit) -> Self {
+        let full_message = commit.message.or(commit.title).unwrap_or_default();
+        let short_hash = commit
+            .short_id
+            .unwrap_or_else(|| commit.id[..7.min(commit.id.len())].to_string());
+
+        Self {
+            short_hash,
+            message: full_message.lines().next().unwrap_or("").to_string(),
+            message_lines: full_message.lines().count(),
+            commit_url: commit.web_url,
+            author_name: commit.author_name.unwrap_or_else(|| "Unknown".to_string()),
+            author_email: commit.author_email,
+            author_login: None,
+            author_url: None,
+            date: commit
+                .authored_date
+                .or(commit.committed_date)
+                .unwrap_or_else(Utc::now),
+            hash: commit.id,
+        }
+    }
+}
+
+impl From<GlMergeRequest> for PullRequestInfo {
+    fn from(mr: GlMergeRequest) -> Self {
+        let merged = mr.state == "merged";
+        // Fast-forward merges leave no merge/squash commit - the head commit is what landed
+        let merge_commit_sha = mr
+            .merge_commit_sha
+            .or(mr.squash_commit_sha)
+            .or_else(|| mr.sha.filter(|_| merged));
+
+        Self {
+            number: mr.iid,
+            repo_info: None,
+            title: mr.title,
+            body: mr.description.filter(|d| !d.is_empty()),
+            state: mr.state,
+            url: mr.web_url,
+            merged,
+            merge_commit_sha,
+            author: mr.author.as_ref().map(|a| a.username.clone()),
+            author_url: mr.author.and_then(|a| a.web_url),
+            created_at: mr.created_at,
+            labels: mr.labels,
+        }
+    }
+}
+
+impl From<GlIssue> for ExtendedIssueInfo {
+    fn from(issue: GlIssue) -> Self {
+        Self {
+            number: issue.iid,
+            title: issue.title,
+            body: issue.description.filter(|d| !d.is_empty()),
+            state: if issue.state == "closed" {
+                IssueState::Closed
+            } else {
+                IssueState::Open
+            },
+            url: issue.web_url,
+            author: issue.author.as_ref().map(|a| a.username.clone()),
+            author_url: issue.author.and_then(|a| a.web_url),
+            closing_prs: Vec::new(),
+            created_at: issue.created_at,
+            timeline_may_be_incomplete: false,
+        }
+    }
+}
+
+// ============================================
+// Client
+// ============================================
+
+/// GitLab API client for a single instance.
+pub struct GitLabClient {
+    /// Talks to the API root, e.g. `https://gitlab.com/api/v4`
+    http: ForgeHttp,
+}
+
+impl GitLabClient {
+    /// Create a client for the instance at `base_url`.
+    ///
+    /// Authenticates with `GITLAB_TOKEN` when set; works anonymously otherwise.
+    /// Returns `None` if the HTTP client cannot be built.
+    #[must_use]
+    pub fn new(base_url: &str) -> Option<Self> {
+        let token = env::var("GITLAB_TOKEN")
+            .ok()
+            .filter(|t| !t.trim().is_empty());
+        Self::with_token(base_url, token)
+    }
+
+    /// Create a client for the instance at `base_url` with an explicit token.
+    #[must_use]
+    pub fn with_token(base_url: &str, token: Option<String>) -> Option<Self> {
+        let api_url = format!("{}/api/v4", base_url.trim_end_matches('/'));
+        let auth = token.map(|token| ForgeAuth::Header {
+            name: "PRIVATE-TOKEN",
+            token,
+        });
+        Some(Self {
+            http: ForgeHttp::new(&api_url, auth)?,
+        })
+    }
+
+    /// Fetch a commit by (possibly abbreviated) SHA or ref name.
+    pub async fn fetch_commit(&self, repo_info: &GlRepoInfo, sha: &str) -> Option<CommitInfo> {
+        let commit: GlCommit = self
+            .http
+            .get_json(&format!(
+                "projects/{}/repository/commits/{}",
+                repo_info.api_id(),
+                encode(sha)
+            ))
+            .await
+            .log_err(&format!(
+                "fetch_commit failed for {} commit {sha}",
+                repo_info.path()
+            ))?;
+
+        Some(commit.into())
+    }
+
+    /// Fetch a merge request by its project-scoped number (`!iid`).
+    pub async fn fetch_merge_request(
+        &self,
+        repo_info: &GlRepoInfo,
+        iid: u64,
+    ) -> Option<PullRequestInfo> {
+        let mr: GlMergeRequest = self
+            .http
+            .get_json(&format!(
+                "projects/{}/merge_requests/{iid}",
+                repo_info.api_id()
+            ))
+            .await
+            .log_err(&format!(
+                "fetch_merge_request failed for {} MR !{iid}",
+                repo_info.path()
+            ))?;
+
+        Some(mr.into())
+    }
+
+    /// Fetch the merged merge request that introduced a commit.
+    pub async fn fetch_merge_request_for_commit(
+        &self,
+        repo_info: &GlRepoInfo,
+        sha: &str,
+    ) -> Option<PullRequestInfo> {
+        let mrs: Vec<GlMergeRequest> = self
+            .http
+            .get_json(&format!(
+                "projects/{}/repository/commits/{}/merge_requests",
+                repo_info.api_id(),
+                encode(sha)
+            ))
+            .await
+            .log_err(&format!(
+                "fetch_merge_request_for_commit failed for {} commit {sha}",
+                repo_info.path()
+            ))?;
+
+        mrs.into_iter()
+            .map(PullRequestInfo::from)
+            .find(|mr| mr.merged)
+    }
+
+    /// Fetch an issue along with the merged merge requests that closed it.
+    pub async fn fetch_issue(&self, repo_info: &GlRepoInfo, iid: u64) -> Option<ExtendedIssueInfo> {
+        let issue: GlIssue = self
+            .http
+            .get_json(&format!("projects/{}/issues/{iid}", repo_info.api_id()))
+            .await
+            .log_err(&format!(
+                "fetch_issue failed for {} issue #{iid}",
+                repo_info.path()
+            ))?;
+
+        let mut issue_info = ExtendedIssueInfo::from(issue);
+
+        // Only closed issues can have closing MRs
+        if matches!(issue_info.state, IssueState::Closed) {
+            match self
+                .http
+                .get_json::<Vec<GlMergeRequest>>(&format!(
+                    "projects/{}/issues/{iid}/closed_by",
+                    repo_info.api_id()
+                ))
+                .await
+            {
+                Ok(mrs) => {
+                    issue_info.closing_prs = mrs
+                        .into_iter()
+                        .map(PullRequestInfo::from)
+                        .filter(|mr| mr.merged)
+                        .collect();
+                }
+                Err(e) => {
+                    log::debug!(
+                        "closed_by lookup failed for {} #{iid}: {e:?}",
+                        repo_info.path()
+                    );
+                    issue_info.timeline_may_be_incomplete = true;
+                }
+            }
+        }
+
+        Some(issue_info)
+    }
+
+    /// Fetch a release by its tag name.
+    pub async fn fetch_release_by_tag(
+        &self,
+        repo_info: &GlRepoInfo,
+        tag_name: &str,
+    ) -> Option<ReleaseInfo> {
+        let release: GlRelease = self
+            .http
+            .get_json(&format!(
+                "projects/{}/releases/{}",
+                repo_info.api_id(),
+                encode(tag_name)
+            ))
+            .await
+            .log_err(&format!(
+                "fetch_release_by_tag failed for {} tag {tag_name}",
+                repo_info.path()
+            ))?;
+
+        let url = release
+            .links
+            .and_then(|l| l.self_url)
+            .unwrap_or_else(|| Self::release_tag_url(repo_info, &release.tag_name));
+
+        Some(ReleaseInfo {
+            tag_name: release.tag_name,
+            name: release.name,
+            body: release.description,
+            url,
+            published_at: release.released_at,
+            created_at: release.created_at,
+            prerelease: release.upcoming_release,
+        })
+    }
+
+    /// Fetch a tag, enriched with release metadata when the tag has a release.
+    pub async fn fetch_tag(&self, repo_info: &GlRepoInfo, tag_name: &str) -> Option<TagInfo> {
+        let tag: GlTag = self
+            .http
+            .get_json(&format!(
+                "projects/{}/repository/tags/{}",
+                repo_info.api_id(),
+                encode(tag_name)
+            ))
+            .await
+            .log_err(&format!(
+                "fetch_tag failed for {} tag {tag_name}",
+                repo_info.path()
+            ))?;
+
+        let release = if tag.release.is_some() {
+            self.fetch_release_by_tag(repo_info, &tag.name).await
+        } else {
+            None
+        };
+
+        Some(Self::tag_info(repo_info, tag, release))
+    }
+
+    /// Fetch all tags whose history contains the given commit.
+    ///
+    /// Release metadata is limited to whether a release exists; use
+    /// [`Self::fetch_release_by_tag`] for names and dates.
+    pub async fn fetch_tags_containing_commit(
+        &self,
+        repo_info: &GlRepoInfo,
+        commit_hash: &str,
+    ) -> Vec<TagInfo> {
+        let mut names = Vec::new();
+        for page in 1.. {
+            let Some(refs) = self
+                .http
+                .get_json::<Vec<GlCommitRef>>(&format!(
+                    "projects/{}/repository/commits/{}/refs?type=tag&per_page=100&page={page}",
+                    repo_info.api_id(),
+                    encode(commit_hash)
+                ))
+                .await
+                .log_err(&format!(
+                    "commit refs lookup failed for {} commit {commit_hash}",
+                    repo_info.path()
+                ))
+            else {
+                break;
+            };
+
+            let count = refs.len();
+            names.extend(refs.into_iter().filter(|r| r.kind == "tag").map(|r| r.name));
+            if count < 100 {
+                break;
+            }
+        }
+
+        let mut tags = Vec::with_capacity(names.len());
+        for name in names {
+            if let Some(tag) = self
+                .http
+                .get_json::<GlTag>(&format!(
+                    "projects/{}/repository/tags/{}",
+                    repo_info.api_id(),
+                    encode(&name)
+                ))
+                .await
+                .log_err(&format!(
+                    "fetch_tag failed for {} tag {name}",
+                    repo_info.path()
+                ))
+            {
+                tags.push(Self::tag_info(repo_info, tag, None));
+            }
+        }
+        tags
+    }
+
+    /// Fetch raw file content from the default branch.
+    pub async fn fetch_file_content(&self, repo_info: &GlRepoInfo, path: &str) -> Option<String> {
+        self.http
+            .get_text(&format!(
+                "projects/{}/repository/files/{}/raw?ref=HEAD",
+                repo_info.api_id(),
+                encode(path)
+            ))
+            .await
+            .ok()
+    }
+
+    fn tag_info(repo_info: &GlRepoInfo, tag: GlTag, release: Option<ReleaseInfo>) -> TagInfo {
+        let is_release = tag.release.is_some() || release.is_some();
+        let release_url = is_release.then(|| {
+            release.as_ref().map_or_else(
+                || {
+                    let tag_name = tag.release.as_ref().map_or(&tag.name, |r| &r.tag_name);
+                    Self::release_tag_url(repo_info, tag_name)
+                },
+                |r| r.url.clone(),
+            )
+        });
+        let tag_url = Some(
+            release_url
+                .clone()
+                .unwrap_or_else(|| Self::tag_url(repo_info, &tag.name)),
+        );
+        let commit: CommitInfo = tag.commit.into();
+
+        TagInfo {
+            semver_info: parse_semver(&tag.name),
+            name: tag.name,
+            commit_hash: commit.hash,
+            created_at: commit.date,
+            is_release,
+            release_name: release.as_ref().and_then(|r| r.name.clone()),
+            release_url,
+            published_at: release.and_then(|r| r.published_at),
+            tag_url,
+        }
+    }
+
+    // ============================================
+    // URL builders
+    // ============================================
+
+    /// Build a commit URL. Uses URL encoding to prevent injection.
+    #[must_use]
+    pub fn commit_url(repo_info: &GlRepoInfo, hash: &str) -> String {
+        format!("{}/-/commit/{}", repo_info.web_url(), encode(hash))
+    }
+
+    /// Build a tag URL pointing to the tree view (for plain git tags).
+    #[must_use]
+    pub fn tag_url(repo_info: &GlRepoInfo, tag: &str) -> String {
+        format!("{}/-/tree/{}", repo_info.web_url(), encode(tag))
+    }
+
+    /// Build a release URL (for tags with releases).
+    #[must_use]
+    pub fn release_tag_url(repo_info: &GlRepoInfo, tag: &str) -> String {
+        format!("{}/-/releases/{}", repo_info.web_url(), encode(tag))
+    }
+
+    /// Build a profile URL from a GitLab noreply email address.
+    ///
+    /// Extracts the username from `id-username@users.noreply.<host>`.
+    #[must_use]
+    pub fn author_url_from_email(repo_info: &GlRepoInfo, email: &str) -> Option<String> {
+        let (user_part, domain) = email.split_once('@')?;
+        if !domain.starts_with("users.noreply.") {
+            return None;
+        }
+        let username = user_part
+            .split_once('-')
+            .filter(|(id, _)| id.chars().all(|c| c.is_ascii_digit()))
+            .map_or(user_part, |(_, name)| name);
+        if username.is_empty() {
+            return None;
+        }
+        Some(format!("{}/{}", repo_info.base_url(), encode(username)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("https://gitlab.com/group/project.git", GITLAB_COM, "group/project")]
+    #[case(
+        "https://gitlab.com/group/sub/project",
+        GITLAB_COM,
+        "group/sub/project"
+    )]
+    #[case("git@gitlab.com:group/project.git", GITLAB_COM, "group/project")]
+    #[case(
+        "ssh://git@gitlab.example.com:2222/team/app.git",
+        "https://gitlab.example.com",
+        "team/app"
+    )]
+    #[case("http://localhost:8080/team/app", "http://localhost:8080", "team/app")]
+    fn parses_remote_urls(#[case] url: &str, #[case] base_url: &str, #[case] path: &str) {
+        let info = GlRepoInfo::from_remote_url(url).expect("should parse");
+        assert_eq!(info.base_url(), base_url);
+        assert_eq!(info.path(), path);
+    }
+
+    #[rstest]
+    #[case("https://gitlab.com/just-a-group")]
+    #[case("git@gitlab.com:")]
+    #[case("not a url")]
+    fn rejects_non_project_urls(#[case] url: &str) {
+        assert!(GlRepoInfo::from_remote_url(url).is_none());
+    }
+
+    #[test]
+    fn builds_urls_with_subgroups() {
+        let info = GlRepoInfo::new(GITLAB_COM, "group/sub/project");
+        assert_eq!(info.api_id(), "group%2Fsub%2Fproject");
+        assert_eq!(
+            GitLabClient::commit_url(&info, "abc123"),
+            "https://gitlab.com/group/sub/project/-/commit/abc123"
+        );
+        assert_eq!(
+            GitLabClient::tag_url(&info, "v1.0.0"),
+            "https://gitlab.com/group/sub/project/-/tree/v1.0.0"
+        );
+        assert_eq!(
+            GitLabClient::tag_url(&info, "release/1.0_rc-1"),
+            "https://gitlab.com/group/sub/project/-/tree/release%2F1.0_rc-1"
+        );
+        assert_eq!(
+            GitLabClient::release_tag_url(&info, "v1"),
+            "https://gitlab.com/group/sub/project/-/releases/v1"
+        );
+    }
+
+    #[rstest]
+    #[case("123-jdoe@users.noreply.gitlab.com", Some("https://gitlab.com/jdoe"))]
+    #[case("jdoe@users.noreply.gitlab.com", Some("https://gitlab.com/jdoe"))]
+    #[case("jdoe@example.com", None)]
+    fn author_url_from_noreply_email(#[case] email: &str, #[case] expected: Option<&str>) {
+        let info = GlRepoInfo::new(GITLAB_COM, "group/project");
+        assert_eq!(
+            GitLabClient::author_url_from_email(&info, email).as_deref(),
+            expected
+        );
+    }
+}
@@ -16,11 +16,35 @@ pub fn display_help() {
   {cmd} {examples}
   {cmd} {examples} {release_arg}
   {cmd} -r {repo_url} {examples_with_r}
+  {cmd} --batch {examples}...
+  {cmd} cache clear
+  {cmd} config show
 
 {options_header}
   {opt_r}              GitHub repository (e.g., owner/repo)
   {opt_fetch}             Fetch missing refs from remote when not found locally
   {opt_skip_pre}  Skip pre-release versions (nightlies, RCs, etc.)
+  {opt_no_skip_pre} Count pre-releases despite the config
+  {opt_tag_prefix}    Only consider one component's tags (py-v1.2, sdk/go/v1.2)
+  {opt_include_tag} Only consider tags matching a glob or /regex/
+  {opt_exclude_tag} Ignore tags matching a glob or /regex/
+  {opt_require_release}   Only count tags with a forge release
+  {opt_no_require_release}  Count tags without one despite the config
+  {opt_all_releases}     First release of every line (v1.x, v2.x, py-v0.x, ...)
+  {opt_format}      Output format: human (default) or json
+  {opt_release}     Release to check against (also in batch mode)
+  {opt_branches}          Also list branches containing the commit
+  {opt_branch}     Only list branches matching a glob (repeatable)
+  {opt_history}           List every commit that changed a file
+  {opt_page}          Page of file history (implies --history)
+  {opt_page_size}     Commits per page of file history (default: 20)
+  {opt_batch}            Treat every argument as a separate query
+  {opt_stdin}             Read queries from stdin, one per line
+  {opt_jobs}          Concurrent queries in batch mode (default: 8)
+  {opt_no_cache}          Skip the on-disk GitHub API cache
+  {opt_cache}             Use the API cache despite the config
+  {opt_record}      Save GitHub API traffic to a directory
+  {opt_replay}      Answer from a recording instead of GitHub
 
 {what_header}
   {bullet} Throw anything at me: commits, issues, PRs, files, or tags
@@ -32,13 +56,29 @@ pub fn display_help() {
   {dim}# Local repository
   {cmd} c62bbcc                              {dim2}# Find commit info
   {cmd} 123                                  {dim2}# Look up issue or PR
+  {cmd} !42                                  {dim2}# Look up a GitLab merge request
   {cmd} Cargo.toml                           {dim2}# Check file history
+  {cmd} Cargo.toml --history                 {dim2}# Every change, with PRs and releases
+  {cmd} src/lib.rs:120-140                   {dim2}# Who wrote these lines, in which PR?
+  {cmd} src/backend/                         {dim2}# Latest change under a directory
+  {cmd} 'crates/*/Cargo.toml'                {dim2}# Latest change to matching files
   {cmd} v1.2.3                               {dim2}# Inspect a release tag
+  {cmd} v1.2.0..v1.3.0                       {dim2}# Release notes between two tags
+  {cmd} @2026-03-14                          {dim2}# Which release was current then?
+  {cmd} @2026-01-01..@2026-03-14             {dim2}# Release notes for a date range
 
   {dim}# Check specific release
   {cmd} c62bbcc v2.0.0                       {dim2}# Is commit in v2.0.0?
   {cmd} 123 v2.0.0                           {dim2}# Is PR/issue fix in v2.0.0?
   {cmd} c62bbcc -S                           {dim2}# Skip pre-releases
+  {cmd} c62bbcc -A                           {dim2}# First release of each major/component
+  {cmd} c62bbcc --tag-prefix py              {dim2}# Only look at py-v* releases
+  {cmd} c62bbcc --format json                {dim2}# Machine-readable output
+  {cmd} 123 --branch 'release/*'             {dim2}# Is the fix on a release branch yet?
+
+  {dim}# Many queries at once
+  {cmd} --batch 123 456 c62bbcc              {dim2}# One report per query
+  {cmd} --stdin --release v2.0.0 < prs.txt   {dim2}# Which of these shipped in v2.0.0?
 
   {dim}# Remote repository
   {cmd} -r owner/repo c62bbcc                {dim2}# Check commit in remote repo
@@ -49,6 +89,8 @@ pub fn display_help() {
   {cmd} https://github.com/owner/repo/issues/42
   {cmd} https://github.com/owner/repo/pull/123
   {cmd} https://github.com/owner/repo/blob/main/src/file.rs
+  {cmd} https://github.com/owner/repo/blob/main/src/file.rs#L42-L50
+  {cmd} https://github.com/owner/repo/compare/v1.2.0...v1.3.0
 ",
         title = format!("{} What The Git?! {}", "🔍", "🔍").green().bold(),
         version = format!("v{version}").dark_grey(),
@@ -63,6 +105,27 @@ pub fn display_help() {
         opt_r = "-r, --repo".green(),
         opt_fetch = "    --fetch".green(),
         opt_skip_pre = "-S, --skip-prereleases".green(),
+        opt_no_skip_pre = "    --no-skip-prereleases".green(),
+        opt_tag_prefix = "    --tag-prefix <P>".green(),
+        opt_include_tag = "    --include-tag <PAT>".green(),
+        opt_exclude_tag = "    --exclude-tag <PAT>".green(),
+        opt_require_release = "    --require-release".green(),
+        opt_no_require_release = "    --no-require-release".green(),
+        opt_all_releases = "-A, --all-releases".green(),
+        opt_format = "    --format <FMT>".green(),
+        opt_release = "    --release <TAG>".green(),
+        opt_branches = "    --branches".green(),
+        opt_branch = "    --branch <GLOB>".green(),
+        opt_history = "    --history".green(),
+        opt_page = "    --page <N>".green(),
+        opt_page_size = "    --page-size <N>".green(),
+        opt_batch = "-b, --batch".green(),
+        opt_stdin = "    --stdin".green(),
+        opt_jobs = "-j, --jobs <N>".green(),
+        opt_no_cache = "    --no-cache".green(),
+        opt_cache = "    --cache".green(),
+        opt_record = "    --record <DIR>".green(),
+        opt_replay = "    --replay <DIR>".green(),
         what_header = "WHAT I DO".cyan().bold(),
         bullet = "→",
         examples_header = "EXAMPLES".cyan().bold(),
@@ -1,31 +1,55 @@
+//! What The Git: find out what a commit, issue, PR, file or tag is and which release shipped it.
+//!
+//! [`Wtg`] is the entry point for using wtg as a library; [`run`] runs the CLI.
+
 use std::{env, ffi::OsString};
 
 use clap::Parser;
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+
+use futures::StreamExt;
 
-use crate::backend::resolve_backend_with_notices;
-use crate::cli::Cli;
-use crate::error::{WtgError, WtgResult};
-use crate::release_filter::ReleaseFilter;
-use crate::resolution::resolve;
+use crate::cli::{CacheAction, Cli, Command, ConfigAction};
+use crate::config::{Config, Settings};
+use crate::output::OutputFormat;
+use crate::parse_input::{Candidate, Query};
+use crate::recording::Recording;
 
+pub mod api_cache;
 pub mod backend;
+pub mod batch;
+pub mod bitbucket;
+pub mod branch_filter;
 pub mod changelog;
 pub mod cli;
+pub mod client;
+pub mod config;
 pub mod constants;
 pub mod error;
+pub(crate) mod forge_http;
 pub mod git;
+pub mod gitea;
 pub mod github;
+pub mod gitlab;
 pub mod help;
 pub mod notice;
 pub mod output;
 pub mod parse_input;
+pub mod picker;
+pub mod recording;
 pub mod release_filter;
+pub mod release_notes;
 pub mod remote;
 pub mod resolution;
 pub mod semver;
 
+pub use client::{Wtg, WtgBuilder};
+pub use error::{WtgError, WtgResult};
+pub use notice::{Notice, NoticeCallback};
+pub use release_filter::ReleaseFilter;
+pub use resolution::IdentifiedThing;
+
 /// Run the CLI using the process arguments.
 pub fn run() -> WtgResult<()> {
     run_with_args(env::args())
@@ -59,9 +83,18 @@ where
     run_with_cli(cli)
 }
 
-fn run_with_cli(cli: Cli) -> WtgResult<()> {
+fn run_with_cli(mut cli: Cli) -> WtgResult<()> {
+    let config = Config::load()?;
+    let settings = Settings::resolve(&config, cli.overrides().with_env()?);
+
+    if let Some(command) = &cli.command {
+        return run_command(command, &config, &settings);
+    }
+
+    cli.apply_settings(&settings);
+
     // If no input provided, show custom help
-    if cli.input.is_none() {
+    if cli.is_empty() {
         help::display_help();
         return Ok(());
     }
@@ -73,40 +106,185 @@ fn run_with_cli(cli: Cli) -> WtgResult<()> {
     runtime.block_on(run_async(cli))
 }
 
+/// Run a maintenance subcommand (no query involved).
+fn run_command(command: &Command, config: &Config, settings: &Settings) -> WtgResult<()> {
+    match command {
+        Command::Cache {
+            action: CacheAction::Clear,
+        } => {
+            let removed = api_cache::clear()?;
+            output::display_cache_cleared(removed.as_deref());
+            Ok(())
+        }
+        Command::Config {
+            action: ConfigAction::Show,
+        } => {
+            output::display_config(config, settings);
+            Ok(())
+        }
+    }
+}
+
 async fn run_async(cli: Cli) -> WtgResult<()> {
-    // Parse the input to determine if it's a remote repo or local
-    let parsed_input = cli.parse_input()?;
-    log::debug!("Parsed input: {parsed_input:?}");
-
-    // Create notice callback - all notices (capability warnings and operational info)
-    // are delivered via callback and printed by output::print_notice
-    let notice_cb = Arc::new(output::print_notice);
-
-    // Create the backend based on available resources
-    log::debug!("Resolving backend (fetch={})", cli.fetch);
-    let backend = resolve_backend_with_notices(&parsed_input, cli.fetch, notice_cb)?;
-    log::debug!("Backend resolved");
-
-    // Build the release filter from CLI args
-    let filter = if let Some(ref release) = cli.release {
-        ReleaseFilter::Specific(release.clone())
-    } else if cli.skip_prereleases {
-        ReleaseFilter::SkipPrereleases
-    } else {
-        ReleaseFilter::Unrestricted
-    };
+    if cli.is_batch() {
+        return run_batch(&cli).await;
+    }
+
+    match cli.format() {
+        OutputFormat::Human => {
+            // All notices (capability warnings and operational info) are
+            // delivered via callback and printed by output::print_notice
+            let (result, filter) = run_query(&cli, Arc::new(output::print_notice)).await?;
+            output::display(result, &filter)
+        }
+        OutputFormat::Json => {
+            let (notice_cb, notices) = collecting_notices();
+            let outcome = run_query(&cli, notice_cb).await;
+            let notices = take_notices(&notices);
+
+            match outcome {
+                Ok((result, filter)) => output::display_json(&result, &filter, &notices),
+                Err(err) => {
+                    // Still one document, and still a failing exit code
+                    output::display_json_error(&err, &notices)?;
+                    Err(err)
+                }
+            }
+        }
+    }
+}
+
+/// Resolve every batch query with shared backends and print results in input order.
+async fn run_batch(cli: &Cli) -> WtgResult<()> {
+    if cli.history_page().is_some() {
+        return Err(WtgError::Cli {
+            message: "--history shows one file at a time and can't be combined with --batch"
+                .to_string(),
+            code: 2,
+        });
+    }
+    let inputs = cli.batch_inputs()?;
+    if inputs.is_empty() {
+        return Err(WtgError::EmptyInput);
+    }
+    let total = inputs.len();
+    let mut failed = 0;
 
-    // Resolve the query using the backend
-    log::debug!("Disambiguating query: {:?}", parsed_input.query());
-    let query = backend.disambiguate_query(parsed_input.query()).await?;
-    log::debug!("Disambiguated to: {query:?}");
+    match cli.format() {
+        OutputFormat::Human => {
+            let wtg = client(cli, Arc::new(output::print_notice))?;
+            let filter = wtg.release_filter();
+            let mut entries = wtg.resolve_batch(inputs, cli.jobs).enumerate();
 
-    log::debug!("Resolving query");
-    let result = resolve(backend.as_ref(), &query, &filter).await?;
-    log::debug!("Resolution complete");
+            // Print each entry as soon as it (and everything before it) is done
+            while let Some((index, entry)) = entries.next().await {
+                failed += usize::from(entry.outcome.is_err());
+                output::display_batch_entry(entry, filter, index == 0)?;
+            }
+        }
+        OutputFormat::Json => {
+            let (notice_cb, notices) = collecting_notices();
+            let wtg = client(cli, notice_cb)?;
+            let entries: Vec<_> = wtg.resolve_batch(inputs, cli.jobs).collect().await;
 
-    // Display the result
-    output::display(result, &filter)?;
+            failed = entries
+                .iter()
+                .filter(|entry| entry.outcome.is_err())
+                .count();
+            output::display_batch_json(&entries, wtg.release_filter(), &take_notices(&notices))?;
+        }
+    }
 
+    if failed > 0 {
+        return Err(WtgError::BatchFailed { failed, total });
+    }
     Ok(())
 }
+
+/// A notice callback that collects notices instead of printing them,
+/// so they end up in the JSON document instead of stderr.
+fn collecting_notices() -> (NoticeCallback, Arc<Mutex<Vec<Notice>>>) {
+    let notices: Arc<Mutex<Vec<Notice>>> = Arc::default();
+    let sink = Arc::clone(&notices);
+    let notice_cb: NoticeCallback = Arc::new(move |notice| {
+        if let Ok(mut notices) = sink.lock() {
+            notices.push(notice);
+        }
+    });
+    (notice_cb, notices)
+}
+
+fn take_notices(notices: &Mutex<Vec<Notice>>) -> Vec<Notice> {
+    notices
+        .lock()
+        .map(|mut notices| std::mem::take(&mut *notices))
+        .unwrap_or_default()
+}
+
+/// Build the release filter from CLI args.
+fn release_filter(cli: &Cli) -> WtgResult<ReleaseFilter> {
+    Ok(cli
+        .release()
+        .map_or_else(
+            || ReleaseFilter::default().skip_prereleases(cli.skip_prereleases),
+            ReleaseFilter::specific,
+        )
+        .tag_prefix(cli.tag_prefix.clone())
+        .tag_patterns(cli.tag_patterns()?)
+        .require_release(cli.require_release)
+        .release_lines(cli.all_releases))
+}
+
+/// Build the library client from CLI args, delivering notices to `notice_cb`.
+fn client(cli: &Cli, notice_cb: NoticeCallback) -> WtgResult<Wtg> {
+    let mut builder = Wtg::builder()
+        .forge_hosts(cli.forge_hosts.clone())
+        .fetch(cli.fetch)
+        .release_filter(release_filter(cli)?)
+        .cache(!cli.no_cache)
+        .cache_ttl(cli.cache_ttl)
+        .notice_callback(notice_cb);
+    if let Some(repo) = &cli.repo {
+        builder = builder.repo(repo);
+    }
+    if let Some(name) = &cli.remote {
+        builder = builder.preferred_remote(name);
+    }
+    if let Some(path) = &cli.changelog {
+        builder = builder.changelog_path(path);
+    }
+    if let Some(branch_filter) = cli.branch_filter()? {
+        builder = builder.branch_filter(branch_filter);
+    }
+    if let Some(dir) = &cli.record {
+        builder = builder.recording(Arc::new(Recording::record(dir)?));
+    } else if let Some(dir) = &cli.replay {
+        builder = builder.recording(Arc::new(Recording::replay(dir)?));
+    }
+    // Ask rather than guess when someone's there to answer, one query at a time
+    if !cli.is_batch() && picker::is_interactive() {
+        builder = builder.on_ambiguous(Arc::new(pick_candidate));
+    }
+    builder.build()
+}
+
+fn pick_candidate(input: &str, mut candidates: Vec<Candidate>) -> WtgResult<Query> {
+    match picker::pick(input, &candidates)? {
+        Some(index) => Ok(candidates.swap_remove(index).query),
+        None => Err(WtgError::MultipleMatches(candidates)),
+    }
+}
+
+/// Resolve the CLI query, delivering notices to `notice_cb`.
+async fn run_query(
+    cli: &Cli,
+    notice_cb: NoticeCallback,
+) -> WtgResult<(IdentifiedThing, ReleaseFilter)> {
+    let input = cli.query_input()?;
+    let wtg = client(cli, notice_cb)?;
+    let result = match cli.history_page() {
+        Some(page) => wtg.file_history(input, page).await?,
+        None => wtg.resolve(input).await?,
+    };
+    Ok((result, wtg.release_filter().clone()))
+}
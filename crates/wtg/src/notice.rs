@@ -4,11 +4,16 @@
 
 use std::sync::Arc;
 
+use serde::Serialize;
+
 use crate::remote::{RemoteHost, RemoteInfo};
 
 /// Notices emitted during backend/git operations.
 /// All notices are delivered via callback - the CLI layer decides how to display them.
-#[derive(Debug, Clone)]
+///
+/// Serializes as an object tagged by `kind` (e.g. `{"kind": "gh_rate_limit_hit", "authenticated": false}`).
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum Notice {
     // --- Backend capability notices ---
@@ -27,6 +32,7 @@ pub enum Notice {
         count: usize,
     },
     /// GitHub remote found but API client couldn't be created
+    #[serde(rename = "unreachable_github")]
     UnreachableGitHub {
         /// The GitHub remote we found
         remote: RemoteInfo,
@@ -65,6 +71,20 @@ pub enum Notice {
         /// The error message from the failed anonymous request
         error: String,
     },
+    /// Too many branches to check via the API; only the first ones were checked
+    BranchCheckLimited {
+        /// Number of branches checked
+        checked: usize,
+        /// Number of branches matching the branch filter
+        total: usize,
+    },
+    /// Too many commits in a release range to look up each one's PR; only the newest were
+    RangePrLookupLimited {
+        /// Number of commits looked up
+        checked: usize,
+        /// Number of commits in the range
+        total: usize,
+    },
     /// Cross-project PR commit fetch failed (e.g., due to rate limits or auth issues)
     CrossProjectPrFetchFailed {
         /// Owner of the cross-project repo
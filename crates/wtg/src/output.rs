@@ -1,23 +1,358 @@
 use std::collections::HashSet;
+use std::io::{self, Write};
+use std::path::Path;
 
 use crossterm::style::Stylize;
 use octocrab::models::IssueState;
+use serde::{Deserialize, Serialize};
 
-use crate::error::WtgResult;
-use crate::git::{CommitInfo, TagInfo};
+use crate::batch::BatchEntry;
+use crate::config::{Config, ConfigLayer, Settings, Source};
+use crate::error::{WtgError, WtgResult};
+use crate::git::{BranchInfo, CommitInfo, TagInfo};
 use crate::github::PullRequestInfo;
 use crate::notice::Notice;
 use crate::release_filter::ReleaseFilter;
+use crate::release_notes::ChangeCategory;
 use crate::remote::{RemoteHost, RemoteInfo};
 use crate::resolution::{
-    ChangesSource, EnrichedInfo, EntryPoint, FileResult, IdentifiedThing, IssueInfo, TagResult,
+    ChangesSource, CommitOrigin, EnrichedInfo, EntryPoint, FileHistoryResult, FileResult,
+    IdentifiedThing, IssueInfo, LinesResult, PathsResult, RangeResult, ReleaseLine, ReleaseNote,
+    TagResult,
 };
 
+/// Branches listed before the rest are summarized as "and N more".
+const MAX_BRANCHES_SHOWN: usize = 10;
+
+/// Version of the JSON document emitted by `--format json`.
+/// Bumped whenever a field is removed or changes meaning.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// How results are rendered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Colorful, human-friendly prose
+    #[default]
+    Human,
+    /// A single JSON document on stdout
+    Json,
+}
+
+/// The top-level JSON document printed by `--format json`.
+#[derive(Debug, Serialize)]
+pub struct JsonReport<'a> {
+    pub schema_version: u32,
+    /// The specific release checked against (the `RELEASE` argument), if any.
+    /// When set, `release` in the result is `null` unless the commit is in it.
+    pub release_check: Option<&'a str>,
+    pub result: &'a IdentifiedThing,
+    pub notices: &'a [Notice],
+}
+
+impl<'a> JsonReport<'a> {
+    #[must_use]
+    pub fn new(
+        result: &'a IdentifiedThing,
+        filter: &'a ReleaseFilter,
+        notices: &'a [Notice],
+    ) -> Self {
+        Self {
+            schema_version: JSON_SCHEMA_VERSION,
+            release_check: filter.specific_tag(),
+            result,
+            notices,
+        }
+    }
+}
+
+/// Print the result and collected notices as one JSON document on stdout.
+pub fn display_json(
+    thing: &IdentifiedThing,
+    filter: &ReleaseFilter,
+    notices: &[Notice],
+) -> WtgResult<()> {
+    let mut stdout = io::stdout().lock();
+    serde_json::to_writer_pretty(&mut stdout, &JsonReport::new(thing, filter, notices))
+        .map_err(io::Error::from)?;
+    writeln!(stdout)?;
+    Ok(())
+}
+
+/// The JSON document printed by `--format json` when the query fails.
+#[derive(Debug, Serialize)]
+pub struct JsonErrorReport<'a> {
+    pub schema_version: u32,
+    pub error: JsonError,
+    pub notices: &'a [Notice],
+}
+
+/// A failed query in JSON output.
+#[derive(Debug, Serialize)]
+pub struct JsonError {
+    /// `snake_case` error name, e.g. `not_found`
+    pub kind: &'static str,
+    /// Plain-text error message
+    pub message: String,
+}
+
+impl<'a> JsonErrorReport<'a> {
+    #[must_use]
+    pub fn new(err: &WtgError, notices: &'a [Notice]) -> Self {
+        Self {
+            schema_version: JSON_SCHEMA_VERSION,
+            error: JsonError {
+                kind: err.kind(),
+                message: plain_error(err),
+            },
+            notices,
+        }
+    }
+}
+
+/// Print the error and collected notices as one JSON document on stdout.
+pub fn display_json_error(err: &WtgError, notices: &[Notice]) -> WtgResult<()> {
+    let mut stdout = io::stdout().lock();
+    serde_json::to_writer_pretty(&mut stdout, &JsonErrorReport::new(err, notices))
+        .map_err(io::Error::from)?;
+    writeln!(stdout)?;
+    Ok(())
+}
+
+/// The top-level JSON document printed by `--format json` in batch mode.
+#[derive(Debug, Serialize)]
+pub struct BatchJsonReport<'a> {
+    pub schema_version: u32,
+    /// The specific release every query was checked against, if any.
+    pub release_check: Option<&'a str>,
+    /// One entry per query, in input order.
+    pub results: Vec<BatchJsonEntry<'a>>,
+    pub notices: &'a [Notice],
+}
+
+/// One query of a batch: exactly one of `result` and `error` is set.
+#[derive(Debug, Serialize)]
+pub struct BatchJsonEntry<'a> {
+    pub input: &'a str,
+    pub result: Option<&'a IdentifiedThing>,
+    /// Plain-text error message
+    pub error: Option<String>,
+}
+
+impl<'a> BatchJsonReport<'a> {
+    #[must_use]
+    pub fn new(
+        entries: &'a [BatchEntry],
+        filter: &'a ReleaseFilter,
+        notices: &'a [Notice],
+    ) -> Self {
+        let results = entries
+            .iter()
+            .map(|entry| BatchJsonEntry {
+                input: &entry.input,
+                result: entry.outcome.as_ref().ok(),
+                error: entry.outcome.as_ref().err().map(plain_error),
+            })
+            .collect();
+
+        Self {
+            schema_version: JSON_SCHEMA_VERSION,
+            release_check: filter.specific_tag(),
+            results,
+            notices,
+        }
+    }
+}
+
+/// Print batch results and collected notices as one JSON document on stdout.
+pub fn display_batch_json(
+    entries: &[BatchEntry],
+    filter: &ReleaseFilter,
+    notices: &[Notice],
+) -> WtgResult<()> {
+    let mut stdout = io::stdout().lock();
+    serde_json::to_writer_pretty(&mut stdout, &BatchJsonReport::new(entries, filter, notices))
+        .map_err(io::Error::from)?;
+    writeln!(stdout)?;
+    Ok(())
+}
+
+/// Print one batch entry: a header naming the query, then its result or error.
+pub fn display_batch_entry(
+    entry: BatchEntry,
+    filter: &ReleaseFilter,
+    first: bool,
+) -> WtgResult<()> {
+    if !first {
+        println!();
+    }
+    println!(
+        "{} {}",
+        "▶".cyan().bold(),
+        entry.input.as_str().cyan().bold()
+    );
+
+    match entry.outcome {
+        Ok(thing) => display(thing, filter),
+        Err(err) => {
+            eprintln!("{err}");
+            Ok(())
+        }
+    }
+}
+
+/// Report the outcome of `wtg cache clear`.
+pub fn display_cache_cleared(removed: Option<&Path>) {
+    match removed {
+        Some(path) => println!(
+            "{} {}",
+            "🧹 Cleared cached GitHub API responses in".green(),
+            path.display().to_string().dark_grey()
+        ),
+        None => println!(
+            "{}",
+            "🧹 Nothing to clear - the API cache is already empty.".green()
+        ),
+    }
+}
+
+/// Report the output of `wtg config show`: the config files and the effective settings.
+pub fn display_config(config: &Config, settings: &Settings) {
+    println!("{}", "⚙️  Config files".cyan().bold());
+    match (&config.user, &config.user_path) {
+        (Some(layer), _) => print_config_file("user", layer),
+        (None, Some(path)) => println!(
+            "   user: {} {}",
+            path.display().to_string().dark_grey(),
+            "(not found)".dark_grey()
+        ),
+        (None, None) => println!("   user: {}", "(no home directory)".dark_grey()),
+    }
+    match &config.repo {
+        Some(layer) => print_config_file("repo", layer),
+        None => println!("   repo: {}", "(not found)".dark_grey()),
+    }
+    println!();
+
+    let unset = || "-".to_string();
+    println!("{}", "🔧 Effective settings".cyan().bold());
+    print_setting(
+        "skip_prereleases",
+        settings.skip_prereleases.value.to_string(),
+        settings.skip_prereleases.source,
+    );
+    print_setting(
+        "tag_prefix",
+        settings.tag_prefix.value.clone().unwrap_or_else(unset),
+        settings.tag_prefix.source,
+    );
+    for (name, patterns) in [
+        ("include_tags", &settings.include_tags),
+        ("exclude_tags", &settings.exclude_tags),
+    ] {
+        let value = if patterns.value.is_empty() {
+            unset()
+        } else {
+            patterns.value.join(", ")
+        };
+        print_setting(name, value, patterns.source);
+    }
+    print_setting(
+        "require_release",
+        settings.require_release.value.to_string(),
+        settings.require_release.source,
+    );
+    print_setting(
+        "remote",
+        settings.remote.value.clone().unwrap_or_else(unset),
+        settings.remote.source,
+    );
+    print_setting(
+        "format",
+        format!("{:?}", settings.format.value).to_lowercase(),
+        settings.format.source,
+    );
+    print_setting(
+        "changelog",
+        settings
+            .changelog
+            .value
+            .as_ref()
+            .map_or_else(unset, |path| path.display().to_string()),
+        settings.changelog.source,
+    );
+    print_setting(
+        "cache.enabled",
+        settings.cache_enabled.value.to_string(),
+        settings.cache_enabled.source,
+    );
+    print_setting(
+        "cache.ttl_secs",
+        settings.cache_ttl_secs.value.to_string(),
+        settings.cache_ttl_secs.source,
+    );
+    for (name, hosts) in [
+        ("github_hosts", &settings.github_hosts),
+        ("gitea_hosts", &settings.gitea_hosts),
+    ] {
+        if hosts.is_empty() {
+            print_setting(name, unset(), Source::Default);
+        }
+        for host in hosts {
+            print_setting(name, host.value.clone(), host.source);
+        }
+    }
+}
+
+fn print_config_file(label: &str, layer: &ConfigLayer) {
+    println!("   {label}: {}", layer.path.display().to_string().cyan());
+}
+
+fn print_setting(name: &str, value: String, source: Source) {
+    println!(
+        "   {name} = {} {}",
+        value.yellow(),
+        format!("({source})").dark_grey()
+    );
+}
+
+/// Render an error as a single line of plain text (no colors, no line breaks),
+/// as JSON output and the Python bindings report it.
+#[must_use]
+pub fn plain_error(err: &WtgError) -> String {
+    let text = err.to_string();
+    let mut plain = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        // Skip ANSI escape sequences like `\x1b[31m`
+        if c == '\x1b' {
+            for next in chars.by_ref() {
+                if next.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
+        plain.push(c);
+    }
+
+    plain
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 pub fn display(thing: IdentifiedThing, filter: &ReleaseFilter) -> WtgResult<()> {
     match thing {
         IdentifiedThing::Enriched(info) => display_enriched(*info, filter),
         IdentifiedThing::File(file_result) => display_file(*file_result, filter),
+        IdentifiedThing::Paths(paths_result) => display_paths(*paths_result, filter),
+        IdentifiedThing::FileHistory(history) => display_file_history(&history),
+        IdentifiedThing::Lines(lines) => display_lines(&lines, filter),
         IdentifiedThing::Tag(tag_result) => display_tag(&tag_result),
+        IdentifiedThing::Range(range_result) => display_range(&range_result),
     }
 
     Ok(())
@@ -28,6 +363,13 @@ fn display_tag(result: &TagResult) {
     let tag = &result.tag_info;
 
     // Header
+    if let Some(at) = result.current_at {
+        println!(
+            "{} {}",
+            "📆 Current release on".green().bold(),
+            at.format("%Y-%m-%d %H:%M UTC").to_string().cyan()
+        );
+    }
     println!("{} {}", "🏷️  Tag:".green().bold(), tag.name.as_str().cyan());
     println!(
         "{} {}",
@@ -117,6 +459,121 @@ fn display_tag(result: &TagResult) {
     }
 }
 
+/// Display a release range as release notes, grouped by category
+fn display_range(result: &RangeResult) {
+    let (from, to) = (&result.from_tag, &result.to_tag);
+
+    // Header
+    println!(
+        "{} {} {} {}",
+        "📦 Release range:".green().bold(),
+        from.name.as_str().cyan(),
+        "→".dark_grey(),
+        to.name.as_str().cyan()
+    );
+    println!(
+        "{} {} {} {}",
+        "📅 Dates:".yellow(),
+        from.created_at.format("%Y-%m-%d").to_string().dark_grey(),
+        "→".dark_grey(),
+        to.created_at.format("%Y-%m-%d").to_string().dark_grey()
+    );
+    let pr_count = result.notes.iter().filter(|note| note.pr.is_some()).count();
+    println!(
+        "{} {}",
+        "🔢 Shipped:".yellow(),
+        format!(
+            "{} commit{}, {} pull request{}",
+            result.commit_count,
+            plural(result.commit_count),
+            pr_count,
+            plural(pr_count)
+        )
+        .dark_grey()
+    );
+    if let Some(url) = to.release_url.as_ref().or(to.tag_url.as_ref()) {
+        println!(
+            "{} {}",
+            "🔗 Release:".blue(),
+            url.as_str().blue().underlined()
+        );
+    }
+
+    if result.notes.is_empty() {
+        println!();
+        println!(
+            "{}",
+            "Nothing shipped between these tags - same commit?"
+                .dark_grey()
+                .italic()
+        );
+        return;
+    }
+
+    let mut current = None;
+    for note in &result.notes {
+        if current != Some(note.category) {
+            current = Some(note.category);
+            println!();
+            println!("{}", category_heading(note.category).magenta().bold());
+        }
+        display_release_note(note);
+    }
+}
+
+/// Print one release notes bullet: PR (or commit), author and closed issues
+fn display_release_note(note: &ReleaseNote) {
+    let (reference, author) = match (&note.pr, note.commits.first()) {
+        (Some(pr), _) => (
+            format!("#{}", pr.number),
+            pr.author.as_ref().map(|login| format!("@{login}")),
+        ),
+        (None, Some(commit)) => (
+            commit.short_hash.clone(),
+            Some(
+                commit
+                    .author_login
+                    .as_ref()
+                    .map_or_else(|| commit.author_name.clone(), |login| format!("@{login}")),
+            ),
+        ),
+        (None, None) => (String::new(), None),
+    };
+
+    let author = author.map_or_else(String::new, |author| format!(" ({author})"));
+    println!(
+        "• {} {}{}",
+        reference.as_str().cyan(),
+        note.title.as_str().white(),
+        author.as_str().dark_grey()
+    );
+
+    for issue in &note.issues {
+        println!(
+            "  {} {} {}",
+            "↳ closes".dark_grey(),
+            format!("#{}", issue.number).as_str().cyan(),
+            issue.title.as_str().dark_grey()
+        );
+    }
+}
+
+const fn category_heading(category: ChangeCategory) -> &'static str {
+    match category {
+        ChangeCategory::Breaking => "💥 Breaking changes",
+        ChangeCategory::Features => "✨ Features",
+        ChangeCategory::Fixes => "🐛 Bug fixes",
+        ChangeCategory::Performance => "⚡ Performance",
+        ChangeCategory::Documentation => "📚 Documentation",
+        ChangeCategory::Maintenance => "🧹 Maintenance",
+        ChangeCategory::Other => "📦 Other changes",
+    }
+}
+
+const fn plural(count: usize) -> &'static str {
+    if count == 1 { "" } else { "s" }
+}
+
 /// Display enriched info - the main display logic
 /// Order depends on what the user searched for
 fn display_enriched(info: EnrichedInfo, filter: &ReleaseFilter) {
@@ -172,28 +629,107 @@ fn display_enriched(info: EnrichedInfo, filter: &ReleaseFilter) {
             // User searched for commit or something else - lead with commit
             display_identification(&info.entry_point);
             println!();
+            display_commit_first(&info, filter);
+        }
+    }
 
-            if let Some(commit_info) = info.commit.as_ref() {
-                display_commit_section(commit_info, info.pr.as_ref());
-                println!();
-            }
+    if let Some(commit) = &info.commit {
+        display_release_lines(&info.releases, &commit.hash);
+    }
 
-            if let Some(pr) = &info.pr {
-                display_pr_section(pr, false);
-                println!();
-            }
+    if let Some(branches) = &info.branches {
+        println!();
+        display_branches(branches);
+    }
+}
 
-            if let Some(issue) = &info.issue {
-                display_issue_section(issue);
-                println!();
-            }
+/// Display commit, PR, issue and release, in that order
+fn display_commit_first(info: &EnrichedInfo, filter: &ReleaseFilter) {
+    if let Some(commit_info) = info.commit.as_ref() {
+        display_commit_section(commit_info, info.pr.as_ref());
+        println!();
+    }
 
-            display_missing_info(&info);
+    if let Some(pr) = &info.pr {
+        display_pr_section(pr, false);
+        println!();
+    }
 
-            if info.commit.is_some() {
-                display_release_info(info.release, filter);
-            }
+    if let Some(issue) = &info.issue {
+        display_issue_section(issue);
+        println!();
+    }
+
+    display_missing_info(info);
+
+    if info.commit.is_some() {
+        display_release_info(info.release.clone(), filter);
+    }
+}
+
+/// Display every release line shipping the change, if there's more than one
+/// or cherry-picks are involved
+fn display_release_lines(lines: &[ReleaseLine], commit_hash: &str) {
+    let cherry_picked = lines
+        .iter()
+        .any(|line| line.is_backport || line.commit_hash != commit_hash);
+    if !cherry_picked && lines.len() < 2 {
+        return;
+    }
+
+    println!();
+    if cherry_picked {
+        println!("{}", "🍒 Release lines:".magenta().bold());
+    } else {
+        println!("{}", "📦 Release lines:".magenta().bold());
+    }
+    for line in lines {
+        let name = line.release.name.as_str().cyan().bold();
+        if !cherry_picked {
+            println!("   {} {name}", "🏷️ ".yellow());
+            continue;
         }
+
+        let short_hash = &line.commit_hash[..7.min(line.commit_hash.len())];
+        let role = if line.is_backport {
+            format!("backport {short_hash}")
+        } else {
+            format!("original {short_hash}")
+        };
+        println!(
+            "   {} {name} {}",
+            "🏷️ ".yellow(),
+            format!("({role})").dark_grey()
+        );
+    }
+}
+
+/// Display the branches that contain the commit
+fn display_branches(branches: &[BranchInfo]) {
+    println!("{}", "🌿 On branches:".magenta().bold());
+
+    if branches.is_empty() {
+        println!(
+            "   {}",
+            "🚧 Not on any (matching) branch yet!".yellow().italic()
+        );
+        return;
+    }
+
+    for branch in branches.iter().take(MAX_BRANCHES_SHOWN) {
+        if branch.is_remote {
+            println!("   {} {}", "•".dark_grey(), branch.name.as_str().blue());
+        } else {
+            println!("   {} {}", "•".dark_grey(), branch.name.as_str().cyan());
+        }
+    }
+
+    let hidden = branches.len().saturating_sub(MAX_BRANCHES_SHOWN);
+    if hidden > 0 {
+        println!(
+            "   {}",
+            format!("... and {hidden} more (narrow it down with --branch <GLOB>)").dark_grey()
+        );
     }
 }
 
@@ -229,6 +765,19 @@ fn display_identification(entry_point: &EntryPoint) {
                 branch.clone().cyan()
             );
         }
+        EntryPoint::FileLines {
+            branch,
+            path,
+            lines,
+        } => {
+            println!(
+                "{} {}:{}@{}",
+                "🔬 Found lines:".green().bold(),
+                path.as_str().cyan(),
+                lines.to_string().cyan(),
+                branch.clone().cyan()
+            );
+        }
         EntryPoint::Tag(tag) => {
             println!(
                 "{} {}",
@@ -512,6 +1061,19 @@ fn display_file(file_result: FileResult, filter: &ReleaseFilter) {
 
     println!();
 
+    // Earlier names, most recent first
+    if !info.renames.is_empty() {
+        println!("{}", "🚚 Formerly known as:".yellow().bold());
+        for rename in &info.renames {
+            println!(
+                "   → {} {}",
+                rename.from.as_str().cyan(),
+                format!("(renamed in {})", &rename.commit_hash[..7]).dark_grey()
+            );
+        }
+        println!();
+    }
+
     // Previous authors - snarky hall of shame (deduplicated)
     if !info.previous_authors.is_empty() {
         // Deduplicate authors - track who we've seen
@@ -552,6 +1114,187 @@ fn display_file(file_result: FileResult, filter: &ReleaseFilter) {
     display_release_info(file_result.release, filter);
 }
 
+/// How many changed files to list for a directory or glob before summarizing the rest
+const MAX_CHANGED_PATHS: usize = 10;
+
+fn display_paths(paths_result: PathsResult, filter: &ReleaseFilter) {
+    let info = paths_result.paths_info;
+
+    println!(
+        "{} {}",
+        "📂 Found files:".green().bold(),
+        info.pattern.cyan()
+    );
+    println!();
+
+    display_commit_section(&info.last_commit, None);
+    println!();
+
+    println!("{}", "📝 Touched:".yellow().bold());
+    for path in info.changed_paths.iter().take(MAX_CHANGED_PATHS) {
+        println!("   → {}", path.as_str().cyan());
+    }
+    if info.changed_paths.len() > MAX_CHANGED_PATHS {
+        println!(
+            "   {}",
+            format!(
+                "... and {} more",
+                info.changed_paths.len() - MAX_CHANGED_PATHS
+            )
+            .dark_grey()
+        );
+    }
+    println!();
+
+    println!(
+        "{} {}",
+        "🏆 Top contributors".yellow().bold(),
+        format!("(since {})", info.window_start.format("%Y-%m-%d")).dark_grey()
+    );
+    for (idx, contributor) in info.top_contributors.iter().enumerate() {
+        let commits = if contributor.commits == 1 {
+            "1 commit".to_string()
+        } else {
+            format!("{} commits", contributor.commits)
+        };
+        print!(
+            "   → {} {}",
+            contributor.name.as_str().cyan(),
+            format!("({commits})").dark_grey()
+        );
+
+        if let Some(Some(url)) = paths_result.contributor_urls.get(idx) {
+            print!(" {} {}", "🔗".blue(), url.as_str().blue().underlined());
+        }
+
+        println!();
+    }
+    println!();
+
+    display_release_info(paths_result.release, filter);
+}
+
+/// Display a page of a file's history, one bullet per commit
+fn display_file_history(result: &FileHistoryResult) {
+    println!(
+        "{} {} {}",
+        "📜 History of".green().bold(),
+        result.path.as_str().cyan(),
+        format!("(page {})", result.page.page).dark_grey()
+    );
+
+    if result.entries.is_empty() {
+        println!();
+        println!(
+            "{}",
+            "Nothing this far back - you've reached the dawn of time."
+                .dark_grey()
+                .italic()
+        );
+        return;
+    }
+
+    for entry in &result.entries {
+        println!();
+        print_origin(&entry.origin);
+        if entry.path != result.path {
+            println!(
+                "   {} {}",
+                "🚚 Known back then as".dark_grey(),
+                entry.path.as_str().cyan()
+            );
+        }
+    }
+
+    if result.has_more {
+        println!();
+        println!(
+            "{}",
+            format!("👉 Older changes: --page {}", result.page.page + 1)
+                .dark_grey()
+                .italic()
+        );
+    }
+}
+
+/// Display who introduced some lines of a file, hunk by hunk
+fn display_lines(result: &LinesResult, filter: &ReleaseFilter) {
+    println!(
+        "{} {}{}{}",
+        "🔬 Blaming".green().bold(),
+        result.path.as_str().cyan(),
+        ":".dark_grey(),
+        result.lines.to_string().cyan()
+    );
+
+    for hunk in &result.hunks {
+        println!();
+        let range = if hunk.start_line == hunk.end_line {
+            format!("📍 Line {}", hunk.start_line)
+        } else {
+            format!("📍 Lines {}-{}", hunk.start_line, hunk.end_line)
+        };
+        println!("{}", range.yellow().bold());
+        for (number, line) in (hunk.start_line..).zip(&hunk.lines) {
+            println!(
+                "   {} {}",
+                format!("{number:>5} │").dark_grey(),
+                line.as_str().white()
+            );
+        }
+        if hunk.path != result.path {
+            println!(
+                "   {} {}",
+                "🚚 Written in".dark_grey(),
+                hunk.path.as_str().cyan()
+            );
+        }
+        println!();
+
+        display_commit_first(&hunk.info, filter);
+        if let Some(commit) = &hunk.info.commit {
+            display_release_lines(&hunk.info.releases, &commit.hash);
+        }
+    }
+}
+
+/// Print a commit with its PR and release on a few compact lines
+fn print_origin(origin: &CommitOrigin) {
+    let commit = &origin.commit;
+    let author = commit
+        .author_login
+        .as_ref()
+        .map_or_else(|| commit.author_name.clone(), |login| format!("@{login}"));
+    println!(
+        "{} {} {} {}",
+        "•".dark_grey(),
+        commit.short_hash.as_str().cyan(),
+        commit.message.as_str().white(),
+        format!("({author}, {})", commit.date.format("%Y-%m-%d")).dark_grey()
+    );
+
+    if let Some(pr) = &origin.pr {
+        println!(
+            "   {} {} {}",
+            "🔀".magenta(),
+            format!("#{}", pr.number).cyan(),
+            pr.title.as_str().white()
+        );
+        print_link(&pr.url);
+    } else if let Some(url) = &commit.commit_url {
+        print_link(url);
+    }
+
+    match &origin.release {
+        Some(release) => println!(
+            "   {} {}",
+            "📦 Shipped in".dark_grey(),
+            release.name.as_str().green()
+        ),
+        None => println!("   {}", "📦 Not released yet".dark_grey().italic()),
+    }
+}
+
 fn display_release_info(release: Option<TagInfo>, filter: &ReleaseFilter) {
     // Special messaging when checking a specific release
     if let Some(tag_name) = filter.specific_tag() {
@@ -649,6 +1392,14 @@ fn display_unsupported_host(remote: &RemoteInfo) {
                     .italic()
             );
         }
+        Some(RemoteHost::Gitea) => {
+            eprintln!(
+                "{}",
+                "🍵 Gitea! Brewing your own forge, how artisanal..."
+                    .yellow()
+                    .italic()
+            );
+        }
         Some(RemoteHost::GitHub) => {
             // Shouldn't happen, but handle gracefully
             return;
@@ -679,6 +1430,7 @@ fn display_mixed_remotes(hosts: &[RemoteHost], count: usize) {
             RemoteHost::GitHub => "GitHub",
             RemoteHost::GitLab => "GitLab",
             RemoteHost::Bitbucket => "Bitbucket",
+            RemoteHost::Gitea => "Gitea",
         })
         .collect();
 
@@ -849,6 +1601,16 @@ pub fn print_notice(notice: Notice) {
                     .italic()
             );
         }
+        Notice::BranchCheckLimited { checked, total } => {
+            eprintln!(
+                "⚠️  {total} branches to check via the API, only looked at the first {checked} (narrow it down with --branch <GLOB>)"
+            );
+        }
+        Notice::RangePrLookupLimited { checked, total } => {
+            eprintln!(
+                "⚠️  {total} commits in the range, only looked up PRs for the newest {checked} (the rest are listed by commit message)"
+            );
+        }
         Notice::CrossProjectPrFetchFailed {
             owner,
             repo,
@@ -1,11 +1,17 @@
+use std::fmt;
 use std::path::{Path, PathBuf};
 
+use chrono::{DateTime, NaiveDate, Utc};
 use percent_encoding::percent_decode_str;
+use serde::Serialize;
 use url::Url;
 
 use crate::{
+    bitbucket::{BbRepoInfo, is_bitbucket_host},
     error::{WtgError, WtgResult},
-    github::GhRepoInfo,
+    gitea::GtRepoInfo,
+    github::{GITHUB_COM, GhRepoInfo},
+    remote::ForgeHosts,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -20,28 +26,138 @@ pub enum Query {
     Pr(u64),
     /// A file path within the repository
     FilePath { branch: String, path: PathBuf },
+    /// Some lines of a file (`src/lib.rs:120-140`)
+    FileLines {
+        branch: String,
+        path: PathBuf,
+        lines: LineRange,
+    },
+    /// Files under a directory or matching a glob (`crates/*/Cargo.toml`);
+    /// directories are stored as `dir/**`
+    PathGlob { branch: String, pattern: String },
     /// A git tag name
     Tag(String),
+    /// Everything shipped after `from` up to and including `to` (`v1.2.0..v1.3.0`)
+    TagRange { from: String, to: String },
+    /// The newest release published at a point in time (`@2026-03-14`)
+    ReleaseAt(DateTime<Utc>),
+    /// Everything shipped between the releases current at two points in time
+    /// (`@2026-01-01..@2026-03-14`)
+    DateRange {
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    },
+}
+
+/// One way to read input that could mean several things (`1234abc` can be a
+/// branch, a file and a commit at the same time).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidate {
+    /// What the input resolves to when read this way
+    pub query: Query,
+    /// What kind of thing matched: `tag`, `branch`, `file`, `commit`, ...
+    pub kind: &'static str,
+    /// A short preview, such as the commit subject
+    pub preview: String,
+}
+
+impl fmt::Display for Candidate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.kind, self.preview)
+    }
+}
+
+/// An inclusive, 1-based range of lines in a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LineRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl LineRange {
+    /// Parse `42` or `120-140`. Line numbers start at 1 and the range can't be reversed.
+    #[must_use]
+    pub fn parse(input: &str) -> Option<Self> {
+        let (start, end) = input.split_once('-').unwrap_or((input, input));
+        let (start, end) = (start.parse().ok()?, end.parse().ok()?);
+        (start > 0 && start <= end).then_some(Self { start, end })
+    }
+}
+
+impl fmt::Display for LineRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start)
+        } else {
+            write!(f, "{}-{}", self.start, self.end)
+        }
+    }
+}
+
+/// Split `path:42` or `path:120-140` into the path and its line range.
+#[must_use]
+pub fn split_line_range(input: &str) -> Option<(&str, LineRange)> {
+    let (path, lines) = input.rsplit_once(':')?;
+    if path.is_empty() {
+        return None;
+    }
+    Some((path, LineRange::parse(lines)?))
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParsedQuery {
     Resolved(Query),
     Unknown(String),
-    UnknownPath { segments: Vec<String> },
+    /// A branch and file path that can't be told apart without the repo,
+    /// optionally narrowed to some lines (`#L42-L50`)
+    UnknownPath {
+        segments: Vec<String>,
+        lines: Option<LineRange>,
+    },
 }
 
-/// Parsed input that can come from either the input argument or a GitHub URL
+/// A repository named explicitly by a URL or the `-r` flag.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RemoteRepo {
+    GitHub(GhRepoInfo),
+    Gitea(GtRepoInfo),
+    Bitbucket(BbRepoInfo),
+}
+
+impl RemoteRepo {
+    /// Parse a repository URL, or `owner/repo` for GitHub, as given to `-r`.
+    pub fn parse(repo_url: &str, hosts: &ForgeHosts) -> WtgResult<Self> {
+        let repo_url = repo_url.trim();
+        if let Some((repo_info, _)) = parse_gitea_url(repo_url, hosts) {
+            return Ok(Self::Gitea(repo_info));
+        }
+        if let Some((repo_info, _)) = parse_bitbucket_url(repo_url) {
+            return Ok(Self::Bitbucket(repo_info));
+        }
+        parse_github_repo_url(repo_url, hosts)
+            .map(Self::GitHub)
+            .ok_or_else(|| WtgError::MalformedGitHubUrl(repo_url.to_string()))
+    }
+}
+
+/// Parsed input that can come from either the input argument or a forge URL
 #[derive(Debug, Clone)]
 pub struct ParsedInput {
-    gh_repo_info: Option<GhRepoInfo>,
+    remote_repo: Option<RemoteRepo>,
     query: ParsedQuery,
 }
 
 impl ParsedInput {
     const fn new_with_remote(gh_repo_info: GhRepoInfo, query: ParsedQuery) -> Self {
         Self {
-            gh_repo_info: Some(gh_repo_info),
+            remote_repo: Some(RemoteRepo::GitHub(gh_repo_info)),
+            query,
+        }
+    }
+
+    const fn new_with_remote_repo(remote_repo: RemoteRepo, query: ParsedQuery) -> Self {
+        Self {
+            remote_repo: Some(remote_repo),
             query,
         }
     }
@@ -50,14 +166,39 @@ impl ParsedInput {
     #[must_use]
     pub const fn new_local_query(query: ParsedQuery) -> Self {
         Self {
-            gh_repo_info: None,
+            remote_repo: None,
             query,
         }
     }
 
+    /// The repository the input points at, if any.
+    #[must_use]
+    pub const fn remote_repo(&self) -> Option<&RemoteRepo> {
+        self.remote_repo.as_ref()
+    }
+
     #[must_use]
     pub const fn gh_repo_info(&self) -> Option<&GhRepoInfo> {
-        self.gh_repo_info.as_ref()
+        match &self.remote_repo {
+            Some(RemoteRepo::GitHub(repo_info)) => Some(repo_info),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn gitea_repo_info(&self) -> Option<&GtRepoInfo> {
+        match &self.remote_repo {
+            Some(RemoteRepo::Gitea(repo_info)) => Some(repo_info),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn bb_repo_info(&self) -> Option<&BbRepoInfo> {
+        match &self.remote_repo {
+            Some(RemoteRepo::Bitbucket(repo_info)) => Some(repo_info),
+            _ => None,
+        }
     }
 
     #[must_use]
@@ -68,13 +209,13 @@ impl ParsedInput {
     #[cfg(test)]
     #[must_use]
     fn owner(&self) -> Option<&str> {
-        self.gh_repo_info.as_ref().map(GhRepoInfo::owner)
+        self.gh_repo_info().map(GhRepoInfo::owner)
     }
 
     #[cfg(test)]
     #[must_use]
     fn repo(&self) -> Option<&str> {
-        self.gh_repo_info.as_ref().map(GhRepoInfo::repo)
+        self.gh_repo_info().map(GhRepoInfo::repo)
     }
 }
 
@@ -92,20 +233,20 @@ impl ParsedInput {
 /// - `Ok(ParsedInput)` if it's a valid GitHub URL
 /// - `Err(NotGitHubUrl)` if it's a valid URL but not GitHub
 /// - `Err(MalformedGitHubUrl)` if it's a GitHub URL but malformed
-fn try_parse_input_from_github_url(url: &str) -> Result<ParsedInput, WtgError> {
+fn try_parse_input_from_github_url(url: &str, hosts: &ForgeHosts) -> Result<ParsedInput, WtgError> {
     debug_assert!(
         reject_control_chars(url).is_ok(),
         "URL should be validated before parsing"
     );
 
     // Try SSH format first
-    if let Some(segments) = parse_git_ssh_segments(url) {
-        return parsed_input_from_segments(&segments, false, url);
+    if let Some(gh_url) = parse_git_ssh_segments(url, hosts) {
+        return parsed_input_from_segments(&gh_url, url);
     }
 
     // Try HTTP/HTTPS format
-    match parse_http_github_segments(url) {
-        Ok((segments, is_api)) => parsed_input_from_segments(&segments, is_api, url),
+    match parse_http_github_segments(url, hosts) {
+        Ok(gh_url) => parsed_input_from_segments(&gh_url, url),
         Err(e) => Err(e),
     }
 }
@@ -120,40 +261,122 @@ fn parse_query(input: &str) -> ParsedQuery {
         return ParsedQuery::Resolved(Query::IssueOrPr(number));
     }
 
+    // GitLab-style merge request reference (`!123`)
+    if let Some(stripped) = input.strip_prefix('!')
+        && let Ok(number) = stripped.parse()
+    {
+        return ParsedQuery::Resolved(Query::Pr(number));
+    }
+
+    // Release by date (`@2026-03-14`) or between two dates (`@2026-01-01..@2026-03-14`)
+    if let Some(query) = parse_date_query(input) {
+        return ParsedQuery::Resolved(query);
+    }
+
+    // Release range (`v1.2.0..v1.3.0`, or `...` as in GitHub compare URLs)
+    if let Some(query) = parse_tag_range(input) {
+        return ParsedQuery::Resolved(query);
+    }
+
     // Otherwise we have to treat as unknown, since path & branches
     // may look the same, and other git refs may be indistinguishable
     // from commit hashes without querying the repo
     ParsedQuery::Unknown(input.to_string())
 }
 
+/// Parse `from..to` or `from...to` into a tag range query.
+fn parse_tag_range(input: &str) -> Option<Query> {
+    let (from, to) = input.split_once("..")?;
+    let to = to.strip_prefix('.').unwrap_or(to);
+
+    let is_ref = |name: &str| {
+        !name.is_empty()
+            && !name.starts_with('.')
+            && !name.contains("..")
+            && !name.chars().any(char::is_whitespace)
+    };
+    if !is_ref(from) || !is_ref(to) {
+        return None;
+    }
+
+    Some(Query::TagRange {
+        from: from.to_string(),
+        to: to.to_string(),
+    })
+}
+
+/// Parse `@date` or `@date..@date` into a date query.
+fn parse_date_query(input: &str) -> Option<Query> {
+    let date = input.strip_prefix('@')?;
+    match date.split_once("..") {
+        Some((from, to)) => Some(Query::DateRange {
+            from: parse_date(from)?,
+            to: parse_date(to.strip_prefix('@')?)?,
+        }),
+        None => parse_date(date).map(Query::ReleaseAt),
+    }
+}
+
+/// Parse an RFC 3339 timestamp, or a `YYYY-MM-DD` date meaning the end of that day (UTC).
+fn parse_date(input: &str) -> Option<DateTime<Utc>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(input) {
+        return Some(time.with_timezone(&Utc));
+    }
+    let date = NaiveDate::parse_from_str(input, "%Y-%m-%d").ok()?;
+    Some(date.and_hms_opt(23, 59, 59)?.and_utc())
+}
+
 /// Parse user input into a structured query, optionally with an explicit repo URL.
 ///
 /// This is the main entry point for parsing CLI input. It handles:
 /// - GitHub URLs (issues, PRs, commits, files)
 /// - Local queries (commit hashes, tags, file paths)
 /// - Explicit repo + query combinations via the `-r` flag
+///
+/// Self-hosted forges are those of [`ForgeHosts::from_env`].
 pub fn try_parse_input(raw_input: &str, repo_url: Option<&str>) -> Result<ParsedInput, WtgError> {
-    // Trim and validate input upfront
-    let input = raw_input.trim();
-    if input.is_empty() {
-        return Err(WtgError::EmptyInput);
-    }
-    let input = reject_control_chars(input)?;
+    try_parse_input_with_hosts(raw_input, repo_url, &ForgeHosts::from_env())
+}
+
+/// Parse user input like [`try_parse_input`], recognizing the self-hosted forges in `hosts`.
+pub fn try_parse_input_with_hosts(
+    raw_input: &str,
+    repo_url: Option<&str>,
+    hosts: &ForgeHosts,
+) -> Result<ParsedInput, WtgError> {
+    let input = clean_input(raw_input)?;
 
     // If repo url is explicitly provided, use it as the repo and input as the query
     if let Some(repo_url) = repo_url {
-        let repo_info = parse_github_repo_url(repo_url)
-            .ok_or_else(|| WtgError::MalformedGitHubUrl(repo_url.to_string()))?;
-        return Ok(ParsedInput::new_with_remote(repo_info, parse_query(input)));
+        let repo = RemoteRepo::parse(repo_url, hosts)?;
+        return Ok(ParsedInput::new_with_remote_repo(repo, parse_query(input)));
+    }
+
+    // URLs on a known Gitea/Forgejo host
+    if let Some((repo_info, segments)) = parse_gitea_url(input, hosts) {
+        let query = parse_gitea_route(&segments, input)?;
+        return Ok(ParsedInput::new_with_remote_repo(
+            RemoteRepo::Gitea(repo_info),
+            query,
+        ));
+    }
+
+    // Bitbucket Cloud URLs
+    if let Some((repo_info, segments)) = parse_bitbucket_url(input) {
+        let query = parse_bitbucket_route(&segments, input)?;
+        return Ok(ParsedInput::new_with_remote_repo(
+            RemoteRepo::Bitbucket(repo_info),
+            query,
+        ));
     }
 
     // Try to parse input as a GitHub URL
-    match try_parse_input_from_github_url(input) {
+    match try_parse_input_from_github_url(input, hosts) {
         Ok(parsed) => Ok(parsed),
         Err(WtgError::NotGitHubUrl(_) | WtgError::MalformedGitHubUrl(_)) => {
             // If it looks like a URL attempt but failed, propagate the error
             if is_url_like(input) {
-                Err(try_parse_input_from_github_url(input).unwrap_err())
+                Err(try_parse_input_from_github_url(input, hosts).unwrap_err())
             } else {
                 // Not a URL, treat as a local query
                 Ok(ParsedInput::new_local_query(parse_query(input)))
@@ -163,6 +386,27 @@ pub fn try_parse_input(raw_input: &str, repo_url: Option<&str>) -> Result<Parsed
     }
 }
 
+/// Parse user input as a query against a known repository.
+///
+/// Unlike [`try_parse_input`], URLs in the input aren't followed: the input is
+/// always a query (commit, tag, file, ...) in `repo`.
+pub fn try_parse_input_in(raw_input: &str, repo: &RemoteRepo) -> WtgResult<ParsedInput> {
+    let input = clean_input(raw_input)?;
+    Ok(ParsedInput::new_with_remote_repo(
+        repo.clone(),
+        parse_query(input),
+    ))
+}
+
+/// Trim and validate input upfront.
+fn clean_input(raw_input: &str) -> WtgResult<&str> {
+    let input = raw_input.trim();
+    if input.is_empty() {
+        return Err(WtgError::EmptyInput);
+    }
+    reject_control_chars(input)
+}
+
 /// Check if input looks like a URL attempt (has scheme or domain-like pattern)
 fn is_url_like(input: &str) -> bool {
     let trimmed = input.trim().to_ascii_lowercase();
@@ -181,19 +425,21 @@ fn is_url_like(input: &str) -> bool {
 /// - <https://www.github.com/owner/repo>
 /// - <https://api.github.com/repos/owner/repo>
 /// - <git@github.com:owner/repo.git>
+/// - the same forms on a GitHub Enterprise host in `hosts`
+///   (API URLs look like <https://ghe.example.com/api/v3/repos/owner/repo>)
 #[must_use]
-pub(crate) fn parse_github_repo_url(url: &str) -> Option<GhRepoInfo> {
+pub(crate) fn parse_github_repo_url(url: &str, hosts: &ForgeHosts) -> Option<GhRepoInfo> {
     let trimmed = url.trim();
     if trimmed.is_empty() {
         return None;
     }
 
-    if let Some(segments) = parse_git_ssh_segments(trimmed) {
-        return owner_repo_from_segments(&segments, false);
+    if let Some(gh_url) = parse_git_ssh_segments(trimmed, hosts) {
+        return owner_repo_from_segments(&gh_url);
     }
 
-    if let Ok((segments, is_api)) = parse_http_github_segments(trimmed)
-        && let Some(owner_repo) = owner_repo_from_segments(&segments, is_api)
+    if let Ok(gh_url) = parse_http_github_segments(trimmed, hosts)
+        && let Some(owner_repo) = owner_repo_from_segments(&gh_url)
     {
         return Some(owner_repo);
     }
@@ -212,49 +458,85 @@ pub(crate) fn parse_github_repo_url(url: &str) -> Option<GhRepoInfo> {
     None
 }
 
-fn parse_http_github_segments(url: &str) -> Result<(Vec<String>, bool), WtgError> {
-    let mut parsed =
-        parse_with_https_fallback(url).ok_or_else(|| WtgError::NotGitHubUrl(url.to_string()))?;
+/// Path segments of a GitHub URL together with the host they belong to
+struct GhUrlSegments {
+    /// Web host (`github.com` or a GitHub Enterprise host)
+    host: String,
+    segments: Vec<String>,
+    /// Whether the segments come from a REST API URL (`repos/owner/repo/...`)
+    is_api: bool,
+}
+
+fn parse_http_github_segments(url: &str, hosts: &ForgeHosts) -> Result<GhUrlSegments, WtgError> {
+    let parsed = parse_with_https_fallback(url, hosts)
+        .ok_or_else(|| WtgError::NotGitHubUrl(url.to_string()))?;
 
     let host = parsed
         .host_str()
         .ok_or_else(|| WtgError::NotGitHubUrl(url.to_string()))?;
 
-    let is_api = match is_allowed_github_host(host) {
-        GhUrlHostType::Github => false,
-        GhUrlHostType::GithubApi => true,
-        GhUrlHostType::Other => return Err(WtgError::NotGitHubUrl(url.to_string())),
-    };
-
-    parsed.set_fragment(None);
-    parsed.set_query(None);
-    Ok((collect_segments(parsed.path()), is_api))
+    // `path()` excludes query and fragment
+    let segments = collect_segments(parsed.path());
+
+    match is_allowed_github_host(host, hosts) {
+        GhUrlHostType::Github => Ok(GhUrlSegments {
+            host: GITHUB_COM.to_string(),
+            segments,
+            is_api: false,
+        }),
+        GhUrlHostType::GithubApi => Ok(GhUrlSegments {
+            host: GITHUB_COM.to_string(),
+            segments,
+            is_api: true,
+        }),
+        GhUrlHostType::Enterprise(host) => {
+            // Enterprise hosts serve the REST API under /api/v3 on the web host
+            let is_api = segments.len() >= 2 && segments[0] == "api" && segments[1] == "v3";
+            let segments = if is_api {
+                segments[2..].to_vec()
+            } else {
+                segments
+            };
+            Ok(GhUrlSegments {
+                host,
+                segments,
+                is_api,
+            })
+        }
+        GhUrlHostType::Other => Err(WtgError::NotGitHubUrl(url.to_string())),
+    }
 }
 
 /// Parse Git SSH URL format:
 /// - `git@github.com:owner/repo/pull/9#discussion_r123`
-fn parse_git_ssh_segments(url: &str) -> Option<Vec<String>> {
+/// - `git@<enterprise host>:owner/repo.git`
+fn parse_git_ssh_segments(url: &str, hosts: &ForgeHosts) -> Option<GhUrlSegments> {
     let normalized = url.trim();
-    if !normalized.starts_with("git@github.com:") {
-        return None;
-    }
-    let path = normalized.split(':').nth(1)?;
+    let (host, path) = normalized.strip_prefix("git@")?.split_once(':')?;
+    let host = match is_allowed_github_host(host, hosts) {
+        GhUrlHostType::Github => GITHUB_COM.to_string(),
+        GhUrlHostType::Enterprise(host) => host,
+        GhUrlHostType::GithubApi | GhUrlHostType::Other => return None,
+    };
     let path = path.split('#').next().unwrap_or(path);
     let path = path.split('?').next().unwrap_or(path);
-    Some(collect_segments(path))
+    Some(GhUrlSegments {
+        host,
+        segments: collect_segments(path),
+        is_api: false,
+    })
 }
 
-fn parse_with_https_fallback(input: &str) -> Option<Url> {
+fn parse_with_https_fallback(input: &str, hosts: &ForgeHosts) -> Option<Url> {
     Url::parse(input).map_or_else(
         |_| {
-            let lower = input.to_ascii_lowercase();
-            if lower.starts_with("github.com/") || lower.starts_with("www.github.com/") {
-                Url::parse(&format!("https://{input}")).ok()
-            } else if lower.starts_with("//github.com/") {
-                Url::parse(&format!("https:{input}")).ok()
-            } else {
-                None
+            // Scheme-less input, e.g. `github.com/owner/repo` or `//github.com/owner/repo`
+            let without_slashes = input.strip_prefix("//").unwrap_or(input);
+            let host = without_slashes.split('/').next()?;
+            if matches!(is_allowed_github_host(host, hosts), GhUrlHostType::Other) {
+                return None;
             }
+            Url::parse(&format!("https://{without_slashes}")).ok()
         },
         Some,
     )
@@ -263,13 +545,15 @@ fn parse_with_https_fallback(input: &str) -> Option<Url> {
 enum GhUrlHostType {
     Github,
     GithubApi,
+    /// A configured GitHub Enterprise host (normalized)
+    Enterprise(String),
     Other,
 }
 
-fn is_allowed_github_host(host: &str) -> GhUrlHostType {
+fn is_allowed_github_host(host: &str, hosts: &ForgeHosts) -> GhUrlHostType {
     let host = host.trim_start_matches("www.").to_ascii_lowercase();
 
-    if host == "github.com" {
+    if host == GITHUB_COM {
         return GhUrlHostType::Github;
     }
 
@@ -277,6 +561,10 @@ fn is_allowed_github_host(host: &str) -> GhUrlHostType {
         return GhUrlHostType::GithubApi;
     }
 
+    if hosts.is_github_enterprise_host(&host) {
+        return GhUrlHostType::Enterprise(host);
+    }
+
     GhUrlHostType::Other
 }
 
@@ -289,6 +577,7 @@ fn collect_segments(path: &str) -> Vec<String> {
 }
 
 fn parse_github_blob_path(segments: &[String], url: &str) -> WtgResult<ParsedQuery> {
+    let lines = line_anchor(url);
     if segments.len() < 3 {
         return Err(WtgError::MalformedGitHubUrl(format!(
             "Missing file path in URL: {url}"
@@ -313,7 +602,14 @@ fn parse_github_blob_path(segments: &[String], url: &str) -> WtgResult<ParsedQue
         check_path(&path).map_err(|_| {
             WtgError::MalformedGitHubUrl(format!("Invalid file path in URL: {url}"))
         })?;
-        return Ok(ParsedQuery::Resolved(Query::FilePath { branch, path }));
+        return Ok(ParsedQuery::Resolved(match lines {
+            Some(lines) => Query::FileLines {
+                branch,
+                path,
+                lines,
+            },
+            None => Query::FilePath { branch, path },
+        }));
     }
 
     let mut sanitized = Vec::with_capacity(tail.len());
@@ -335,37 +631,42 @@ fn parse_github_blob_path(segments: &[String], url: &str) -> WtgResult<ParsedQue
 
     Ok(ParsedQuery::UnknownPath {
         segments: sanitized,
+        lines,
     })
 }
 
-fn owner_repo_from_segments(segments: &[String], is_api: bool) -> Option<GhRepoInfo> {
-    split_url_segments(segments, is_api).map(|(repo_info, _)| repo_info)
+/// The lines selected by a `#L42` or `#L42-L50` anchor on a file URL.
+fn line_anchor(url: &str) -> Option<LineRange> {
+    let (_, fragment) = url.split_once('#')?;
+    let lines = fragment.strip_prefix('L')?;
+    LineRange::parse(&lines.replacen("-L", "-", 1))
+}
+
+fn owner_repo_from_segments(gh_url: &GhUrlSegments) -> Option<GhRepoInfo> {
+    split_url_segments(gh_url).map(|(repo_info, _)| repo_info)
 }
 
-fn split_url_segments(segments: &[String], is_api: bool) -> Option<(GhRepoInfo, &[String])> {
-    let min_segments = if is_api { 3 } else { 2 };
+fn split_url_segments(gh_url: &GhUrlSegments) -> Option<(GhRepoInfo, &[String])> {
+    let segments = &gh_url.segments;
+    let min_segments = if gh_url.is_api { 3 } else { 2 };
 
     if segments.len() < min_segments {
         return None;
     }
 
-    let owner_segment_index = usize::from(is_api);
+    let owner_segment_index = usize::from(gh_url.is_api);
 
     let owner = sanitize_owner_repo_segment(segments[owner_segment_index].as_str())?;
     let repo =
         sanitize_owner_repo_segment(segments[owner_segment_index + 1].trim_end_matches(".git"))?;
     Some((
-        GhRepoInfo::new(owner, repo),
+        GhRepoInfo::with_host(&gh_url.host, owner, repo),
         &segments[owner_segment_index + 2..],
     ))
 }
 
-fn parsed_input_from_segments(
-    segments: &[String],
-    is_api: bool,
-    url: &str,
-) -> WtgResult<ParsedInput> {
-    let (repo_info, segments) = split_url_segments(segments, is_api).ok_or_else(|| {
+fn parsed_input_from_segments(gh_url: &GhUrlSegments, url: &str) -> WtgResult<ParsedInput> {
+    let (repo_info, segments) = split_url_segments(gh_url).ok_or_else(|| {
         WtgError::MalformedGitHubUrl("Where's the repo, where's the owner?".to_string())
     })?;
 
@@ -403,6 +704,17 @@ fn parsed_input_from_segments(
             })?;
             ParsedQuery::Resolved(Query::Pr(num))
         }
+        "compare" if segments.len() >= 2 => {
+            // Tags may contain slashes, so the range spans the remaining segments
+            let range = segments[1..].join("/");
+            reject_control_chars(&range).map_err(|_| {
+                WtgError::MalformedGitHubUrl(format!("Invalid characters in URL: {url}"))
+            })?;
+            let query = parse_tag_range(&range).ok_or_else(|| {
+                WtgError::MalformedGitHubUrl(format!("Invalid compare range in URL: {url}"))
+            })?;
+            ParsedQuery::Resolved(query)
+        }
         // File path will start from segment index 2, e.g., /blob/branch/path/to/file
         "blob" | "tree" if segments.len() >= 2 => parse_github_blob_path(segments, url)?,
         _ => {
@@ -415,6 +727,165 @@ fn parsed_input_from_segments(
     Ok(ParsedInput::new_with_remote(repo_info, query))
 }
 
+/// Split a URL on a host accepted by `is_host` into the web base URL
+/// (`https://host[:port]`), the repository owner and name, and the remaining
+/// path segments.
+///
+/// Supports `https://host/owner/repo/...`, scheme-less `host/owner/repo/...`
+/// and `git@host:owner/repo(.git)`.
+fn parse_forge_url(url: &str, is_host: impl Fn(&str) -> bool) -> Option<ForgeUrlSegments> {
+    let (base_url, segments) = if let Some(rest) = url.strip_prefix("git@") {
+        let (host, path) = rest.split_once(':')?;
+        if !is_host(host) {
+            return None;
+        }
+        let path = path.split(['#', '?']).next().unwrap_or(path);
+        (format!("https://{host}"), collect_segments(path))
+    } else {
+        let parsed = Url::parse(url).ok().or_else(|| {
+            let without_slashes = url.strip_prefix("//").unwrap_or(url);
+            let host = without_slashes.split('/').next()?;
+            if !is_host(host) {
+                return None;
+            }
+            Url::parse(&format!("https://{without_slashes}")).ok()
+        })?;
+        let host = parsed.host_str()?;
+        if !matches!(parsed.scheme(), "http" | "https") || !is_host(host) {
+            return None;
+        }
+        let port = parsed.port().map(|p| format!(":{p}")).unwrap_or_default();
+        (
+            format!("{}://{host}{port}", parsed.scheme()),
+            collect_segments(parsed.path()),
+        )
+    };
+
+    let owner = sanitize_owner_repo_segment(segments.first()?)?;
+    let repo = sanitize_owner_repo_segment(segments.get(1)?.trim_end_matches(".git"))?;
+    Some(ForgeUrlSegments {
+        base_url,
+        owner,
+        repo,
+        route: segments[2..].to_vec(),
+    })
+}
+
+/// A forge URL split by [`parse_forge_url`]
+struct ForgeUrlSegments {
+    base_url: String,
+    owner: String,
+    repo: String,
+    /// Segments after `owner/repo`
+    route: Vec<String>,
+}
+
+/// Parse a URL on a known Gitea/Forgejo host into the repository and the
+/// remaining path segments.
+fn parse_gitea_url(url: &str, hosts: &ForgeHosts) -> Option<(GtRepoInfo, Vec<String>)> {
+    let forge_url = parse_forge_url(url, |host| hosts.is_gitea_host(host))?;
+    Some((
+        GtRepoInfo::new(&forge_url.base_url, &forge_url.owner, &forge_url.repo),
+        forge_url.route,
+    ))
+}
+
+/// Parse a Bitbucket Cloud URL into the repository and the remaining path segments.
+fn parse_bitbucket_url(url: &str) -> Option<(BbRepoInfo, Vec<String>)> {
+    let forge_url = parse_forge_url(url, is_bitbucket_host)?;
+    Some((
+        BbRepoInfo::new(&forge_url.owner, &forge_url.repo),
+        forge_url.route,
+    ))
+}
+
+/// Turn the route of a Bitbucket URL (the segments after `workspace/repo`) into a query.
+///
+/// Supports `pull-requests/<id>` and `commits/<sha>`.
+fn parse_bitbucket_route(segments: &[String], url: &str) -> WtgResult<ParsedQuery> {
+    let malformed = |what: &str| WtgError::MalformedUrl(format!("{what} in URL: {url}"));
+
+    let query = match segments.first().map(String::as_str) {
+        Some("pull-requests") => {
+            let id = segments
+                .get(1)
+                .ok_or_else(|| malformed("Missing PR number"))?
+                .parse()
+                .map_err(|_| malformed("Invalid PR number"))?;
+            ParsedQuery::Resolved(Query::Pr(id))
+        }
+        Some("commits") => {
+            let hash = segments
+                .get(1)
+                .ok_or_else(|| malformed("Missing commit hash"))?;
+            reject_control_chars(hash).map_err(|_| malformed("Invalid characters"))?;
+            ParsedQuery::Resolved(Query::GitCommit(hash.clone()))
+        }
+        _ => {
+            return Err(WtgError::MalformedUrl(format!(
+                "Unrecognized Bitbucket URL route: {url}"
+            )));
+        }
+    };
+
+    Ok(query)
+}
+
+/// Turn the route of a Gitea URL (the segments after `owner/repo`) into a query.
+///
+/// Supports `commit/<sha>`, `issues/<n>`, `pulls/<n>`, `compare/<from>...<to>`
+/// and `src/{branch,tag,commit}/<ref>/<path>`.
+fn parse_gitea_route(segments: &[String], url: &str) -> WtgResult<ParsedQuery> {
+    let malformed = |what: &str| WtgError::MalformedUrl(format!("{what} in URL: {url}"));
+    let number = |what: &str| -> WtgResult<u64> {
+        segments
+            .get(1)
+            .ok_or_else(|| malformed(&format!("Missing {what} number")))?
+            .parse()
+            .map_err(|_| malformed(&format!("Invalid {what} number")))
+    };
+
+    let route = segments
+        .first()
+        .ok_or_else(|| WtgError::MalformedUrl("No route found in Gitea URL".to_string()))?;
+
+    let query = match route.as_str() {
+        "commit" => {
+            let hash = segments
+                .get(1)
+                .ok_or_else(|| malformed("Missing commit hash"))?;
+            reject_control_chars(hash).map_err(|_| malformed("Invalid characters"))?;
+            ParsedQuery::Resolved(Query::GitCommit(hash.clone()))
+        }
+        "issues" => ParsedQuery::Resolved(Query::Issue(number("issue")?)),
+        "pulls" => ParsedQuery::Resolved(Query::Pr(number("PR")?)),
+        "compare" if segments.len() >= 2 => {
+            let range = segments[1..].join("/");
+            reject_control_chars(&range).map_err(|_| malformed("Invalid characters"))?;
+            ParsedQuery::Resolved(
+                parse_tag_range(&range).ok_or_else(|| malformed("Invalid compare range"))?,
+            )
+        }
+        // `src/branch/<branch>/<path>` has the same shape as GitHub's `blob/<branch>/<path>`
+        "src"
+            if segments.len() >= 3
+                && matches!(segments[1].as_str(), "branch" | "tag" | "commit") =>
+        {
+            parse_github_blob_path(&segments[1..], url).map_err(|e| match e {
+                WtgError::MalformedGitHubUrl(message) => WtgError::MalformedUrl(message),
+                e => e,
+            })?
+        }
+        _ => {
+            return Err(WtgError::MalformedUrl(format!(
+                "Unrecognized Gitea URL route: {url}"
+            )));
+        }
+    };
+
+    Ok(query)
+}
+
 /// Sanitize owner or repo segment by trimming whitespace and allowing only certain characters
 fn sanitize_owner_repo_segment(raw: &str) -> Option<String> {
     let trimmed = raw.trim();
@@ -657,7 +1128,7 @@ mod tests {
         #[case] expected_branch: &str,
         #[case] expected_path: &str,
     ) {
-        let parsed = try_parse_input_from_github_url(url)
+        let parsed = try_parse_input_from_github_url(url, &ForgeHosts::new())
             .unwrap_or_else(|_| panic!("failed to parse {url}"));
         assert_eq!(parsed.owner(), Some(expected_owner));
         assert_eq!(parsed.repo(), Some(expected_repo));
@@ -670,6 +1141,60 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::single_line(
+        "https://github.com/owner/repo/blob/main/src/lib.rs#L42",
+        "src/lib.rs",
+        LineRange { start: 42, end: 42 }
+    )]
+    #[case::line_range(
+        "https://github.com/owner/repo/blob/main/src/lib.rs#L42-L50",
+        "src/lib.rs",
+        LineRange { start: 42, end: 50 }
+    )]
+    #[case::gitlab_style_range(
+        "https://github.com/owner/repo/blob/main/README.md#L3-7",
+        "README.md",
+        LineRange { start: 3, end: 7 }
+    )]
+    fn parses_github_file_urls_with_line_anchors(
+        #[case] url: &str,
+        #[case] expected_path: &str,
+        #[case] expected_lines: LineRange,
+    ) {
+        let parsed = try_parse_input_from_github_url(url, &ForgeHosts::new())
+            .unwrap_or_else(|_| panic!("failed to parse {url}"));
+        let expected = Query::FileLines {
+            branch: "main".to_string(),
+            path: PathBuf::from(expected_path),
+            lines: expected_lines,
+        };
+        match parsed.query {
+            ParsedQuery::Resolved(query) => assert_eq!(query, expected),
+            ParsedQuery::UnknownPath { segments, lines } => {
+                assert_eq!(segments.first().map(String::as_str), Some("main"));
+                assert_eq!(segments[1..].join("/"), expected_path);
+                assert_eq!(lines, Some(expected_lines));
+            }
+            other @ ParsedQuery::Unknown(_) => panic!("unexpected query for {url}: {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case::heading_anchor("https://github.com/owner/repo/blob/main/README.md#usage")]
+    #[case::reversed_range("https://github.com/owner/repo/blob/main/README.md#L9-L3")]
+    fn ignores_non_line_anchors(#[case] url: &str) {
+        let parsed = try_parse_input_from_github_url(url, &ForgeHosts::new())
+            .unwrap_or_else(|_| panic!("failed to parse {url}"));
+        assert_eq!(
+            parsed.query,
+            ParsedQuery::Resolved(Query::FilePath {
+                branch: "main".to_string(),
+                path: PathBuf::from("README.md"),
+            })
+        );
+    }
+
     #[rstest]
     #[case::tree_nested_branch(
         "https://github.com/owner/repo/tree/feat/new-feature/docs/api",
@@ -691,12 +1216,13 @@ mod tests {
         #[case] url: &str,
         #[case] expected_segments: Vec<&str>,
     ) {
-        let parsed = try_parse_input_from_github_url(url)
+        let parsed = try_parse_input_from_github_url(url, &ForgeHosts::new())
             .unwrap_or_else(|_| panic!("failed to parse {url}"));
         assert_eq!(
             parsed.query,
             ParsedQuery::UnknownPath {
-                segments: expected_segments.iter().map(|s| (*s).to_string()).collect()
+                segments: expected_segments.iter().map(|s| (*s).to_string()).collect(),
+                lines: None,
             }
         );
     }
@@ -790,6 +1316,7 @@ mod tests {
     #[case::hash_with_prefix("#42", ParsedQuery::Resolved(Query::IssueOrPr(42)))]
     #[case::hash_without_prefix("42", ParsedQuery::Unknown("42".to_string()))]
     #[case::hash_with_whitespace("  #99  ", ParsedQuery::Resolved(Query::IssueOrPr(99)))]
+    #[case::merge_request("!42", ParsedQuery::Resolved(Query::Pr(42)))]
     #[case::short_hash("abc123d", ParsedQuery::Unknown("abc123d".to_string()))]
     #[case::hash_with_whitespace("  abc123  ", ParsedQuery::Unknown("abc123".to_string()))]
     #[case::simple_tag("v1.0.0", ParsedQuery::Unknown("v1.0.0".to_string()))]
@@ -798,12 +1325,83 @@ mod tests {
     #[case::unicode_path("src/файл.rs", ParsedQuery::Unknown("src/файл.rs".to_string()))]
     #[case::unicode_tag("версия-1.0", ParsedQuery::Unknown("версия-1.0".to_string()))]
     #[case::emoji_in_path("src/👍.md", ParsedQuery::Unknown("src/👍.md".to_string()))]
+    #[case::tag_range("v1.2.0..v1.3.0", range("v1.2.0", "v1.3.0"))]
+    #[case::three_dot_range("v1.2.0...v1.3.0", range("v1.2.0", "v1.3.0"))]
+    #[case::component_range("crate/v1.0.0..crate/v2.0.0", range("crate/v1.0.0", "crate/v2.0.0"))]
+    #[case::parent_path("../secret", ParsedQuery::Unknown("../secret".to_string()))]
+    #[case::open_range("v1.2.0..", ParsedQuery::Unknown("v1.2.0..".to_string()))]
+    #[case::four_dots("a....b", ParsedQuery::Unknown("a....b".to_string()))]
+    #[case::date("@2026-03-14", at("2026-03-14T23:59:59Z"))]
+    #[case::timestamp("@2026-03-14T08:30:00+02:00", at("2026-03-14T06:30:00Z"))]
+    #[case::date_range(
+        "@2026-01-01..@2026-03-14",
+        ParsedQuery::Resolved(Query::DateRange {
+            from: time("2026-01-01T23:59:59Z"),
+            to: time("2026-03-14T23:59:59Z"),
+        })
+    )]
+    #[case::invalid_date("@2026-13-01", ParsedQuery::Unknown("@2026-13-01".to_string()))]
+    #[case::open_date_range("@2026-01-01..", ParsedQuery::Unknown("@2026-01-01..".to_string()))]
     fn parses_local_inputs(#[case] input: &str, #[case] expected: ParsedQuery) {
         let parsed = try_parse_input(input, None).expect("Should parse issue/PR number");
         assert_eq!(parsed.query, expected);
         assert!(parsed.gh_repo_info().is_none());
     }
 
+    fn time(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn at(rfc3339: &str) -> ParsedQuery {
+        ParsedQuery::Resolved(Query::ReleaseAt(time(rfc3339)))
+    }
+
+    fn range(from: &str, to: &str) -> ParsedQuery {
+        ParsedQuery::Resolved(Query::TagRange {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+    }
+
+    #[rstest]
+    #[case::single_line("src/lib.rs:42", Some(("src/lib.rs", 42, 42)))]
+    #[case::line_range("src/lib.rs:120-140", Some(("src/lib.rs", 120, 140)))]
+    #[case::colon_in_path("dir:name/file.rs:7", Some(("dir:name/file.rs", 7, 7)))]
+    #[case::reversed("src/lib.rs:140-120", None)]
+    #[case::line_zero("src/lib.rs:0", None)]
+    #[case::not_a_number("src/lib.rs:abc", None)]
+    #[case::no_path(":42", None)]
+    #[case::no_lines("src/lib.rs", None)]
+    fn splits_line_ranges(#[case] input: &str, #[case] expected: Option<(&str, usize, usize)>) {
+        let expected = expected.map(|(path, start, end)| (path, LineRange { start, end }));
+        assert_eq!(split_line_range(input), expected);
+    }
+
+    #[rstest]
+    #[case::three_dots(
+        "https://github.com/owner/repo/compare/v1.0.0...v1.1.0",
+        "v1.0.0",
+        "v1.1.0"
+    )]
+    #[case::two_dots(
+        "https://github.com/owner/repo/compare/v1.0.0..v1.1.0",
+        "v1.0.0",
+        "v1.1.0"
+    )]
+    #[case::slashes_in_tags(
+        "https://github.com/owner/repo/compare/crate/v1.0.0...crate/v1.1.0",
+        "crate/v1.0.0",
+        "crate/v1.1.0"
+    )]
+    fn parses_github_compare_urls(#[case] url: &str, #[case] from: &str, #[case] to: &str) {
+        let parsed = try_parse_input(url, None).unwrap_or_else(|_| panic!("failed to parse {url}"));
+        assert_eq!(parsed.owner(), Some("owner"));
+        assert_eq!(parsed.repo(), Some("repo"));
+        assert_eq!(parsed.query, range(from, to));
+    }
+
     // ========================================================================
     // Repository URL Parsing Tests
     // ========================================================================
@@ -845,6 +1443,189 @@ mod tests {
         assert_eq!(parsed.query, ParsedQuery::Unknown("dummy".to_string()));
     }
 
+    #[rstest]
+    #[case::web_issue("https://ghe.example.com/owner/repo/issues/42", Query::Issue(42))]
+    #[case::web_pr_www("https://www.ghe.example.com/owner/repo/pull/7", Query::Pr(7))]
+    #[case::api_issue(
+        "https://ghe.example.com/api/v3/repos/owner/repo/issues/42",
+        Query::Issue(42)
+    )]
+    #[case::no_scheme("ghe.example.com/owner/repo/pull/7", Query::Pr(7))]
+    #[case::ssh_commit(
+        "git@ghe.example.com:owner/repo/commit/abc123",
+        Query::GitCommit("abc123".to_string())
+    )]
+    fn parses_github_enterprise_urls(#[case] url: &str, #[case] expected_query: Query) {
+        let parsed = try_parse_input_with_hosts(url, None, &ghe_hosts())
+            .unwrap_or_else(|_| panic!("failed to parse {url}"));
+        let repo_info = parsed.gh_repo_info().expect("repo info");
+        assert_eq!(repo_info.host(), "ghe.example.com");
+        assert_eq!(repo_info.owner(), "owner");
+        assert_eq!(repo_info.repo(), "repo");
+        assert_eq!(parsed.query, ParsedQuery::Resolved(expected_query));
+    }
+
+    #[rstest]
+    #[case::https("https://ghe.example.com/owner/repo.git")]
+    #[case::ssh("git@ghe.example.com:owner/repo.git")]
+    #[case::api_repos("https://ghe.example.com/api/v3/repos/owner/repo")]
+    fn parses_github_enterprise_repo_urls(#[case] url: &str) {
+        let repo_info = parse_github_repo_url(url, &ghe_hosts())
+            .unwrap_or_else(|| panic!("failed to parse {url}"));
+        assert_eq!(repo_info.host(), "ghe.example.com");
+        assert_eq!(repo_info.owner(), "owner");
+        assert_eq!(repo_info.repo(), "repo");
+    }
+
+    fn ghe_hosts() -> ForgeHosts {
+        let mut hosts = ForgeHosts::new();
+        hosts.add_github_enterprise_host("ghe.example.com");
+        hosts
+    }
+
+    #[test]
+    fn unknown_hosts_are_not_github() {
+        let hosts = ForgeHosts::new();
+        assert!(matches!(
+            try_parse_input_with_hosts(
+                "https://git.unknown-corp.example/owner/repo/issues/1",
+                None,
+                &hosts
+            ),
+            Err(WtgError::NotGitHubUrl(_))
+        ));
+        assert!(
+            parse_github_repo_url("git@git.unknown-corp.example:owner/repo.git", &hosts).is_none()
+        );
+        // Enterprise hosts are only known when configured
+        assert!(parse_github_repo_url("git@ghe.example.com:owner/repo.git", &hosts).is_none());
+    }
+
+    #[test]
+    fn github_com_urls_default_to_github_host() {
+        let repo_info = parse_github_repo_url("owner/repo", &ForgeHosts::new()).expect("repo info");
+        assert!(repo_info.is_github_com());
+        let parsed = try_parse_input("https://github.com/owner/repo/pull/1", None).expect("parse");
+        assert_eq!(
+            parsed.gh_repo_info().map(GhRepoInfo::host),
+            Some("github.com")
+        );
+    }
+
+    #[rstest]
+    #[case::pull("https://codeberg.org/owner/repo/pulls/7", Query::Pr(7))]
+    #[case::pull_files("https://codeberg.org/owner/repo/pulls/7/files", Query::Pr(7))]
+    #[case::issue(
+        "https://codeberg.org/owner/repo/issues/42#issuecomment-1",
+        Query::Issue(42)
+    )]
+    #[case::commit(
+        "https://codeberg.org/owner/repo/commit/abc123",
+        Query::GitCommit("abc123".to_string())
+    )]
+    #[case::src_branch(
+        "https://codeberg.org/owner/repo/src/branch/main/README.md",
+        Query::FilePath { branch: "main".to_string(), path: PathBuf::from("README.md") }
+    )]
+    #[case::compare(
+        "https://codeberg.org/owner/repo/compare/v1.0.0...v1.1.0",
+        Query::TagRange { from: "v1.0.0".to_string(), to: "v1.1.0".to_string() }
+    )]
+    #[case::no_scheme("codeberg.org/owner/repo/pulls/7", Query::Pr(7))]
+    fn parses_gitea_urls(#[case] url: &str, #[case] expected_query: Query) {
+        let parsed = try_parse_input(url, None).unwrap_or_else(|_| panic!("failed to parse {url}"));
+        let repo_info = parsed.gitea_repo_info().expect("gitea repo info");
+        assert_eq!(repo_info.base_url(), "https://codeberg.org");
+        assert_eq!(repo_info.owner(), "owner");
+        assert_eq!(repo_info.repo(), "repo");
+        assert!(parsed.gh_repo_info().is_none());
+        assert_eq!(parsed.query, ParsedQuery::Resolved(expected_query));
+    }
+
+    #[test]
+    fn parses_gitea_paths_with_ambiguous_branch() {
+        let parsed = try_parse_input(
+            "https://codeberg.org/owner/repo/src/branch/release/1.x/src/lib.rs",
+            None,
+        )
+        .expect("parse");
+        assert_eq!(
+            parsed.query,
+            ParsedQuery::UnknownPath {
+                segments: ["release", "1.x", "src", "lib.rs"]
+                    .map(String::from)
+                    .to_vec(),
+                lines: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_configured_gitea_hosts() {
+        let mut hosts = ForgeHosts::new();
+        hosts.add_gitea_host("forgejo.example.com");
+
+        let parsed = try_parse_input_with_hosts(
+            "http://forgejo.example.com:3000/team/app/pulls/3",
+            None,
+            &hosts,
+        )
+        .expect("parse");
+        let repo_info = parsed.gitea_repo_info().expect("gitea repo info");
+        assert_eq!(repo_info.base_url(), "http://forgejo.example.com:3000");
+        assert_eq!(parsed.query, ParsedQuery::Resolved(Query::Pr(3)));
+
+        let parsed =
+            try_parse_input_with_hosts("#12", Some("git@forgejo.example.com:team/app.git"), &hosts)
+                .expect("parse");
+        assert_eq!(
+            parsed.gitea_repo_info().map(GtRepoInfo::base_url),
+            Some("https://forgejo.example.com")
+        );
+        assert_eq!(parsed.query, ParsedQuery::Resolved(Query::IssueOrPr(12)));
+    }
+
+    #[rstest]
+    #[case::pull("https://bitbucket.org/ws/repo/pull-requests/12", Query::Pr(12))]
+    #[case::pull_diff(
+        "https://bitbucket.org/ws/repo/pull-requests/12/diff#chg-src/lib.rs",
+        Query::Pr(12)
+    )]
+    #[case::commit(
+        "https://bitbucket.org/ws/repo/commits/abc123",
+        Query::GitCommit("abc123".to_string())
+    )]
+    #[case::no_scheme("bitbucket.org/ws/repo/pull-requests/12", Query::Pr(12))]
+    fn parses_bitbucket_urls(#[case] url: &str, #[case] expected_query: Query) {
+        let parsed = try_parse_input(url, None).unwrap_or_else(|_| panic!("failed to parse {url}"));
+        let repo_info = parsed.bb_repo_info().expect("bitbucket repo info");
+        assert_eq!(repo_info.workspace(), "ws");
+        assert_eq!(repo_info.repo(), "repo");
+        assert_eq!(parsed.query, ParsedQuery::Resolved(expected_query));
+    }
+
+    #[test]
+    fn parses_bitbucket_repo_flag() {
+        let parsed = try_parse_input("#12", Some("git@bitbucket.org:ws/repo.git")).expect("parse");
+        assert_eq!(parsed.bb_repo_info().map(BbRepoInfo::repo), Some("repo"));
+        assert!(matches!(
+            try_parse_input("https://bitbucket.org/ws/repo/wiki/Home", None),
+            Err(WtgError::MalformedUrl(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_unknown_gitea_routes() {
+        assert!(matches!(
+            try_parse_input("https://codeberg.org/owner/repo/wiki/Home", None),
+            Err(WtgError::MalformedUrl(_))
+        ));
+        assert!(matches!(
+            try_parse_input("https://codeberg.org/owner/repo/src/branch/main", None),
+            Err(WtgError::MalformedUrl(_))
+        ));
+    }
+
     // ========================================================================
     // Combined Parsing Tests (try_parse_input)
     // ========================================================================
@@ -0,0 +1,166 @@
+//! Interactive picker for input that could mean several things.
+//!
+//! Drawn on stderr so stdout only ever carries the result.
+
+use std::io::{self, IsTerminal, Write};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    queue,
+    style::Stylize,
+    terminal::{self, ClearType},
+};
+
+use crate::error::WtgResult;
+use crate::parse_input::Candidate;
+
+/// Whether someone is around to answer: both stdin and stdout are terminals.
+#[must_use]
+pub fn is_interactive() -> bool {
+    io::stdin().is_terminal() && io::stdout().is_terminal()
+}
+
+/// Ask which candidate was meant. Returns `None` if the user backs out.
+pub fn pick(input: &str, candidates: &[Candidate]) -> WtgResult<Option<usize>> {
+    let mut out = io::stderr();
+    writeln!(
+        out,
+        "{} {}",
+        "🤔 That could mean a few things:".yellow().bold(),
+        input.cyan()
+    )?;
+    writeln!(
+        out,
+        "{}",
+        "   ↑/↓ to move, enter to pick, esc to give up".dark_grey()
+    )?;
+
+    terminal::enable_raw_mode()?;
+    let picked = run(&mut out, candidates);
+    terminal::disable_raw_mode()?;
+    writeln!(out)?;
+    picked
+}
+
+fn run(out: &mut impl Write, candidates: &[Candidate]) -> WtgResult<Option<usize>> {
+    let mut selected = 0;
+    loop {
+        draw(out, candidates, selected)?;
+
+        if let Event::Key(key) = event::read()? {
+            match step(key, selected, candidates.len()) {
+                Step::Move(index) => selected = index,
+                Step::Pick(index) => return Ok(Some(index)),
+                Step::Cancel => return Ok(None),
+                Step::Ignore => {}
+            }
+        }
+
+        // Redraw the list in place
+        let lines = u16::try_from(candidates.len()).unwrap_or(u16::MAX);
+        queue!(out, cursor::MoveUp(lines))?;
+    }
+}
+
+fn draw(out: &mut impl Write, candidates: &[Candidate], selected: usize) -> io::Result<()> {
+    for (index, candidate) in candidates.iter().enumerate() {
+        queue!(out, terminal::Clear(ClearType::CurrentLine))?;
+        let kind = format!("{:<10}", candidate.kind);
+        if index == selected {
+            write!(
+                out,
+                "{} {} {} {}\r\n",
+                " ❯".green().bold(),
+                format!("{}.", index + 1).green(),
+                kind.green().bold(),
+                candidate.preview.as_str().bold()
+            )?;
+        } else {
+            write!(
+                out,
+                "   {} {} {}\r\n",
+                format!("{}.", index + 1).dark_grey(),
+                kind.cyan(),
+                candidate.preview
+            )?;
+        }
+    }
+    out.flush()
+}
+
+/// What a key press does to the picker.
+#[derive(Debug, PartialEq, Eq)]
+enum Step {
+    Move(usize),
+    Pick(usize),
+    Cancel,
+    Ignore,
+}
+
+fn step(key: KeyEvent, selected: usize, count: usize) -> Step {
+    if key.kind != KeyEventKind::Press {
+        return Step::Ignore;
+    }
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::BackTab => {
+            Step::Move(selected.checked_sub(1).unwrap_or(count - 1))
+        }
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Tab => Step::Move((selected + 1) % count),
+        KeyCode::Enter => Step::Pick(selected),
+        // Jump straight to a numbered entry
+        KeyCode::Char(c @ '1'..='9') => {
+            let index = c as usize - '1' as usize;
+            if index < count {
+                Step::Pick(index)
+            } else {
+                Step::Ignore
+            }
+        }
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Step::Cancel,
+        KeyCode::Esc | KeyCode::Char('q') => Step::Cancel,
+        _ => Step::Ignore,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[rstest]
+    #[case::down(KeyCode::Down, 0, Step::Move(1))]
+    #[case::down_wraps(KeyCode::Down, 2, Step::Move(0))]
+    #[case::up(KeyCode::Up, 2, Step::Move(1))]
+    #[case::up_wraps(KeyCode::Up, 0, Step::Move(2))]
+    #[case::vim_down(KeyCode::Char('j'), 1, Step::Move(2))]
+    #[case::enter(KeyCode::Enter, 1, Step::Pick(1))]
+    #[case::number(KeyCode::Char('3'), 0, Step::Pick(2))]
+    #[case::number_out_of_range(KeyCode::Char('4'), 0, Step::Ignore)]
+    #[case::escape(KeyCode::Esc, 0, Step::Cancel)]
+    #[case::other(KeyCode::Char('x'), 0, Step::Ignore)]
+    fn steps_through_three_candidates(
+        #[case] code: KeyCode,
+        #[case] selected: usize,
+        #[case] expected: Step,
+    ) {
+        assert_eq!(step(press(code), selected, 3), expected);
+    }
+
+    #[test]
+    fn ctrl_c_cancels() {
+        let key = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert_eq!(step(key, 0, 3), Step::Cancel);
+    }
+
+    #[test]
+    fn ignores_key_releases() {
+        let mut key = press(KeyCode::Enter);
+        key.kind = KeyEventKind::Release;
+        assert_eq!(step(key, 0, 3), Step::Ignore);
+    }
+}
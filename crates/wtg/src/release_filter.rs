@@ -1,60 +1,250 @@
 //! Release filtering configuration.
 //!
-//! This module provides the `ReleaseFilter` enum which controls which tags/releases
+//! This module provides the `ReleaseFilter` type which controls which tags/releases
 //! are considered when finding releases for a commit.
 
+use globset::{Glob, GlobMatcher};
+use regex::Regex;
+
+use crate::error::{WtgError, WtgResult};
 use crate::git::TagInfo;
+use crate::semver::{SemverInfo, parse_semver};
 
 /// Controls which tags/releases are considered when finding releases.
+///
+/// The default considers every tag and reports the single best release.
 #[derive(Debug, Clone, Default)]
-pub enum ReleaseFilter {
-    /// All tags are considered (default behavior).
-    #[default]
-    Unrestricted,
+pub struct ReleaseFilter {
     /// Filter out pre-release versions (nightlies, RCs, etc.).
-    SkipPrereleases,
+    skip_prereleases: bool,
     /// Limit to one specific tag by name.
-    Specific(String),
+    specific: Option<String>,
+    /// Limit to tags of one component, e.g. `py` for `py-v1.2.0`.
+    tag_prefix: Option<String>,
+    /// Report the earliest release of every release line, not just the best one.
+    release_lines: bool,
+    /// Tag names to consider or ignore.
+    tag_patterns: TagPatterns,
+    /// Only count tags that have a forge release.
+    require_release: bool,
 }
 
 impl ReleaseFilter {
-    /// Filter a list of `TagInfo` candidates.
+    /// A filter limited to one specific tag by name.
+    #[must_use]
+    pub fn specific(tag_name: impl Into<String>) -> Self {
+        Self {
+            specific: Some(tag_name.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Set whether pre-release versions are skipped.
+    #[must_use]
+    pub const fn skip_prereleases(mut self, skip: bool) -> Self {
+        self.skip_prereleases = skip;
+        self
+    }
+
+    /// Limit to semver tags of one component, e.g. `py` (or `py-`, `py-v`) for
+    /// `py-v1.2.0`, or `sdk/go` for `sdk/go/v1.2.3`.
+    #[must_use]
+    pub fn tag_prefix(mut self, prefix: Option<String>) -> Self {
+        self.tag_prefix = prefix.map(|prefix| normalize_prefix(&prefix).to_string());
+        self
+    }
+
+    /// Set whether every release line is reported.
+    ///
+    /// A release line is a tag prefix plus major version, so a monorepo change can
+    /// be "first shipped in py-v0.9.0 and rust-v0.3.1".
+    #[must_use]
+    pub const fn release_lines(mut self, enabled: bool) -> Self {
+        self.release_lines = enabled;
+        self
+    }
+
+    /// Only consider tags matching the include patterns and none of the exclude patterns.
+    #[must_use]
+    pub fn tag_patterns(mut self, patterns: TagPatterns) -> Self {
+        self.tag_patterns = patterns;
+        self
+    }
+
+    /// Set whether a tag needs a forge release (GitHub, GitLab, ...) to count.
+    #[must_use]
+    pub const fn require_release(mut self, required: bool) -> Self {
+        self.require_release = required;
+        self
+    }
+
+    /// Filter a list of `TagInfo` candidates by name and version.
     ///
-    /// Returns a new vector containing only tags that pass the filter.
+    /// Returns a new vector containing only tags that pass the filter. The
+    /// release requirement is checked separately by `filter_releases`, once
+    /// candidates carry their release metadata.
     #[must_use]
     pub fn filter_tags(&self, tags: Vec<TagInfo>) -> Vec<TagInfo> {
-        match self {
-            Self::Unrestricted => tags,
-            Self::SkipPrereleases => tags
-                .into_iter()
-                .filter(|t| {
-                    // Keep tags that are not semver (can't determine pre-release status)
-                    // or are semver but not pre-releases
-                    t.semver_info
+        tags.into_iter()
+            .filter(|t| self.specific.as_ref().is_none_or(|name| t.name == *name))
+            .filter(|t| self.tag_patterns.matches(&t.name))
+            .filter(|t| self.matches_prefix(t.semver_info.as_ref()))
+            .filter(|t| {
+                // Keep tags that are not semver (can't determine pre-release status)
+                // or are semver but not pre-releases
+                !self.skip_prereleases
+                    || t.semver_info
                         .as_ref()
                         .is_none_or(|s| s.pre_release.is_none())
-                })
-                .collect(),
-            Self::Specific(name) => tags.into_iter().filter(|t| t.name == *name).collect(),
+            })
+            .collect()
+    }
+
+    /// Drop candidates without a forge release, if releases are required.
+    #[must_use]
+    pub fn filter_releases(&self, tags: Vec<TagInfo>) -> Vec<TagInfo> {
+        if !self.require_release {
+            return tags;
+        }
+        tags.into_iter().filter(|t| t.is_release).collect()
+    }
+
+    /// Check a release by tag name before fetching anything else about it.
+    ///
+    /// For API backends that walk releases: applies everything `filter_tags`
+    /// would, using the forge's pre-release flag.
+    #[must_use]
+    pub fn allows_release(&self, tag_name: &str, prerelease: bool) -> bool {
+        if self.skip_prereleases && prerelease {
+            return false;
+        }
+        if self.specific.as_ref().is_some_and(|name| name != tag_name) {
+            return false;
+        }
+        if !self.tag_patterns.matches(tag_name) {
+            return false;
+        }
+        self.tag_prefix.is_none() || self.matches_prefix(parse_semver(tag_name).as_ref())
+    }
+
+    /// Whether a tag's version belongs to the component selected by the prefix, if any.
+    fn matches_prefix(&self, semver: Option<&SemverInfo>) -> bool {
+        match (&self.tag_prefix, semver) {
+            (None, _) => true,
+            // Only semver tags say which component they belong to
+            (Some(_), None) => false,
+            (Some(prefix), Some(semver)) => {
+                semver.prefix.as_deref().unwrap_or_default() == prefix.as_str()
+            }
         }
     }
 
     /// Check if this is a specific release filter and return the tag name if so.
     #[must_use]
     pub fn specific_tag(&self) -> Option<&str> {
-        match self {
-            Self::Specific(name) => Some(name),
-            _ => None,
-        }
+        self.specific.as_deref()
     }
 
     /// Returns true if pre-releases should be skipped.
     #[must_use]
     pub const fn skips_prereleases(&self) -> bool {
-        matches!(self, Self::SkipPrereleases)
+        self.skip_prereleases
+    }
+
+    /// The component prefix tags are limited to, if any.
+    #[must_use]
+    pub fn prefix(&self) -> Option<&str> {
+        self.tag_prefix.as_deref()
+    }
+
+    /// Returns true if every release line should be reported.
+    #[must_use]
+    pub const fn reports_release_lines(&self) -> bool {
+        self.release_lines
+    }
+
+    /// Returns true if only tags with a forge release count.
+    #[must_use]
+    pub const fn requires_release(&self) -> bool {
+        self.require_release
     }
 }
 
+/// Include and exclude patterns for tag names, e.g. to ignore `nightly-*` tags.
+///
+/// A pattern is a glob matched against the full tag name, or a regex when
+/// wrapped in slashes (`/^deploy-(prod|staging)-/`). With include patterns a tag
+/// has to match one of them; a tag matching any exclude pattern never counts.
+#[derive(Debug, Clone, Default)]
+pub struct TagPatterns {
+    include: Vec<TagPattern>,
+    exclude: Vec<TagPattern>,
+}
+
+impl TagPatterns {
+    /// Build patterns from their string form.
+    pub fn new(include: &[String], exclude: &[String]) -> WtgResult<Self> {
+        let parse = |patterns: &[String]| -> WtgResult<Vec<TagPattern>> {
+            patterns.iter().map(|p| TagPattern::parse(p)).collect()
+        };
+        Ok(Self {
+            include: parse(include)?,
+            exclude: parse(exclude)?,
+        })
+    }
+
+    /// Whether a tag name passes the patterns.
+    #[must_use]
+    pub fn matches(&self, tag_name: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|p| p.matches(tag_name)))
+            && !self.exclude.iter().any(|p| p.matches(tag_name))
+    }
+}
+
+/// A single tag name pattern.
+#[derive(Debug, Clone)]
+enum TagPattern {
+    Glob(GlobMatcher),
+    Regex(Regex),
+}
+
+impl TagPattern {
+    fn parse(pattern: &str) -> WtgResult<Self> {
+        let invalid = |e: &dyn std::fmt::Display| WtgError::Cli {
+            message: format!("Invalid tag pattern '{pattern}': {e}"),
+            code: 2,
+        };
+
+        if let Some(regex) = pattern
+            .strip_prefix('/')
+            .and_then(|rest| rest.strip_suffix('/'))
+            .filter(|regex| !regex.is_empty())
+        {
+            return Regex::new(regex).map(Self::Regex).map_err(|e| invalid(&e));
+        }
+
+        Glob::new(pattern)
+            .map(|glob| Self::Glob(glob.compile_matcher()))
+            .map_err(|e| invalid(&e))
+    }
+
+    fn matches(&self, tag_name: &str) -> bool {
+        match self {
+            Self::Glob(glob) => glob.is_match(tag_name),
+            Self::Regex(regex) => regex.is_match(tag_name),
+        }
+    }
+}
+
+/// Strip the separator (and a trailing `v`) users may type: `py-v` and `py-` mean `py`.
+fn normalize_prefix(prefix: &str) -> &str {
+    let prefix = prefix
+        .strip_suffix('v')
+        .filter(|rest| rest.is_empty() || rest.ends_with(['-', '/']))
+        .unwrap_or(prefix);
+    prefix.trim_end_matches(['-', '/'])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,7 +273,7 @@ mod tests {
             make_tag("v2.0.0-rc.1"),
             make_tag("release-2024"),
         ];
-        let filter = ReleaseFilter::Unrestricted;
+        let filter = ReleaseFilter::default();
         let result = filter.filter_tags(tags);
         assert_eq!(result.len(), 4);
     }
@@ -96,7 +286,7 @@ mod tests {
             make_tag("v2.0.0-rc.1"),
             make_tag("release-2024"), // non-semver, kept
         ];
-        let filter = ReleaseFilter::SkipPrereleases;
+        let filter = ReleaseFilter::default().skip_prereleases(true);
         let result = filter.filter_tags(tags);
         assert_eq!(result.len(), 2);
         assert!(result.iter().any(|t| t.name == "v1.0.0"));
@@ -106,7 +296,7 @@ mod tests {
     #[test]
     fn specific_filters_to_one_tag() {
         let tags = vec![make_tag("v1.0.0"), make_tag("v1.1.0"), make_tag("v2.0.0")];
-        let filter = ReleaseFilter::Specific("v1.1.0".to_string());
+        let filter = ReleaseFilter::specific("v1.1.0");
         let result = filter.filter_tags(tags);
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].name, "v1.1.0");
@@ -115,27 +305,145 @@ mod tests {
     #[test]
     fn specific_returns_empty_if_not_found() {
         let tags = vec![make_tag("v1.0.0"), make_tag("v2.0.0")];
-        let filter = ReleaseFilter::Specific("v99.0.0".to_string());
+        let filter = ReleaseFilter::specific("v99.0.0");
         let result = filter.filter_tags(tags);
         assert!(result.is_empty());
     }
 
     #[test]
     fn specific_tag_returns_name() {
-        let filter = ReleaseFilter::Specific("v1.0.0".to_string());
+        let filter = ReleaseFilter::specific("v1.0.0");
         assert_eq!(filter.specific_tag(), Some("v1.0.0"));
 
-        let filter = ReleaseFilter::Unrestricted;
+        let filter = ReleaseFilter::default();
         assert_eq!(filter.specific_tag(), None);
 
-        let filter = ReleaseFilter::SkipPrereleases;
+        let filter = ReleaseFilter::default().skip_prereleases(true);
         assert_eq!(filter.specific_tag(), None);
     }
 
     #[test]
     fn skips_prereleases_helper() {
-        assert!(!ReleaseFilter::Unrestricted.skips_prereleases());
-        assert!(ReleaseFilter::SkipPrereleases.skips_prereleases());
-        assert!(!ReleaseFilter::Specific("v1.0.0".to_string()).skips_prereleases());
+        assert!(!ReleaseFilter::default().skips_prereleases());
+        assert!(
+            ReleaseFilter::default()
+                .skip_prereleases(true)
+                .skips_prereleases()
+        );
+        assert!(!ReleaseFilter::specific("v1.0.0").skips_prereleases());
+    }
+
+    #[test]
+    fn tag_prefix_keeps_one_component() {
+        let tags = || {
+            vec![
+                make_tag("py-v1.0.0"),
+                make_tag("rust-v1.0.0"),
+                make_tag("v1.0.0"),
+                make_tag("sdk/go/v0.3.0"),
+                make_tag("py-nightly"),
+            ]
+        };
+        let names = |filter: &ReleaseFilter| -> Vec<String> {
+            filter
+                .filter_tags(tags())
+                .into_iter()
+                .map(|t| t.name)
+                .collect()
+        };
+
+        for prefix in ["py", "py-", "py-v"] {
+            let filter = ReleaseFilter::default().tag_prefix(Some(prefix.to_string()));
+            assert_eq!(filter.prefix(), Some("py"));
+            assert_eq!(names(&filter), ["py-v1.0.0"]);
+        }
+
+        let filter = ReleaseFilter::default().tag_prefix(Some("sdk/go/".to_string()));
+        assert_eq!(names(&filter), ["sdk/go/v0.3.0"]);
+
+        // An empty prefix selects unprefixed tags
+        let filter = ReleaseFilter::default().tag_prefix(Some("v".to_string()));
+        assert_eq!(names(&filter), ["v1.0.0"]);
+    }
+
+    #[test]
+    fn allows_release_applies_every_rule() {
+        let filter = ReleaseFilter::default()
+            .skip_prereleases(true)
+            .tag_prefix(Some("rust".to_string()));
+        assert!(filter.allows_release("rust-v1.2.0", false));
+        assert!(!filter.allows_release("rust-v1.3.0-rc.1", true));
+        assert!(!filter.allows_release("py-v1.2.0", false));
+        assert!(ReleaseFilter::default().allows_release("nightly", true));
+    }
+
+    #[test]
+    fn tag_patterns_include_and_exclude() {
+        let tags = || {
+            vec![
+                make_tag("v1.0.0"),
+                make_tag("nightly-2024-01-01"),
+                make_tag("deploy-prod-42"),
+                make_tag("backup/v0.9.0"),
+                make_tag("release-2024"),
+            ]
+        };
+        let names = |filter: &ReleaseFilter| -> Vec<String> {
+            filter
+                .filter_tags(tags())
+                .into_iter()
+                .map(|t| t.name)
+                .collect()
+        };
+
+        let patterns = TagPatterns::new(
+            &[],
+            &[
+                "nightly-*".to_string(),
+                "/^deploy-(prod|staging)-/".to_string(),
+                "backup/*".to_string(),
+            ],
+        )
+        .unwrap();
+        let filter = ReleaseFilter::default().tag_patterns(patterns);
+        assert_eq!(names(&filter), ["v1.0.0", "release-2024"]);
+        assert!(!filter.allows_release("nightly-2024-02-01", false));
+
+        let patterns = TagPatterns::new(&["v*".to_string()], &[]).unwrap();
+        let filter = ReleaseFilter::default().tag_patterns(patterns);
+        assert_eq!(names(&filter), ["v1.0.0"]);
+        assert!(filter.allows_release("v2.0.0", false));
+        assert!(!filter.allows_release("release-2025", false));
+    }
+
+    #[test]
+    fn rejects_invalid_tag_patterns() {
+        assert!(
+            TagPatterns::new(&["v[".to_string()], &[])
+                .unwrap_err()
+                .is_cli()
+        );
+        assert!(
+            TagPatterns::new(&[], &["/(/".to_string()])
+                .unwrap_err()
+                .is_cli()
+        );
+    }
+
+    #[test]
+    fn require_release_drops_plain_tags() {
+        let mut release = make_tag("v1.1.0");
+        release.is_release = true;
+        let tags = vec![make_tag("v1.0.0"), release];
+
+        assert_eq!(
+            ReleaseFilter::default().filter_releases(tags.clone()).len(),
+            2
+        );
+        let filtered = ReleaseFilter::default()
+            .require_release(true)
+            .filter_releases(tags);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "v1.1.0");
     }
 }
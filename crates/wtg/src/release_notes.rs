@@ -0,0 +1,146 @@
+//! Grouping changes into release notes.
+//!
+//! A change is categorized by its PR labels first (e.g. `bug`, `enhancement`),
+//! then by the conventional-commit type of its title (`feat:`, `fix(cli)!:`),
+//! and lands in "Other" if neither says anything.
+//!
+//! See <https://www.conventionalcommits.org> for the commit title format.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::Serialize;
+
+/// Conventional commit header: `type(scope)!: description`
+static CONVENTIONAL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<type>[A-Za-z]+)(?:\([^)]*\))?(?P<breaking>!)?:\s")
+        .expect("Invalid conventional commit regex")
+});
+
+/// Closing keywords followed by a same-repository reference: `fixes #123`
+static CLOSING_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:close[sd]?|fix(?:e[sd])?|resolve[sd]?):?\s+#(?P<number>\d+)\b")
+        .expect("Invalid closing keyword regex")
+});
+
+/// Release notes section, in display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeCategory {
+    Breaking,
+    Features,
+    Fixes,
+    Performance,
+    Documentation,
+    Maintenance,
+    Other,
+}
+
+impl ChangeCategory {
+    /// Category for a label name, if the label means anything to us.
+    fn from_label(label: &str) -> Option<Self> {
+        let label = label.to_ascii_lowercase();
+        let label = label.rsplit([':', '/']).next().unwrap_or(&label).trim();
+
+        match label {
+            "breaking" | "breaking change" | "breaking-change" | "major" => Some(Self::Breaking),
+            "feature" | "enhancement" | "feat" | "new feature" => Some(Self::Features),
+            "bug" | "bugfix" | "fix" | "regression" => Some(Self::Fixes),
+            "performance" | "perf" => Some(Self::Performance),
+            "documentation" | "docs" => Some(Self::Documentation),
+            "chore" | "dependencies" | "deps" | "ci" | "build" | "refactor" | "maintenance"
+            | "internal" | "test" | "tests" => Some(Self::Maintenance),
+            _ => None,
+        }
+    }
+
+    /// Category for a conventional commit type.
+    fn from_commit_type(commit_type: &str) -> Self {
+        match commit_type.to_ascii_lowercase().as_str() {
+            "feat" | "feature" => Self::Features,
+            "fix" | "bugfix" => Self::Fixes,
+            "perf" => Self::Performance,
+            "docs" | "doc" => Self::Documentation,
+            "chore" | "build" | "ci" | "refactor" | "style" | "test" | "tests" | "deps" => {
+                Self::Maintenance
+            }
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Categorize a change from its PR labels and title (PR title or commit subject).
+///
+/// Labels win over the title; among labels, the most important category wins.
+#[must_use]
+pub fn categorize(labels: &[String], title: &str) -> ChangeCategory {
+    if let Some(category) = labels
+        .iter()
+        .filter_map(|label| ChangeCategory::from_label(label))
+        .min()
+    {
+        return category;
+    }
+
+    CONVENTIONAL_REGEX
+        .captures(title.trim())
+        .map_or(ChangeCategory::Other, |caps| {
+            if caps.name("breaking").is_some() {
+                ChangeCategory::Breaking
+            } else {
+                ChangeCategory::from_commit_type(&caps["type"])
+            }
+        })
+}
+
+/// Issue numbers (same repository) closed via keywords like `Fixes #12`.
+#[must_use]
+pub fn closing_issue_numbers(text: &str) -> Vec<u64> {
+    let mut numbers: Vec<u64> = Vec::new();
+    for caps in CLOSING_REGEX.captures_iter(text) {
+        if let Ok(number) = caps["number"].parse()
+            && !numbers.contains(&number)
+        {
+            numbers.push(number);
+        }
+    }
+    numbers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::feat("feat: add batch mode", ChangeCategory::Features)]
+    #[case::scoped_fix("fix(cli): handle empty input", ChangeCategory::Fixes)]
+    #[case::breaking("feat(api)!: drop v1 endpoints", ChangeCategory::Breaking)]
+    #[case::perf("perf: cache tags", ChangeCategory::Performance)]
+    #[case::docs("docs: explain caching", ChangeCategory::Documentation)]
+    #[case::chore("chore(deps): bump octocrab", ChangeCategory::Maintenance)]
+    #[case::uppercase("Fix: crash on startup", ChangeCategory::Fixes)]
+    #[case::plain("Update README", ChangeCategory::Other)]
+    #[case::no_space("fix:typo", ChangeCategory::Other)]
+    fn categorizes_by_conventional_title(#[case] title: &str, #[case] expected: ChangeCategory) {
+        assert_eq!(categorize(&[], title), expected);
+    }
+
+    #[rstest]
+    #[case::bug(&["bug"], ChangeCategory::Fixes)]
+    #[case::prefixed(&["type: enhancement"], ChangeCategory::Features)]
+    #[case::scoped(&["kind/documentation"], ChangeCategory::Documentation)]
+    #[case::most_important_wins(&["dependencies", "breaking"], ChangeCategory::Breaking)]
+    #[case::unknown_falls_back_to_title(&["good first issue"], ChangeCategory::Features)]
+    fn categorizes_by_labels_first(#[case] labels: &[&str], #[case] expected: ChangeCategory) {
+        let labels: Vec<String> = labels.iter().map(ToString::to_string).collect();
+        assert_eq!(categorize(&labels, "feat: something"), expected);
+    }
+
+    #[test]
+    fn finds_closing_issue_references() {
+        let body =
+            "Fixes #12 and closes #34.\nResolved: #12\nSee #56, refs #78\nfixes owner/repo#9";
+        assert_eq!(closing_issue_numbers(body), vec![12, 34]);
+    }
+}
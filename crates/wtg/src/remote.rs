@@ -1,29 +1,105 @@
 //! Remote type definitions for git repository remotes.
 
+use serde::Serialize;
 use url::Url;
 
+use crate::gitea::{self, BUILTIN_HOSTS};
+use crate::github::{self, GITHUB_COM, normalize_host};
+
+/// Self-hosted forges to recognize in URLs and remotes, besides github.com,
+/// gitlab.com, bitbucket.org and the public Gitea instances.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ForgeHosts {
+    /// GitHub Enterprise Server hosts (normalized)
+    github_enterprise: Vec<String>,
+    /// Gitea/Forgejo hosts besides codeberg.org and gitea.com (normalized)
+    gitea: Vec<String>,
+}
+
+impl ForgeHosts {
+    /// No self-hosted forges.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The hosts from `WTG_GITHUB_HOSTS`, every non-github.com host gh CLI is
+    /// logged into, and `WTG_GITEA_HOSTS`.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let mut hosts = Self::new();
+        for host in github::enterprise_hosts_from_env() {
+            hosts.add_github_enterprise_host(&host);
+        }
+        for host in gitea::gitea_hosts_from_env() {
+            hosts.add_gitea_host(&host);
+        }
+        hosts
+    }
+
+    /// Recognize `host` as a GitHub Enterprise Server host.
+    pub fn add_github_enterprise_host(&mut self, host: &str) {
+        let host = normalize_host(host);
+        if !host.is_empty() && host != GITHUB_COM && !self.github_enterprise.contains(&host) {
+            self.github_enterprise.push(host);
+        }
+    }
+
+    /// Recognize `host` as a Gitea/Forgejo host.
+    pub fn add_gitea_host(&mut self, host: &str) {
+        let host = normalize_host(host);
+        if !host.is_empty() && !self.gitea.contains(&host) {
+            self.gitea.push(host);
+        }
+    }
+
+    /// Check whether `host` is a known GitHub Enterprise Server host.
+    #[must_use]
+    pub fn is_github_enterprise_host(&self, host: &str) -> bool {
+        self.github_enterprise.contains(&normalize_host(host))
+    }
+
+    /// Check whether `host` is a known Gitea/Forgejo host.
+    #[must_use]
+    pub fn is_gitea_host(&self, host: &str) -> bool {
+        let host = normalize_host(host);
+        BUILTIN_HOSTS.contains(&host.as_str()) || self.gitea.contains(&host)
+    }
+}
+
 /// The hosting platform for a git remote.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum RemoteHost {
     GitHub,
     GitLab,
     Bitbucket,
+    /// Gitea or Forgejo (codeberg.org, gitea.com or a configured host)
+    Gitea,
 }
 
 impl RemoteHost {
     /// Detect host from a remote URL (HTTP/HTTPS or SSH).
     /// Uses proper URL parsing, not string containment.
+    ///
+    /// Self-hosted forges are those of [`ForgeHosts::from_env`].
     #[must_use]
     pub fn from_url(url: &str) -> Option<Self> {
+        Self::from_url_with_hosts(url, &ForgeHosts::from_env())
+    }
+
+    /// Detect host from a remote URL, recognizing the self-hosted forges in `hosts`.
+    #[must_use]
+    pub fn from_url_with_hosts(url: &str, hosts: &ForgeHosts) -> Option<Self> {
         // Try SSH format first: git@host:path
         if let Some(host) = Self::parse_ssh_host(url) {
-            return Self::from_host_str(&host);
+            return Self::from_host_str(&host, hosts);
         }
 
         // Try HTTP/HTTPS URL parsing
         if let Some(host) = Self::parse_http_host(url) {
-            return Self::from_host_str(&host);
+            return Self::from_host_str(&host, hosts);
         }
 
         None
@@ -68,16 +144,22 @@ impl RemoteHost {
         Url::parse(&prefixed).ok()?.host_str().map(str::to_string)
     }
 
-    /// Map a host string to a `RemoteHost`
-    fn from_host_str(host: &str) -> Option<Self> {
+    /// Map a host string to a `RemoteHost`.
+    /// Configured GitHub Enterprise hosts map to `GitHub`, configured Gitea hosts to `Gitea`.
+    fn from_host_str(host: &str, hosts: &ForgeHosts) -> Option<Self> {
         let normalized = host.trim_start_matches("www.").to_ascii_lowercase();
 
-        if normalized == "github.com" || normalized == "api.github.com" {
+        if normalized == "github.com"
+            || normalized == "api.github.com"
+            || hosts.is_github_enterprise_host(&normalized)
+        {
             Some(Self::GitHub)
         } else if normalized == "gitlab.com" || normalized.ends_with(".gitlab.com") {
             Some(Self::GitLab)
         } else if normalized == "bitbucket.org" || normalized.ends_with(".bitbucket.org") {
             Some(Self::Bitbucket)
+        } else if hosts.is_gitea_host(&normalized) {
+            Some(Self::Gitea)
         } else {
             None
         }
@@ -85,12 +167,14 @@ impl RemoteHost {
 }
 
 /// Which named remote this is.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum RemoteKind {
-    Upstream, // Highest priority (canonical repo in fork workflows)
-    Origin,   // Second priority
-    Other,    // Lowest priority
+    Preferred, // The remote named in the config, ahead of everything else
+    Upstream,  // Highest priority (canonical repo in fork workflows)
+    Origin,    // Second priority
+    Other,     // Lowest priority
 }
 
 impl RemoteKind {
@@ -105,7 +189,7 @@ impl RemoteKind {
 }
 
 /// Information about a git remote.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RemoteInfo {
     pub name: String,
     pub url: String,
@@ -115,7 +199,7 @@ pub struct RemoteInfo {
 
 impl RemoteInfo {
     /// Priority for sorting (lower = higher priority).
-    /// Upstream < Origin < Other, within same kind: GitHub first.
+    /// Preferred < Upstream < Origin < Other, within same kind: GitHub first.
     #[must_use]
     pub fn priority(&self) -> (RemoteKind, bool) {
         (self.kind, self.host != Some(RemoteHost::GitHub))
@@ -190,6 +274,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_remote_host_gitea_variants() {
+        assert_eq!(
+            RemoteHost::from_url("https://codeberg.org/owner/repo.git"),
+            Some(RemoteHost::Gitea)
+        );
+        let mut hosts = ForgeHosts::new();
+        hosts.add_gitea_host("forgejo.remote-test.example");
+        assert_eq!(
+            RemoteHost::from_url_with_hosts(
+                "git@forgejo.remote-test.example:owner/repo.git",
+                &hosts
+            ),
+            Some(RemoteHost::Gitea)
+        );
+    }
+
+    #[test]
+    fn test_remote_host_github_enterprise() {
+        let mut hosts = ForgeHosts::new();
+        hosts.add_github_enterprise_host("ghe.remote-test.example");
+        assert_eq!(
+            RemoteHost::from_url_with_hosts(
+                "https://ghe.remote-test.example/owner/repo.git",
+                &hosts
+            ),
+            Some(RemoteHost::GitHub)
+        );
+        assert_eq!(
+            RemoteHost::from_url_with_hosts("git@ghe.remote-test.example:owner/repo.git", &hosts),
+            Some(RemoteHost::GitHub)
+        );
+        assert_eq!(
+            RemoteHost::from_url_with_hosts(
+                "git@ghe.remote-test.example:owner/repo.git",
+                &ForgeHosts::new()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_forge_hosts() {
+        let mut hosts = ForgeHosts::new();
+        assert!(hosts.is_gitea_host("codeberg.org"));
+        assert!(!hosts.is_gitea_host("forgejo.hosts-test.example"));
+        assert!(!hosts.is_github_enterprise_host("ghe.hosts-test.example"));
+
+        hosts.add_gitea_host("https://Forgejo.hosts-test.example/");
+        hosts.add_github_enterprise_host("https://GHE.hosts-test.example/");
+        assert!(hosts.is_gitea_host("forgejo.hosts-test.example"));
+        assert!(hosts.is_github_enterprise_host("ghe.hosts-test.example"));
+
+        // github.com is never an Enterprise host
+        hosts.add_github_enterprise_host("github.com");
+        assert!(!hosts.is_github_enterprise_host("github.com"));
+    }
+
     #[test]
     fn test_remote_host_unknown() {
         assert_eq!(RemoteHost::from_url("https://example.com/owner/repo"), None);
@@ -243,4 +385,22 @@ mod tests {
         // GitHub upstream beats non-GitHub origin
         assert!(github_upstream.priority() < gitlab_origin.priority());
     }
+
+    #[test]
+    fn test_preferred_remote_priority() {
+        let upstream = RemoteInfo {
+            name: "upstream".to_string(),
+            url: "https://github.com/owner/repo".to_string(),
+            kind: RemoteKind::Upstream,
+            host: Some(RemoteHost::GitHub),
+        };
+        let preferred = RemoteInfo {
+            name: "preferred-remote-test".to_string(),
+            url: "https://gitlab.com/owner/repo".to_string(),
+            kind: RemoteKind::Preferred,
+            host: Some(RemoteHost::GitLab),
+        };
+
+        assert!(preferred.priority() < upstream.priority());
+    }
 }
@@ -4,31 +4,54 @@
 //! to identified information using backend implementations. It also defines
 //! the types for representing resolved information.
 
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use serde::Serialize;
+
 use crate::backend::Backend;
+use crate::branch_filter::BranchFilter;
 use crate::changelog;
 use crate::error::{WtgError, WtgResult};
-use crate::git::{CommitInfo, FileInfo, TagInfo};
+use crate::git::{BranchInfo, CommitInfo, FileInfo, PathsInfo, TagInfo};
 use crate::github::{ExtendedIssueInfo, PullRequestInfo};
 use crate::notice::Notice;
-use crate::parse_input::Query;
+use crate::parse_input::{LineRange, Query};
 use crate::release_filter::ReleaseFilter;
+use crate::release_notes::{self, ChangeCategory};
+
+/// How many commits of a release range, file history or blame are mapped to PRs concurrently.
+const PR_LOOKUP_CONCURRENCY: usize = 8;
+
+/// Most commits of a release range mapped to PRs (one request each without a local clone).
+const MAX_RANGE_PR_LOOKUPS: usize = 300;
 
 // ============================================
 // Result types
 // ============================================
 
 /// What the user entered to search for
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
 pub enum EntryPoint {
-    Commit(String),                            // Hash they entered
-    IssueNumber(u64),                          // Issue # they entered
-    PullRequestNumber(u64),                    // PR # they entered
-    FilePath { branch: String, path: String }, // File path they entered
-    Tag(String),                               // Tag they entered
+    Commit(String),         // Hash they entered
+    IssueNumber(u64),       // Issue # they entered
+    PullRequestNumber(u64), // PR # they entered
+    FilePath {
+        branch: String,
+        path: String,
+    }, // File path they entered
+    FileLines {
+        branch: String,
+        path: String,
+        lines: LineRange,
+    }, // Lines of a file they entered (`path:42`, `#L42-L50`)
+    Tag(String),            // Tag they entered
 }
 
 /// Information about an Issue
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IssueInfo {
     pub number: u64,
     pub title: String,
@@ -57,7 +80,7 @@ impl From<&ExtendedIssueInfo> for IssueInfo {
 }
 
 /// The enriched result of identification - progressively accumulates data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct EnrichedInfo {
     pub entry_point: EntryPoint,
 
@@ -72,10 +95,26 @@ pub struct EnrichedInfo {
 
     // Metadata
     pub release: Option<TagInfo>,
+
+    /// Every release line shipping the change, including cherry-picked copies
+    pub releases: Vec<ReleaseLine>,
+
+    /// Branches containing the commit (only when branch containment was requested)
+    pub branches: Option<Vec<BranchInfo>>,
+}
+
+/// A release that ships a change, directly or through a cherry-picked copy.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReleaseLine {
+    pub release: TagInfo,
+    /// The commit the release contains: the resolved one or an equivalent copy
+    pub commit_hash: String,
+    /// Whether the release ships a later copy of the change rather than the original
+    pub is_backport: bool,
 }
 
 /// For file results (special case with blame history)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FileResult {
     pub file_info: FileInfo,
     pub commit_url: Option<String>,
@@ -83,10 +122,85 @@ pub struct FileResult {
     pub release: Option<TagInfo>,
 }
 
+/// For directory and glob results: the latest change and who's been busy there
+#[derive(Debug, Clone, Serialize)]
+pub struct PathsResult {
+    pub paths_info: PathsInfo,
+    pub commit_url: Option<String>,
+    pub contributor_urls: Vec<Option<String>>,
+    pub release: Option<TagInfo>,
+}
+
+/// A commit with the PR that merged it and the first release shipping it.
+#[derive(Debug, Clone, Serialize)]
+pub struct CommitOrigin {
+    pub commit: CommitInfo,
+    pub pr: Option<PullRequestInfo>,
+    pub release: Option<TagInfo>,
+}
+
+/// Which page of a file's history to show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct HistoryPage {
+    /// 1-based page number
+    pub page: usize,
+    pub per_page: usize,
+}
+
+impl HistoryPage {
+    /// Number of changes on earlier pages.
+    #[must_use]
+    pub const fn skip(&self) -> usize {
+        self.page.saturating_sub(1) * self.per_page
+    }
+}
+
+/// One commit in a file's history.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileHistoryEntry {
+    /// Path of the file in this commit (differs from the query before a rename)
+    pub path: String,
+    #[serde(flatten)]
+    pub origin: CommitOrigin,
+}
+
+/// A page of the commits that changed a file, most recent first.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileHistoryResult {
+    pub path: String,
+    pub page: HistoryPage,
+    pub entries: Vec<FileHistoryEntry>,
+    /// Whether older changes are left for the next page
+    pub has_more: bool,
+}
+
+/// Lines of a file that were last changed by the same commit.
+#[derive(Debug, Clone, Serialize)]
+pub struct LineBlame {
+    pub start_line: usize,
+    pub end_line: usize,
+    /// Path of the file in the commit that introduced the lines
+    pub path: String,
+    pub lines: Vec<String>,
+    /// The commit that introduced the lines, enriched like a commit query
+    pub info: EnrichedInfo,
+}
+
+/// Who introduced some lines of a file, in which PR and release.
+#[derive(Debug, Clone, Serialize)]
+pub struct LinesResult {
+    pub path: String,
+    pub lines: LineRange,
+    /// Hunks in line order
+    pub hunks: Vec<LineBlame>,
+}
+
 /// Source of changes information for a tag
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ChangesSource {
     /// From GitHub release description
+    #[serde(rename = "github_release")]
     GitHubRelease,
     /// From CHANGELOG.md
     Changelog,
@@ -95,7 +209,7 @@ pub enum ChangesSource {
 }
 
 /// Enriched tag result with changes information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TagResult {
     pub tag_info: TagInfo,
     pub github_url: Option<String>,
@@ -107,13 +221,48 @@ pub struct TagResult {
     pub truncated_lines: usize,
     /// Commits between this tag and previous (when source is Commits)
     pub commits: Vec<CommitInfo>,
+    /// The time the tag was looked up for, when it's the release current then (`@date`)
+    pub current_at: Option<DateTime<Utc>>,
+}
+
+/// One release notes entry: a merged PR, or a commit that didn't come from one.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReleaseNote {
+    pub category: ChangeCategory,
+    /// PR title, or the commit subject for commits without a PR
+    pub title: String,
+    pub pr: Option<PullRequestInfo>,
+    /// Issues the PR closed
+    pub issues: Vec<IssueInfo>,
+    /// Commits of the range that belong to this entry, most recent first
+    pub commits: Vec<CommitInfo>,
 }
 
-#[derive(Debug, Clone)]
+/// Everything shipped between two tags, grouped as release notes.
+#[derive(Debug, Clone, Serialize)]
+pub struct RangeResult {
+    pub from_tag: TagInfo,
+    pub to_tag: TagInfo,
+    /// Number of commits in the range
+    pub commit_count: usize,
+    /// Entries sorted by category, most recent first within a category
+    pub notes: Vec<ReleaseNote>,
+}
+
+/// Final result of resolving a query.
+///
+/// Serializes as the wrapped result object with an extra `kind` field
+/// (`enriched`, `file`, `file_history`, `lines`, `tag` or `range`).
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum IdentifiedThing {
     Enriched(Box<EnrichedInfo>),
     File(Box<FileResult>),
+    Paths(Box<PathsResult>),
+    FileHistory(Box<FileHistoryResult>),
+    Lines(Box<LinesResult>),
     Tag(Box<TagResult>),
+    Range(Box<RangeResult>),
 }
 
 // ============================================
@@ -153,7 +302,59 @@ pub async fn resolve(
         Query::FilePath { branch, path } => {
             resolve_file(backend, branch, &path.to_string_lossy(), filter).await
         }
+        Query::FileLines {
+            branch,
+            path,
+            lines,
+        } => resolve_lines(backend, branch, &path.to_string_lossy(), *lines, filter).await,
+        Query::PathGlob { branch, pattern } => {
+            resolve_paths(backend, branch, pattern, filter).await
+        }
         Query::Tag(tag) => resolve_tag(backend, tag).await,
+        Query::TagRange { from, to } => resolve_range(backend, from, to).await,
+        Query::ReleaseAt(at) => {
+            let release = release_at(backend, *at, filter).await?;
+            let mut result = resolve_tag(backend, &release.name).await?;
+            if let IdentifiedThing::Tag(tag) = &mut result {
+                tag.current_at = Some(*at);
+            }
+            Ok(result)
+        }
+        Query::DateRange { from, to } => {
+            let from = release_at(backend, *from, filter).await?;
+            let to = release_at(backend, *to, filter).await?;
+            resolve_range(backend, &from.name, &to.name).await
+        }
+    }
+}
+
+/// The release current at `at`.
+async fn release_at(
+    backend: &dyn Backend,
+    at: DateTime<Utc>,
+    filter: &ReleaseFilter,
+) -> WtgResult<TagInfo> {
+    backend
+        .find_release_at(at, filter)
+        .await?
+        .ok_or(WtgError::NoReleaseAt(at))
+}
+
+/// Report which branches contain the resolved commit.
+///
+/// Only affects enriched results that found a commit; `branches` stays `None`
+/// if the backend can't tell.
+pub async fn resolve_branches(
+    backend: &dyn Backend,
+    thing: &mut IdentifiedThing,
+    filter: &BranchFilter,
+) {
+    if let IdentifiedThing::Enriched(info) = thing
+        && let Some(commit) = &info.commit
+    {
+        info.branches = backend
+            .branches_containing_commit(&commit.hash, filter)
+            .await;
     }
 }
 
@@ -165,9 +366,7 @@ async fn resolve_commit(
 ) -> WtgResult<IdentifiedThing> {
     let commit = backend.find_commit(hash).await?;
     let commit = backend.enrich_commit(commit).await;
-    let release = backend
-        .find_release_for_commit(&commit.hash, Some(commit.date), filter)
-        .await;
+    let (release, releases) = find_releases(backend, &commit, filter).await;
 
     Ok(IdentifiedThing::Enriched(Box::new(EnrichedInfo {
         entry_point: EntryPoint::Commit(hash.to_string()),
@@ -175,6 +374,8 @@ async fn resolve_commit(
         pr: None,
         issue: None,
         release,
+        releases,
+        branches: None,
     })))
 }
 
@@ -192,12 +393,10 @@ async fn resolve_pr(
         None => None,
     };
 
-    let release = if let Some(ref c) = commit {
-        backend
-            .find_release_for_commit(&c.hash, Some(c.date), filter)
-            .await
+    let (release, releases) = if let Some(ref c) = commit {
+        find_releases(backend, c, filter).await
     } else {
-        None
+        (None, Vec::new())
     };
 
     Ok(IdentifiedThing::Enriched(Box::new(EnrichedInfo {
@@ -206,6 +405,8 @@ async fn resolve_pr(
         pr: Some(pr),
         issue: None,
         release,
+        releases,
+        branches: None,
     })))
 }
 
@@ -223,7 +424,7 @@ async fn resolve_issue(
     // Try to find closing PR info
     let closing_pr = ext_issue.closing_prs.into_iter().next();
 
-    let (commit, release) = if let Some(ref pr) = closing_pr {
+    let (commit, release, releases) = if let Some(ref pr) = closing_pr {
         if let Some(merge_sha) = &pr.merge_commit_sha {
             // Get backend for PR (returns cross-project backend if needed, None if same repo)
             let cross_backend = backend.backend_for_pr(pr).await;
@@ -248,32 +449,41 @@ async fn resolve_issue(
                 }
             };
 
-            let release = if let Some(ref c) = commit {
+            let (release, releases) = if let Some(ref c) = commit {
                 let hash = &c.hash;
                 let date = Some(c.date);
                 // Try issue's repo first, fall back to PR's repo for releases
                 if is_cross_project {
-                    match backend.find_release_for_commit(hash, date, filter).await {
+                    let release = match backend.find_release_for_commit(hash, date, filter).await {
                         Some(r) => Some(r),
                         None => {
                             effective_backend
                                 .find_release_for_commit(hash, date, filter)
                                 .await
                         }
-                    }
+                    };
+                    let releases = release
+                        .iter()
+                        .map(|r| ReleaseLine {
+                            release: r.clone(),
+                            commit_hash: c.hash.clone(),
+                            is_backport: false,
+                        })
+                        .collect();
+                    (release, releases)
                 } else {
-                    backend.find_release_for_commit(hash, date, filter).await
+                    find_releases(backend, c, filter).await
                 }
             } else {
-                None
+                (None, Vec::new())
             };
 
-            (commit, release)
+            (commit, release, releases)
         } else {
-            (None, None)
+            (None, None, Vec::new())
         }
     } else {
-        (None, None)
+        (None, None, Vec::new())
     };
 
     Ok(IdentifiedThing::Enriched(Box::new(EnrichedInfo {
@@ -282,9 +492,96 @@ async fn resolve_issue(
         pr: closing_pr,
         issue: Some(display_issue),
         release,
+        releases,
+        branches: None,
     })))
 }
 
+/// Find the release of a commit and every release line shipping the change.
+///
+/// Release lines come from the commit itself and from its cherry-picked copies
+/// (see [`Backend::find_equivalent_commits`]); the earliest-committed copy is the
+/// original and the others are backports. When the filter asks for release lines,
+/// every commit also contributes the earliest release per tag prefix and major
+/// version. The returned release is the commit's own best release, or the
+/// earliest release of a copy if the commit itself isn't released.
+///
+/// Copies are only looked for when they can change the answer: when the
+/// commit isn't released itself, or when release lines are requested.
+async fn find_releases(
+    backend: &dyn Backend,
+    commit: &CommitInfo,
+    filter: &ReleaseFilter,
+) -> (Option<TagInfo>, Vec<ReleaseLine>) {
+    let own_releases = releases_of(backend, commit, filter).await;
+    // Lines come earliest first, so the first released one is the best overall
+    let release = own_releases
+        .iter()
+        .find(|tag| tag.is_release)
+        .or_else(|| own_releases.first())
+        .cloned();
+
+    // The earliest-committed copy of the change is the original
+    let copies = if release.is_none() || filter.reports_release_lines() {
+        backend.find_equivalent_commits(&commit.hash).await
+    } else {
+        Vec::new()
+    };
+    let original = copies
+        .iter()
+        .chain(std::iter::once(commit))
+        .min_by_key(|c| c.date)
+        .map_or_else(|| commit.hash.clone(), |c| c.hash.clone());
+
+    let mut releases: Vec<ReleaseLine> = own_releases
+        .into_iter()
+        .map(|tag| ReleaseLine {
+            release: tag,
+            commit_hash: commit.hash.clone(),
+            is_backport: commit.hash != original,
+        })
+        .collect();
+
+    let mut copy_releases = Vec::new();
+    for copy in copies {
+        for tag in releases_of(backend, &copy, filter).await {
+            copy_releases.push(ReleaseLine {
+                release: tag,
+                is_backport: copy.hash != original,
+                commit_hash: copy.hash.clone(),
+            });
+        }
+    }
+    copy_releases.sort_by_key(|line| line.release.created_at);
+    for line in copy_releases {
+        if !releases.iter().any(|r| r.release.name == line.release.name) {
+            releases.push(line);
+        }
+    }
+
+    let release = release.or_else(|| releases.first().map(|line| line.release.clone()));
+    (release, releases)
+}
+
+/// The best release of a commit, or the earliest of every release line if requested.
+async fn releases_of(
+    backend: &dyn Backend,
+    commit: &CommitInfo,
+    filter: &ReleaseFilter,
+) -> Vec<TagInfo> {
+    if filter.reports_release_lines() {
+        backend
+            .find_releases_for_commit(&commit.hash, Some(commit.date), filter)
+            .await
+    } else {
+        backend
+            .find_release_for_commit(&commit.hash, Some(commit.date), filter)
+            .await
+            .into_iter()
+            .collect()
+    }
+}
+
 /// Resolve a file path to `IdentifiedThing`.
 async fn resolve_file(
     backend: &dyn Backend,
@@ -318,6 +615,172 @@ async fn resolve_file(
     })))
 }
 
+async fn resolve_paths(
+    backend: &dyn Backend,
+    branch: &str,
+    pattern: &str,
+    filter: &ReleaseFilter,
+) -> WtgResult<IdentifiedThing> {
+    let paths_info = backend.find_paths(branch, pattern).await?;
+    let commit_url = backend.commit_url(&paths_info.last_commit.hash);
+
+    let contributor_urls = paths_info
+        .top_contributors
+        .iter()
+        .map(|contributor| backend.author_url_from_email(&contributor.email))
+        .collect();
+
+    let release = backend
+        .find_release_for_commit(
+            &paths_info.last_commit.hash,
+            Some(paths_info.last_commit.date),
+            filter,
+        )
+        .await;
+
+    Ok(IdentifiedThing::Paths(Box::new(PathsResult {
+        paths_info,
+        commit_url,
+        contributor_urls,
+        release,
+    })))
+}
+
+/// Resolve a page of a file's history, each commit with its PR and release.
+pub async fn resolve_file_history(
+    backend: &dyn Backend,
+    branch: &str,
+    path: &str,
+    page: HistoryPage,
+    filter: &ReleaseFilter,
+) -> WtgResult<IdentifiedThing> {
+    // One extra change tells whether there's another page
+    let mut changes = backend
+        .file_history(branch, path, page.skip(), page.per_page + 1)
+        .await?;
+    let has_more = changes.len() > page.per_page;
+    changes.truncate(page.per_page);
+
+    let entries = stream::iter(changes)
+        .map(|change| async move {
+            FileHistoryEntry {
+                path: change.path,
+                origin: commit_origin(backend, change.commit, filter).await,
+            }
+        })
+        .buffered(PR_LOOKUP_CONCURRENCY)
+        .collect()
+        .await;
+
+    Ok(IdentifiedThing::FileHistory(Box::new(FileHistoryResult {
+        path: path.to_string(),
+        page,
+        entries,
+        has_more,
+    })))
+}
+
+/// Resolve some lines of a file to the commits, PRs and releases that introduced them.
+///
+/// Each blamed commit goes through the same enrichment as a commit query, plus
+/// the issue its PR closed.
+async fn resolve_lines(
+    backend: &dyn Backend,
+    branch: &str,
+    path: &str,
+    lines: LineRange,
+    filter: &ReleaseFilter,
+) -> WtgResult<IdentifiedThing> {
+    let mut hunks = backend.blame_lines(branch, path, lines).await?;
+    hunks.sort_by_key(|hunk| hunk.start_line);
+
+    // Look every commit up once, however many hunks it has
+    let mut commits: Vec<CommitInfo> = Vec::new();
+    for hunk in &hunks {
+        if !commits.iter().any(|c| c.hash == hunk.commit.hash) {
+            commits.push(hunk.commit.clone());
+        }
+    }
+    let entry_point = EntryPoint::FileLines {
+        branch: branch.to_string(),
+        path: path.to_string(),
+        lines,
+    };
+    let infos: Vec<EnrichedInfo> = stream::iter(commits)
+        .map(|commit| enrich_blamed_commit(backend, commit, entry_point.clone(), filter))
+        .buffered(PR_LOOKUP_CONCURRENCY)
+        .collect()
+        .await;
+
+    let hunks = hunks
+        .into_iter()
+        .filter_map(|hunk| {
+            let info = infos
+                .iter()
+                .find(|info| {
+                    info.commit
+                        .as_ref()
+                        .is_some_and(|c| c.hash == hunk.commit.hash)
+                })?
+                .clone();
+            Some(LineBlame {
+                start_line: hunk.start_line,
+                end_line: hunk.end_line,
+                path: hunk.path,
+                lines: hunk.lines,
+                info,
+            })
+        })
+        .collect();
+
+    Ok(IdentifiedThing::Lines(Box::new(LinesResult {
+        path: path.to_string(),
+        lines,
+        hunks,
+    })))
+}
+
+/// Enrich a blamed commit with its PR, the issue the PR closed and its releases.
+async fn enrich_blamed_commit(
+    backend: &dyn Backend,
+    commit: CommitInfo,
+    entry_point: EntryPoint,
+    filter: &ReleaseFilter,
+) -> EnrichedInfo {
+    let commit = backend.enrich_commit(commit).await;
+    let pr = backend.find_pr_for_commit(&commit.hash).await;
+    let issue = closed_issues(backend, pr.as_ref()).await.into_iter().next();
+    let (release, releases) = find_releases(backend, &commit, filter).await;
+
+    EnrichedInfo {
+        entry_point,
+        commit: Some(commit),
+        pr,
+        issue,
+        release,
+        releases,
+        branches: None,
+    }
+}
+
+/// Enrich a commit and find the PR that merged it and its first release.
+async fn commit_origin(
+    backend: &dyn Backend,
+    commit: CommitInfo,
+    filter: &ReleaseFilter,
+) -> CommitOrigin {
+    let commit = backend.enrich_commit(commit).await;
+    let pr = backend.find_pr_for_commit(&commit.hash).await;
+    let release = backend
+        .find_release_for_commit(&commit.hash, Some(commit.date), filter)
+        .await;
+    CommitOrigin {
+        commit,
+        pr,
+        release,
+    }
+}
+
 /// Select the best changes source, falling back to commits if needed.
 async fn select_best_changes(
     backend: &dyn Backend,
@@ -413,5 +876,122 @@ async fn resolve_tag(backend: &dyn Backend, name: &str) -> WtgResult<IdentifiedT
         changes_source: source,
         truncated_lines: truncated,
         commits,
+        current_at: None,
+    })))
+}
+
+/// Resolve a release range to release notes.
+///
+/// Every commit after `from` up to `to` is mapped to the merged PR that introduced
+/// it; commits of the same PR are folded into one entry, along with the issues the
+/// PR closed. Commits without a PR get an entry of their own.
+async fn resolve_range(backend: &dyn Backend, from: &str, to: &str) -> WtgResult<IdentifiedThing> {
+    let from_tag = backend
+        .find_tag(from)
+        .await
+        .map_err(|_| WtgError::TagNotFound(from.to_string()))?;
+    let to_tag = backend
+        .find_tag(to)
+        .await
+        .map_err(|_| WtgError::TagNotFound(to.to_string()))?;
+
+    let commits = backend.commits_between_tags(from, to, usize::MAX).await?;
+    let commit_count = commits.len();
+
+    // Every commit costs a request, so only the newest ones are looked up
+    if commit_count > MAX_RANGE_PR_LOOKUPS {
+        backend.emit_notice(Notice::RangePrLookupLimited {
+            checked: MAX_RANGE_PR_LOOKUPS,
+            total: commit_count,
+        });
+    }
+
+    // Streams run over owned items: borrowing ones keep the future from being `Send`
+    let hashes: Vec<String> = commits
+        .iter()
+        .take(MAX_RANGE_PR_LOOKUPS)
+        .map(|commit| commit.hash.clone())
+        .collect();
+    let mut prs: Vec<Option<PullRequestInfo>> = stream::iter(hashes)
+        .map(|hash| async move {
+            // Once rate limited, the remaining lookups would fail one by one
+            if backend.is_rate_limited() {
+                return None;
+            }
+            backend.find_pr_for_commit(&hash).await
+        })
+        .buffered(PR_LOOKUP_CONCURRENCY)
+        .collect()
+        .await;
+    prs.resize(commit_count, None);
+
+    // Fold commits into entries, keeping the order of first appearance
+    let mut notes: Vec<ReleaseNote> = Vec::new();
+    let mut note_for_pr: HashMap<u64, usize> = HashMap::new();
+    for (commit, pr) in commits.into_iter().zip(prs) {
+        if let Some(pr) = pr {
+            if let Some(&index) = note_for_pr.get(&pr.number) {
+                notes[index].commits.push(commit);
+                continue;
+            }
+            note_for_pr.insert(pr.number, notes.len());
+            notes.push(ReleaseNote {
+                category: release_notes::categorize(&pr.labels, &pr.title),
+                title: pr.title.clone(),
+                pr: Some(pr),
+                issues: Vec::new(),
+                commits: vec![commit],
+            });
+        } else {
+            notes.push(ReleaseNote {
+                category: release_notes::categorize(&[], &commit.message),
+                title: commit.message.clone(),
+                pr: None,
+                issues: Vec::new(),
+                commits: vec![commit],
+            });
+        }
+    }
+
+    let prs: Vec<Option<PullRequestInfo>> = notes.iter().map(|note| note.pr.clone()).collect();
+    let issues: Vec<Vec<IssueInfo>> = stream::iter(prs)
+        .map(|pr| async move {
+            if backend.is_rate_limited() {
+                return Vec::new();
+            }
+            closed_issues(backend, pr.as_ref()).await
+        })
+        .buffered(PR_LOOKUP_CONCURRENCY)
+        .collect()
+        .await;
+    for (note, issues) in notes.iter_mut().zip(issues) {
+        note.issues = issues;
+    }
+
+    // Stable sort keeps the most recent entries first within each category
+    notes.sort_by_key(|note| note.category);
+
+    Ok(IdentifiedThing::Range(Box::new(RangeResult {
+        from_tag,
+        to_tag,
+        commit_count,
+        notes,
     })))
 }
+
+/// Issues a PR closed, per the closing keywords in its title and description.
+async fn closed_issues(backend: &dyn Backend, pr: Option<&PullRequestInfo>) -> Vec<IssueInfo> {
+    let Some(pr) = pr else {
+        return Vec::new();
+    };
+    let text = format!("{}\n{}", pr.title, pr.body.as_deref().unwrap_or_default());
+
+    let mut issues = Vec::new();
+    for number in release_notes::closing_issue_numbers(&text) {
+        // The number may well be another PR, which isn't an issue fix
+        if let Ok(issue) = backend.fetch_issue(number).await {
+            issues.push(IssueInfo::from(&issue));
+        }
+    }
+    issues
+}
@@ -3,10 +3,14 @@
 use std::sync::LazyLock;
 
 use regex::Regex;
+use serde::Serialize;
 
 /// Parsed semantic version information from a tag.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct SemverInfo {
+    /// Component prefix without its separator: `py` for `py-v1.2.0`,
+    /// `sdk/go` for `sdk/go/v1.2.3`
+    pub prefix: Option<String>,
     pub major: u32,
     pub minor: u32,
     pub patch: Option<u32>,
@@ -17,6 +21,11 @@ pub struct SemverInfo {
 
 impl Ord for SemverInfo {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Versions of different components don't interleave
+        match self.prefix.cmp(&other.prefix) {
+            std::cmp::Ordering::Equal => {}
+            ord => return ord,
+        }
         match self.major.cmp(&other.major) {
             std::cmp::Ordering::Equal => {}
             ord => return ord,
@@ -51,14 +60,14 @@ impl PartialOrd for SemverInfo {
 
 /// Regex for parsing semantic version tags.
 /// Supports:
-/// - Optional prefix: py-, rust-, python-, etc.
+/// - Optional component prefix: py-, rust-, python-, or slash-style component/, sdk/go/
 /// - Optional 'v' prefix
 /// - Version: X.Y, X.Y.Z, X.Y.Z.W
 /// - Pre-release: -alpha, -beta.1, -rc.1 (dash style) OR a1, b1, rc1 (Python style)
 /// - Build metadata: +build.123
 static SEMVER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(
-        r"^(?:[a-z]+-)?v?(\d+)\.(\d+)(?:\.(\d+))?(?:\.(\d+))?(?:(?:-([a-zA-Z0-9.-]+))|(?:([a-z]+)(\d+)))?(?:\+(.+))?$"
+        r"^(?:(?P<dash_prefix>[a-z][a-z0-9_]*)-|(?P<path_prefix>[a-z0-9][a-z0-9_.-]*(?:/[a-z0-9_.-]+)*)/)?v?(?P<major>\d+)\.(?P<minor>\d+)(?:\.(?P<patch>\d+))?(?:\.(?P<build>\d+))?(?:(?:-(?P<pre>[a-zA-Z0-9.-]+))|(?:(?P<py_pre>[a-z]+)(?P<py_num>\d+)))?(?:\+(?P<metadata>.+))?$"
     )
     .expect("Invalid semver regex")
 });
@@ -73,22 +82,27 @@ static SEMVER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
 /// - Build metadata: 1.0.0+build.123
 /// - With or without 'v' prefix (e.g., v1.0.0)
 /// - With custom prefixes (e.g., py-v1.0.0, rust-v1.0.0, python-1.0.0)
+/// - With slash-style component prefixes (e.g., component/v1.2.3, sdk/go/v1.2.3)
 pub fn parse_semver(tag: &str) -> Option<SemverInfo> {
     let caps = SEMVER_REGEX.captures(tag)?;
 
-    let major = caps.get(1)?.as_str().parse::<u32>().ok()?;
-    let minor = caps.get(2)?.as_str().parse::<u32>().ok()?;
-    let patch = caps.get(3).and_then(|m| m.as_str().parse::<u32>().ok());
-    let build = caps.get(4).and_then(|m| m.as_str().parse::<u32>().ok());
+    let major = caps.name("major")?.as_str().parse::<u32>().ok()?;
+    let minor = caps.name("minor")?.as_str().parse::<u32>().ok()?;
+    let patch = caps
+        .name("patch")
+        .and_then(|m| m.as_str().parse::<u32>().ok());
+    let build = caps
+        .name("build")
+        .and_then(|m| m.as_str().parse::<u32>().ok());
 
     // Pre-release can be either:
-    // - Group 5: dash-style (-alpha, -beta.1, -rc.1)
-    // - Groups 6+7: Python-style (a1, b1, rc1)
-    let pre_release = caps.get(5).map_or_else(
+    // - `pre`: dash-style (-alpha, -beta.1, -rc.1)
+    // - `py_pre` + `py_num`: Python-style (a1, b1, rc1)
+    let pre_release = caps.name("pre").map_or_else(
         || {
-            caps.get(6).map(|py_pre| {
+            caps.name("py_pre").map(|py_pre| {
                 let py_num = caps
-                    .get(7)
+                    .name("py_num")
                     .map_or(String::new(), |m| m.as_str().to_string());
                 format!("{}{}", py_pre.as_str(), py_num)
             })
@@ -96,9 +110,15 @@ pub fn parse_semver(tag: &str) -> Option<SemverInfo> {
         |dash_pre| Some(dash_pre.as_str().to_string()),
     );
 
-    let build_metadata = caps.get(8).map(|m| m.as_str().to_string());
+    let build_metadata = caps.name("metadata").map(|m| m.as_str().to_string());
+
+    let prefix = caps
+        .name("dash_prefix")
+        .or_else(|| caps.name("path_prefix"))
+        .map(|m| m.as_str().to_string());
 
     Some(SemverInfo {
+        prefix,
         major,
         minor,
         patch,
@@ -267,6 +287,8 @@ mod tests {
         assert!(is_semver_tag("py-v1.0.0"));
         assert!(is_semver_tag("rust-v1.2.3-beta.1"));
         assert!(is_semver_tag("python-1.2.3b1"));
+        assert!(is_semver_tag("component/v1.2.3"));
+        assert!(is_semver_tag("sdk/go-client/v0.4.0"));
 
         // Invalid
         assert!(!is_semver_tag("v1"));
@@ -304,6 +326,39 @@ mod tests {
         assert_eq!(semver.patch, Some(0));
     }
 
+    #[test]
+    fn test_parse_semver_captures_prefix() {
+        let prefix = |tag: &str| parse_semver(tag).unwrap().prefix;
+
+        assert_eq!(prefix("v1.2.3"), None);
+        assert_eq!(prefix("1.2"), None);
+        assert_eq!(prefix("py-v1.2.0"), Some("py".to_string()));
+        assert_eq!(prefix("python-2.1.0"), Some("python".to_string()));
+        assert_eq!(prefix("component/v1.2.3"), Some("component".to_string()));
+        assert_eq!(
+            prefix("sdk/go-client/v0.4.0"),
+            Some("sdk/go-client".to_string())
+        );
+        assert_eq!(prefix("tools/v2/v2.1.0"), Some("tools/v2".to_string()));
+
+        let semver = parse_semver("component/v1.2.3-rc.1").unwrap();
+        assert_eq!(semver.major, 1);
+        assert_eq!(semver.patch, Some(3));
+        assert_eq!(semver.pre_release, Some("rc.1".to_string()));
+    }
+
+    #[test]
+    fn test_semver_ordering_groups_by_prefix() {
+        let py_v2 = parse_semver("py-v2.0.0").unwrap();
+        let rust_v1 = parse_semver("rust-v1.0.0").unwrap();
+        let rust_v1_1 = parse_semver("rust-v1.1.0").unwrap();
+
+        // Same component compares by version
+        assert!(rust_v1 < rust_v1_1);
+        // Different components never compare equal
+        assert_ne!(py_v2.cmp(&rust_v1), std::cmp::Ordering::Equal);
+    }
+
     #[test]
     fn test_parse_semver_python_style() {
         // Alpha
@@ -0,0 +1,124 @@
+//! A fake GitHub REST API for offline tests.
+//!
+//! Routes come from `tests/fixtures/github/<scenario>.json`: an object mapping
+//! request paths to the JSON GitHub answers with. A route without a query
+//! string answers whatever the query; one with a query string (`releases?page=2`)
+//! only answers requests carrying those parameters, and takes precedence, so
+//! paginated listings get one route per page. Anything else gets GitHub's 404.
+//! Tests mount error responses (SAML, rate limits) on top of a scenario.
+
+use std::path::PathBuf;
+
+use serde_json::{Map, Value, json};
+use wiremock::matchers::{header_exists, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+use wtg_cli::{Wtg, WtgBuilder};
+
+/// Token the tests hand to clients talking to the fake server
+pub(crate) const TOKEN: &str = "ghp_fake";
+
+pub(crate) struct FakeGitHub {
+    server: MockServer,
+}
+
+/// A request the fake server received
+#[derive(Debug)]
+pub(crate) struct Received {
+    pub path: String,
+    /// The query string, if any
+    pub query: Option<String>,
+    /// Whether the request carried a token
+    pub authenticated: bool,
+}
+
+impl FakeGitHub {
+    /// Start a server answering the routes of `scenario`.
+    pub(crate) async fn start(scenario: &str) -> Self {
+        let fixture = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join(format!("tests/fixtures/github/{scenario}.json"));
+        let contents = std::fs::read_to_string(&fixture).expect("Failed to read fixture");
+        let routes: Map<String, Value> =
+            serde_json::from_str(&contents).expect("Fixture must map paths to responses");
+
+        let server = MockServer::start().await;
+        for (route, body) in routes {
+            let (route_path, query) = route.split_once('?').unwrap_or((&route, ""));
+            let mut mock = Mock::given(method("GET")).and(path(route_path));
+            for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+                mock = mock.and(query_param(key, value));
+            }
+            // Routes with a query beat the catch-all route for their path
+            let priority = if query.is_empty() { 5 } else { 4 };
+            mock.respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .with_priority(priority)
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "message": "Not Found",
+                "documentation_url": "https://docs.github.com/rest"
+            })))
+            .with_priority(u8::MAX)
+            .mount(&server)
+            .await;
+
+        Self { server }
+    }
+
+    /// A client talking to this server, without the disk cache or clones.
+    pub(crate) fn client(&self) -> WtgBuilder {
+        Wtg::builder()
+            .github_api_url(self.server.uri())
+            .cache(false)
+            .clone_remotes(false)
+    }
+
+    /// Reject requests for `route` that carry a token with SAML SSO
+    /// enforcement; anonymous requests still get the scenario's answer.
+    pub(crate) async fn enforce_saml(&self, route: &str) {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .and(header_exists("authorization"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+                "message": "Resource protected by organization SAML enforcement. \
+                            You must grant your Personal Access token access to this organization.",
+                "documentation_url": "https://docs.github.com/articles/authenticating-to-a-github-organization-with-saml-single-sign-on/"
+            })))
+            .with_priority(1)
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Answer every request as if the rate limit was used up.
+    pub(crate) async fn exhaust_rate_limit(&self) {
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .insert_header("x-ratelimit-limit", "60")
+                    .insert_header("x-ratelimit-remaining", "0")
+                    .set_body_json(json!({
+                        "message": "API rate limit exceeded for 127.0.0.1.",
+                        "documentation_url": "https://docs.github.com/rest/overview/resources-in-the-rest-api#rate-limiting"
+                    })),
+            )
+            .with_priority(1)
+            .mount(&self.server)
+            .await;
+    }
+
+    /// Requests received so far, oldest first.
+    pub(crate) async fn received(&self) -> Vec<Received> {
+        self.server
+            .received_requests()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|request| Received {
+                path: request.url.path().to_string(),
+                query: request.url.query().map(str::to_string),
+                authenticated: request.headers.contains_key("authorization"),
+            })
+            .collect()
+    }
+}
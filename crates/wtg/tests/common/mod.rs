@@ -1,6 +1,8 @@
+pub(crate) mod github;
+
 use std::fs::File;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 use wtg_cli::git::GitRepo;
 use zip::ZipArchive;
@@ -10,10 +12,16 @@ use zip::ZipArchive;
 pub(crate) struct TestRepoFixture {
     pub repo: GitRepo,
     pub commits: TestCommits,
-    #[allow(dead_code)]
     temp_dir: TempDir, // Kept alive to prevent cleanup
 }
 
+impl TestRepoFixture {
+    /// Root of the extracted working tree
+    pub(crate) fn path(&self) -> &Path {
+        self.temp_dir.path()
+    }
+}
+
 /// Expected commit hashes in the test repository
 #[allow(clippy::redundant_pub_crate)]
 pub(crate) struct TestCommits {
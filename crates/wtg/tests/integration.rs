@@ -6,10 +6,11 @@
 /// - CI: automatically included in the `ci` profile
 use std::path::PathBuf;
 use wtg_cli::backend::resolve_backend;
+use wtg_cli::git::{CommitInfo, TagInfo};
 use wtg_cli::parse_input::{ParsedInput, ParsedQuery, Query};
 use wtg_cli::release_filter::ReleaseFilter;
-use wtg_cli::resolution::IdentifiedThing;
 use wtg_cli::resolution::resolve;
+use wtg_cli::resolution::{CommitOrigin, IdentifiedThing};
 
 /// Test identifying a recent commit from the actual wtg repository
 #[tokio::test]
@@ -24,7 +25,7 @@ async fn integration_identify_recent_commit() {
         .await
         .expect("Failed to disambiguate commit");
 
-    let result = resolve(backend.as_ref(), &query, &ReleaseFilter::Unrestricted)
+    let result = resolve(backend.as_ref(), &query, &ReleaseFilter::default())
         .await
         .expect("Failed to identify commit");
 
@@ -45,7 +46,7 @@ async fn integration_identify_tag() {
         .await
         .expect("Failed to disambiguate tag");
 
-    let result = resolve(backend.as_ref(), &query, &ReleaseFilter::Unrestricted)
+    let result = resolve(backend.as_ref(), &query, &ReleaseFilter::default())
         .await
         .expect("Failed to identify tag");
 
@@ -67,7 +68,7 @@ async fn integration_identify_file() {
         .await
         .expect("Failed to disambiguate file");
 
-    let result = resolve(backend.as_ref(), &query, &ReleaseFilter::Unrestricted)
+    let result = resolve(backend.as_ref(), &query, &ReleaseFilter::default())
         .await
         .expect("Failed to identify LICENSE");
 
@@ -126,7 +127,7 @@ async fn integration_identify_zed_issue_41633() {
         .expect("Failed to disambiguate query");
 
     // Step 4: Resolve (same as CLI)
-    let result = resolve(backend.as_ref(), &query, &ReleaseFilter::Unrestricted)
+    let result = resolve(backend.as_ref(), &query, &ReleaseFilter::default())
         .await
         .expect("Failed to resolve");
 
@@ -190,7 +191,7 @@ async fn integration_identify_go_task_issue_1322() {
         .expect("Failed to disambiguate query");
 
     // Step 4: Resolve (same as CLI)
-    let result = resolve(backend.as_ref(), &query, &ReleaseFilter::Unrestricted)
+    let result = resolve(backend.as_ref(), &query, &ReleaseFilter::default())
         .await
         .expect("Failed to resolve");
 
@@ -244,14 +245,18 @@ async fn integration_skip_prereleases_filter() {
         .expect("Failed to disambiguate commit");
 
     // Resolve without filter
-    let result_unrestricted = resolve(backend.as_ref(), &query, &ReleaseFilter::Unrestricted)
+    let result_unrestricted = resolve(backend.as_ref(), &query, &ReleaseFilter::default())
         .await
         .expect("Failed to resolve without filter");
 
     // Resolve with SkipPrereleases filter
-    let result_filtered = resolve(backend.as_ref(), &query, &ReleaseFilter::SkipPrereleases)
-        .await
-        .expect("Failed to resolve with skip-prereleases filter");
+    let result_filtered = resolve(
+        backend.as_ref(),
+        &query,
+        &ReleaseFilter::default().skip_prereleases(true),
+    )
+    .await
+    .expect("Failed to resolve with skip-prereleases filter");
 
     // Both should resolve to the same tag (wtg repo has no pre-releases)
     let tag_unrestricted = match &result_unrestricted {
@@ -282,7 +287,7 @@ async fn integration_nonexistent_tag_error() {
         .expect("Failed to disambiguate commit");
 
     // Tag that doesn't exist in wtg repo
-    let filter = ReleaseFilter::Specific("v999.999.999-never-exists".to_string());
+    let filter = ReleaseFilter::specific("v999.999.999-never-exists");
 
     let result = resolve(backend.as_ref(), &query, &filter).await;
 
@@ -307,7 +312,7 @@ async fn integration_specific_tag_found() {
         .expect("Failed to disambiguate commit");
 
     // Use v0.1.0 which exists and contains this commit
-    let filter = ReleaseFilter::Specific("v0.1.0".to_string());
+    let filter = ReleaseFilter::specific("v0.1.0");
 
     let result = resolve(backend.as_ref(), &query, &filter)
         .await
@@ -423,26 +428,111 @@ fn to_snapshot(result: &IdentifiedThing) -> IntegrationSnapshot {
             file_path: None,
             previous_authors_count: None,
         },
-        IdentifiedThing::File(file_result) => IntegrationSnapshot {
-            result_type: "file".to_string(),
+        IdentifiedThing::File(file_result) => last_change_snapshot(
+            "file",
+            &file_result.file_info.path,
+            &file_result.file_info.last_commit,
+            file_result.commit_url.is_some(),
+            file_result.release.as_ref(),
+            file_result.file_info.previous_authors.len(),
+        ),
+        IdentifiedThing::Paths(paths_result) => last_change_snapshot(
+            "paths",
+            &paths_result.paths_info.pattern,
+            &paths_result.paths_info.last_commit,
+            paths_result.commit_url.is_some(),
+            paths_result.release.as_ref(),
+            paths_result.paths_info.top_contributors.len(),
+        ),
+        IdentifiedThing::FileHistory(history) => origin_snapshot(
+            "file_history",
+            &history.path,
+            &history
+                .entries
+                .iter()
+                .map(|entry| &entry.origin)
+                .collect::<Vec<_>>(),
+            Some(history.entries.len()),
+        ),
+        IdentifiedThing::Lines(lines) => {
+            // Blamed lines are enriched like a commit; snapshot the first hunk's commit
+            let first = lines.hunks.first().expect("blame has at least one hunk");
+            IntegrationSnapshot {
+                result_type: "lines".to_string(),
+                file_path: Some(lines.path.clone()),
+                ..to_snapshot(&IdentifiedThing::Enriched(Box::new(first.info.clone())))
+            }
+        }
+        IdentifiedThing::Range(range_result) => IntegrationSnapshot {
+            result_type: "range".to_string(),
             entry_point: None,
-            commit_message: Some(file_result.file_info.last_commit.message.clone()),
-            commit_author: Some(file_result.file_info.last_commit.author_name.clone()),
-            has_commit_url: file_result.commit_url.is_some(),
-            has_pr: false,
-            has_issue: false,
-            release_name: file_result.release.as_ref().map(|r| r.name.clone()),
-            release_is_semver: file_result
-                .release
-                .as_ref()
-                .map(wtg_cli::git::TagInfo::is_semver),
-            tag_name: None,
-            file_path: Some(file_result.file_info.path.clone()),
-            previous_authors_count: Some(file_result.file_info.previous_authors.len()),
+            commit_message: None,
+            commit_author: None,
+            has_commit_url: false,
+            has_pr: range_result.notes.iter().any(|note| note.pr.is_some()),
+            has_issue: range_result
+                .notes
+                .iter()
+                .any(|note| !note.issues.is_empty()),
+            release_name: Some(range_result.to_tag.name.clone()),
+            release_is_semver: Some(range_result.to_tag.is_semver()),
+            tag_name: Some(range_result.from_tag.name.clone()),
+            file_path: None,
+            previous_authors_count: None,
         },
     }
 }
 
+/// Snapshot of a file or set of files, led by the commit that last changed them
+fn last_change_snapshot(
+    result_type: &str,
+    path: &str,
+    last_commit: &CommitInfo,
+    has_commit_url: bool,
+    release: Option<&TagInfo>,
+    authors_count: usize,
+) -> IntegrationSnapshot {
+    IntegrationSnapshot {
+        result_type: result_type.to_string(),
+        entry_point: None,
+        commit_message: Some(last_commit.message.clone()),
+        commit_author: Some(last_commit.author_name.clone()),
+        has_commit_url,
+        has_pr: false,
+        has_issue: false,
+        release_name: release.map(|r| r.name.clone()),
+        release_is_semver: release.map(TagInfo::is_semver),
+        tag_name: None,
+        file_path: Some(path.to_string()),
+        previous_authors_count: Some(authors_count),
+    }
+}
+
+/// Snapshot of a list of commit origins (file history, blame), led by the first one
+fn origin_snapshot(
+    result_type: &str,
+    path: &str,
+    origins: &[&CommitOrigin],
+    count: Option<usize>,
+) -> IntegrationSnapshot {
+    let first = origins.first().copied();
+    let release = first.and_then(|o| o.release.as_ref());
+    IntegrationSnapshot {
+        result_type: result_type.to_string(),
+        entry_point: None,
+        commit_message: first.map(|o| o.commit.message.clone()),
+        commit_author: first.map(|o| o.commit.author_name.clone()),
+        has_commit_url: first.is_some_and(|o| o.commit.commit_url.is_some()),
+        has_pr: origins.iter().any(|o| o.pr.is_some()),
+        has_issue: false,
+        release_name: release.map(|r| r.name.clone()),
+        release_is_semver: release.map(wtg_cli::git::TagInfo::is_semver),
+        tag_name: None,
+        file_path: Some(path.to_string()),
+        previous_authors_count: count,
+    }
+}
+
 /// Unified snapshot structure for all integration tests
 /// Captures common elements (commit, release) plus type-specific fields
 #[derive(serde::Serialize)]
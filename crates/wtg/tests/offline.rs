@@ -1,13 +1,26 @@
 mod common;
 
+use common::github::{FakeGitHub, TOKEN};
 use common::{TestRepoFixture, test_repo};
+use futures::StreamExt;
 use rstest::rstest;
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use wtg_cli::backend::{Backend, GitBackend};
-use wtg_cli::parse_input::{ParsedQuery, Query};
+use wtg_cli::batch::resolve_batch_with;
+use wtg_cli::branch_filter::BranchFilter;
+use wtg_cli::error::WtgError;
+use wtg_cli::notice::Notice;
+use wtg_cli::output::{BatchJsonReport, JsonErrorReport, JsonReport};
+use wtg_cli::parse_input::{LineRange, ParsedQuery, Query};
+use wtg_cli::recording::Recording;
 use wtg_cli::release_filter::ReleaseFilter;
-use wtg_cli::resolution::resolve;
-use wtg_cli::resolution::{EntryPoint, IdentifiedThing};
+use wtg_cli::release_notes::ChangeCategory;
+use wtg_cli::resolution::{ChangesSource, EntryPoint, IdentifiedThing};
+use wtg_cli::resolution::{resolve, resolve_branches};
+use wtg_cli::{NoticeCallback, Wtg};
 
 /// Test identifying a commit by its hash
 #[rstest]
@@ -17,7 +30,7 @@ async fn test_identify_commit_by_hash(test_repo: TestRepoFixture) {
     let backend = GitBackend::new(test_repo.repo);
     let query = Query::GitCommit(commit_hash.clone());
 
-    let result = resolve(&backend, &query, &ReleaseFilter::Unrestricted)
+    let result = resolve(&backend, &query, &ReleaseFilter::default())
         .await
         .expect("Failed to identify commit");
 
@@ -58,7 +71,7 @@ async fn test_identify_commit_by_short_hash(test_repo: TestRepoFixture) {
     let backend = GitBackend::new(test_repo.repo);
     let query = Query::GitCommit(short_hash.to_string());
 
-    let result = resolve(&backend, &query, &ReleaseFilter::Unrestricted)
+    let result = resolve(&backend, &query, &ReleaseFilter::default())
         .await
         .expect("Failed to identify commit");
 
@@ -92,7 +105,7 @@ async fn test_identify_file(test_repo: TestRepoFixture) {
         path: PathBuf::from("test.txt"),
     };
 
-    let result = resolve(&backend, &query, &ReleaseFilter::Unrestricted)
+    let result = resolve(&backend, &query, &ReleaseFilter::default())
         .await
         .expect("Failed to identify file");
 
@@ -123,6 +136,81 @@ async fn test_identify_file(test_repo: TestRepoFixture) {
     }
 }
 
+/// Test blaming lines of a file: the originating commit is enriched like a commit query
+#[rstest]
+#[tokio::test]
+async fn test_identify_file_lines(test_repo: TestRepoFixture) {
+    let backend = GitBackend::new(test_repo.repo);
+    let query = Query::FileLines {
+        branch: "HEAD".to_string(),
+        path: PathBuf::from("test.txt"),
+        lines: LineRange { start: 2, end: 2 },
+    };
+
+    let result = resolve(&backend, &query, &ReleaseFilter::default())
+        .await
+        .expect("Failed to blame lines");
+
+    let IdentifiedThing::Lines(lines) = result else {
+        panic!("Expected Lines result, got something else");
+    };
+    assert_eq!(lines.path, "test.txt");
+    assert_eq!(lines.hunks.len(), 1);
+
+    let hunk = &lines.hunks[0];
+    assert_eq!((hunk.start_line, hunk.end_line), (2, 2));
+    assert_eq!(hunk.lines, vec!["With more content".to_string()]);
+    assert!(matches!(
+        &hunk.info.entry_point,
+        EntryPoint::FileLines { path, lines, .. }
+            if path == "test.txt" && *lines == LineRange { start: 2, end: 2 }
+    ));
+
+    let commit = hunk.info.commit.as_ref().expect("blamed commit");
+    assert_eq!(commit.hash, test_repo.commits.commit2_update_file);
+    assert_eq!(
+        hunk.info.release.as_ref().map(|r| r.name.as_str()),
+        Some("beta-release")
+    );
+}
+
+/// Test a glob query: the latest matching change and the release that shipped it
+#[rstest]
+#[tokio::test]
+async fn test_identify_path_glob(test_repo: TestRepoFixture) {
+    let backend = GitBackend::new(test_repo.repo);
+    let query = backend
+        .disambiguate_query(&ParsedQuery::Unknown("*.txt".to_string()))
+        .await
+        .expect("Failed to disambiguate glob");
+    assert_eq!(
+        query,
+        Query::PathGlob {
+            branch: "HEAD".to_string(),
+            pattern: "*.txt".to_string(),
+        }
+    );
+
+    let result = resolve(&backend, &query, &ReleaseFilter::default())
+        .await
+        .expect("Failed to resolve glob");
+
+    let IdentifiedThing::Paths(paths) = result else {
+        panic!("Expected Paths result, got something else");
+    };
+    assert_eq!(paths.paths_info.pattern, "*.txt");
+    assert_eq!(
+        paths.paths_info.last_commit.hash,
+        test_repo.commits.commit2_update_file
+    );
+    assert_eq!(paths.paths_info.changed_paths, vec!["test.txt".to_string()]);
+    assert!(!paths.paths_info.top_contributors.is_empty());
+    assert_eq!(
+        paths.release.as_ref().map(|r| r.name.as_str()),
+        Some("beta-release")
+    );
+}
+
 /// Test identifying a tag
 #[rstest]
 #[tokio::test]
@@ -133,7 +221,7 @@ async fn test_identify_tag(test_repo: TestRepoFixture) {
         .await
         .expect("Failed to disambiguate tag");
 
-    let result = resolve(&backend, &query, &ReleaseFilter::Unrestricted)
+    let result = resolve(&backend, &query, &ReleaseFilter::default())
         .await
         .expect("Failed to identify tag");
 
@@ -159,6 +247,86 @@ async fn test_identify_tag(test_repo: TestRepoFixture) {
     }
 }
 
+/// Test the JSON document for a commit lookup
+#[rstest]
+#[tokio::test]
+async fn test_json_report_for_commit(test_repo: TestRepoFixture) {
+    let backend = GitBackend::new(test_repo.repo);
+    let query = Query::GitCommit(test_repo.commits.commit1_add_file.clone());
+    let filter = ReleaseFilter::default();
+
+    let result = resolve(&backend, &query, &filter)
+        .await
+        .expect("Failed to identify commit");
+    let notices = [Notice::ApiOnly];
+    let json = serde_json::to_value(JsonReport::new(&result, &filter, &notices))
+        .expect("Failed to serialize report");
+
+    assert_eq!(json["schema_version"], 1);
+    assert!(json["release_check"].is_null());
+    assert_eq!(json["result"]["kind"], "enriched");
+    assert_eq!(json["result"]["entry_point"]["kind"], "commit");
+    assert_eq!(
+        json["result"]["entry_point"]["value"],
+        test_repo.commits.commit1_add_file
+    );
+    assert_eq!(json["result"]["commit"]["message"], "Add test.txt file");
+    assert!(json["result"]["pr"].is_null());
+    assert_eq!(json["result"]["release"]["name"], "v1.0.0");
+    assert_eq!(json["result"]["release"]["semver_info"]["major"], 1);
+    assert_eq!(json["notices"][0]["kind"], "api_only");
+}
+
+/// A failed query still produces one document, with the error in place of the result
+#[rstest]
+#[tokio::test]
+async fn test_json_report_for_error(test_repo: TestRepoFixture) {
+    let backend = GitBackend::new(test_repo.repo);
+    let query = Query::Tag("no-such-tag".to_string());
+
+    let err = resolve(&backend, &query, &ReleaseFilter::default())
+        .await
+        .expect_err("Unknown tag should fail");
+    let notices = [Notice::ApiOnly];
+    let json = serde_json::to_value(JsonErrorReport::new(&err, &notices))
+        .expect("Failed to serialize report");
+
+    assert_eq!(json["schema_version"], 1);
+    assert_eq!(json["error"]["kind"], "not_found");
+    let message = json["error"]["message"].as_str().expect("message");
+    assert!(message.contains("no-such-tag"), "{message}");
+    assert!(!message.contains('\x1b'), "{message}");
+    assert!(json.get("result").is_none());
+    assert_eq!(json["notices"][0]["kind"], "api_only");
+}
+
+/// Test the JSON document for a tag lookup
+#[rstest]
+#[tokio::test]
+async fn test_json_report_for_tag(test_repo: TestRepoFixture) {
+    let backend = GitBackend::new(test_repo.repo);
+    let query = Query::Tag("beta-release".to_string());
+    let filter = ReleaseFilter::default();
+
+    let result = resolve(&backend, &query, &filter)
+        .await
+        .expect("Failed to identify tag");
+    let json = serde_json::to_value(JsonReport::new(&result, &filter, &[]))
+        .expect("Failed to serialize report");
+
+    assert_eq!(json["result"]["kind"], "tag");
+    assert_eq!(json["result"]["tag_info"]["name"], "beta-release");
+    assert_eq!(
+        json["result"]["tag_info"]["commit_hash"],
+        test_repo.commits.commit2_update_file
+    );
+    assert!(json["result"]["tag_info"]["semver_info"].is_null());
+    // Non-semver tag without release notes or changelog has no changes source
+    assert!(json["result"]["changes_source"].is_null());
+    assert_eq!(json["result"]["commits"], serde_json::json!([]));
+    assert_eq!(json["notices"], serde_json::json!([]));
+}
+
 /// Test that nonexistent input returns error
 #[rstest]
 #[tokio::test]
@@ -319,6 +487,7 @@ async fn disambiguates_branch_paths_with_slashes() {
                 "docs".to_string(),
                 "guide.md".to_string(),
             ],
+            lines: None,
         })
         .await
         .expect("disambiguate path");
@@ -329,3 +498,607 @@ async fn disambiguates_branch_paths_with_slashes() {
             if branch == "some/path" && path == Path::new("docs/guide.md")
     ));
 }
+
+/// Input naming both a file and a branch is reported with every reading instead of guessed
+#[rstest]
+#[tokio::test]
+async fn reports_every_match_for_ambiguous_input() {
+    let temp_dir = tempfile::TempDir::new().expect("temp dir");
+    let repo_path = temp_dir.path().to_path_buf();
+
+    let initial_hash = {
+        let repo = git2::Repository::init(&repo_path).expect("init repo");
+        let signature = git2::Signature::now("Test User", "test@example.com").expect("signature");
+        std::fs::write(repo_path.join("release"), "checklist").expect("write file");
+        let mut index = repo.index().expect("index");
+        index.add_path(Path::new("release")).expect("add path");
+        let tree_id = index.write_tree().expect("tree");
+        let tree = repo.find_tree(tree_id).expect("tree lookup");
+        let oid = repo
+            .commit(Some("HEAD"), &signature, &signature, "initial", &tree, &[])
+            .expect("commit");
+        let commit = repo.find_commit(oid).expect("commit");
+        repo.branch("release", &commit, true).expect("branch");
+        repo.branch("hotfix", &commit, true).expect("branch");
+        oid.to_string()
+    };
+
+    let repo = wtg_cli::git::GitRepo::from_path(&repo_path).expect("open repo");
+    let backend = GitBackend::new(repo);
+
+    let err = backend
+        .disambiguate_query(&ParsedQuery::Unknown("release".to_string()))
+        .await
+        .expect_err("ambiguous input");
+    let WtgError::MultipleMatches(candidates) = err else {
+        panic!("Expected MultipleMatches, got {err:?}");
+    };
+    let kinds: Vec<&str> = candidates.iter().map(|c| c.kind).collect();
+    assert_eq!(kinds, vec!["file", "branch"]);
+    assert_eq!(
+        candidates[0].query,
+        Query::FilePath {
+            branch: "HEAD".to_string(),
+            path: PathBuf::from("release"),
+        }
+    );
+    assert_eq!(candidates[1].query, Query::GitCommit(initial_hash.clone()));
+    assert!(candidates[1].preview.ends_with("initial"));
+
+    // A branch on its own resolves to the commit it points at
+    let query = backend
+        .disambiguate_query(&ParsedQuery::Unknown("hotfix".to_string()))
+        .await
+        .expect("branch");
+    assert_eq!(query, Query::GitCommit(initial_hash));
+}
+
+/// Test that batch mode shares one backend and keeps input order
+#[rstest]
+#[tokio::test]
+async fn test_batch_preserves_input_order(test_repo: TestRepoFixture) {
+    let mut repo = Some(test_repo.repo);
+    let mut backends_created = 0;
+    let inputs = vec![
+        "v1.0.0".to_string(),
+        test_repo.commits.commit0_initial[..7].to_string(),
+        "definitely-not-a-thing".to_string(),
+        test_repo.commits.commit1_add_file.clone(),
+    ];
+    let filter = ReleaseFilter::default();
+
+    let entries: Vec<_> = resolve_batch_with(
+        inputs.clone(),
+        None,
+        &filter,
+        None,
+        NonZeroUsize::new(3).unwrap(),
+        &mut |_| {
+            backends_created += 1;
+            let backend: Box<dyn Backend> = Box::new(GitBackend::new(
+                repo.take().expect("backend requested twice"),
+            ));
+            Ok(backend)
+        },
+    )
+    .collect()
+    .await;
+
+    assert_eq!(backends_created, 1);
+    let order: Vec<_> = entries.iter().map(|entry| entry.input.clone()).collect();
+    assert_eq!(order, inputs);
+
+    assert!(matches!(
+        entries[0].outcome,
+        Ok(IdentifiedThing::Tag(ref tag)) if tag.tag_info.name == "v1.0.0"
+    ));
+    assert!(matches!(
+        entries[1].outcome,
+        Ok(IdentifiedThing::Enriched(ref info))
+            if info.commit.as_ref().is_some_and(|c| c.hash == test_repo.commits.commit0_initial)
+    ));
+    assert!(
+        entries[2]
+            .outcome
+            .as_ref()
+            .is_err_and(WtgError::is_not_found)
+    );
+    assert!(entries[3].outcome.is_ok());
+
+    // Failed queries carry a plain-text error in the JSON document
+    let json = serde_json::to_value(BatchJsonReport::new(&entries, &filter, &[]))
+        .expect("Failed to serialize report");
+    assert_eq!(json["results"][0]["input"], "v1.0.0");
+    assert_eq!(json["results"][0]["result"]["kind"], "tag");
+    assert!(json["results"][0]["error"].is_null());
+    assert!(json["results"][2]["result"].is_null());
+    let error = json["results"][2]["error"].as_str().expect("error message");
+    assert!(error.contains("definitely-not-a-thing"));
+    assert!(!error.contains('\x1b') && !error.contains('\n'));
+}
+
+/// Test resolving a release range into release notes
+#[rstest]
+#[tokio::test]
+async fn test_resolve_tag_range(test_repo: TestRepoFixture) {
+    let backend = GitBackend::new(test_repo.repo);
+    let query = Query::TagRange {
+        from: "v1.0.0".to_string(),
+        to: "beta-release".to_string(),
+    };
+    let filter = ReleaseFilter::default();
+
+    let result = resolve(&backend, &query, &filter)
+        .await
+        .expect("Failed to resolve range");
+
+    let IdentifiedThing::Range(range) = &result else {
+        panic!("Expected Range result, got {result:?}");
+    };
+    assert_eq!(range.from_tag.name, "v1.0.0");
+    assert_eq!(range.to_tag.name, "beta-release");
+    assert_eq!(range.commit_count, 1);
+    assert_eq!(range.notes.len(), 1);
+
+    // Without a forge there are no PRs, so each commit is its own entry
+    let note = &range.notes[0];
+    assert_eq!(note.title, "Update test.txt with new content");
+    assert_eq!(note.category, ChangeCategory::Other);
+    assert!(note.pr.is_none());
+    assert_eq!(note.commits[0].hash, test_repo.commits.commit2_update_file);
+
+    let json = serde_json::to_value(JsonReport::new(&result, &filter, &[]))
+        .expect("Failed to serialize report");
+    assert_eq!(json["result"]["kind"], "range");
+    assert_eq!(json["result"]["notes"][0]["category"], "other");
+}
+
+/// Test that an unknown range end is reported as a missing tag
+#[rstest]
+#[tokio::test]
+async fn test_resolve_tag_range_unknown_tag(test_repo: TestRepoFixture) {
+    let backend = GitBackend::new(test_repo.repo);
+    let query = Query::TagRange {
+        from: "v0.0.1".to_string(),
+        to: "v1.0.0".to_string(),
+    };
+
+    let result = resolve(&backend, &query, &ReleaseFilter::default()).await;
+
+    assert!(matches!(result, Err(WtgError::TagNotFound(ref tag)) if tag == "v0.0.1"));
+}
+
+/// Test reporting the branches that contain a commit
+#[rstest]
+#[tokio::test]
+async fn test_resolve_branches_containing_commit(test_repo: TestRepoFixture) {
+    let backend = GitBackend::new(test_repo.repo);
+    let query = Query::GitCommit(test_repo.commits.commit0_initial.clone());
+    let mut result = resolve(&backend, &query, &ReleaseFilter::default())
+        .await
+        .expect("Failed to identify commit");
+
+    resolve_branches(&backend, &mut result, &BranchFilter::default()).await;
+    let IdentifiedThing::Enriched(info) = &result else {
+        panic!("Expected Enriched result, got {result:?}");
+    };
+    let branches = info.branches.as_ref().expect("branches should be checked");
+    assert!(!branches.is_empty(), "initial commit should be on a branch");
+
+    // A filter that matches nothing leaves an empty (but known) list
+    let filter = BranchFilter::new(&["no-such-branch/*".to_string()]).unwrap();
+    resolve_branches(&backend, &mut result, &filter).await;
+    let IdentifiedThing::Enriched(info) = &result else {
+        unreachable!();
+    };
+    assert_eq!(info.branches.as_deref(), Some(&[][..]));
+}
+
+/// Test reporting the earliest release of every release line
+#[rstest]
+#[tokio::test]
+async fn test_resolve_release_lines(test_repo: TestRepoFixture) {
+    // A second major version and a prefixed component, both on the latest commit
+    {
+        let repo = git2::Repository::open(test_repo.repo.path()).expect("Failed to open repo");
+        let latest = repo
+            .revparse_single(&test_repo.commits.commit2_update_file)
+            .expect("Failed to find commit");
+        for name in ["v2.0.0", "py-v0.1.0"] {
+            repo.tag_lightweight(name, &latest, false)
+                .expect("Failed to create tag");
+        }
+    }
+
+    let backend = GitBackend::new(test_repo.repo);
+    let query = Query::GitCommit(test_repo.commits.commit1_add_file.clone());
+
+    let result = resolve(&backend, &query, &ReleaseFilter::default())
+        .await
+        .expect("Failed to identify commit");
+    let IdentifiedThing::Enriched(info) = result else {
+        panic!("Expected Enriched result, got {result:?}");
+    };
+    assert_eq!(info.releases.len(), 1);
+
+    let filter = ReleaseFilter::default().release_lines(true);
+    let result = resolve(&backend, &query, &filter)
+        .await
+        .expect("Failed to identify commit");
+    let IdentifiedThing::Enriched(info) = result else {
+        panic!("Expected Enriched result, got {result:?}");
+    };
+    assert_eq!(info.release.map(|r| r.name).as_deref(), Some("v1.0.0"));
+    let names: Vec<&str> = info
+        .releases
+        .iter()
+        .map(|line| line.release.name.as_str())
+        .collect();
+    assert_eq!(names, ["v1.0.0", "v2.0.0", "py-v0.1.0"]);
+    assert!(info.releases.iter().all(|line| !line.is_backport));
+}
+
+/// Test that previous-tag lookup and release selection stay within a component
+#[rstest]
+#[tokio::test]
+async fn test_tag_prefix_components(test_repo: TestRepoFixture) {
+    {
+        let repo = git2::Repository::open(test_repo.repo.path()).expect("Failed to open repo");
+        let tags = [
+            ("py-v1.0.0", &test_repo.commits.commit1_add_file),
+            ("rust-v1.0.5", &test_repo.commits.commit1_add_file),
+            ("py-v1.1.0", &test_repo.commits.commit2_update_file),
+        ];
+        for (name, commit) in tags {
+            let target = repo.revparse_single(commit).expect("Failed to find commit");
+            repo.tag_lightweight(name, &target, false)
+                .expect("Failed to create tag");
+        }
+    }
+
+    let backend = GitBackend::new(test_repo.repo);
+    let previous = backend
+        .find_previous_tag("py-v1.1.0")
+        .await
+        .expect("Failed to find previous tag")
+        .expect("Expected a previous tag");
+    assert_eq!(previous.name, "py-v1.0.0");
+
+    let query = Query::GitCommit(test_repo.commits.commit1_add_file.clone());
+    let filter = ReleaseFilter::default().tag_prefix(Some("rust".to_string()));
+    let result = resolve(&backend, &query, &filter)
+        .await
+        .expect("Failed to identify commit");
+    let IdentifiedThing::Enriched(info) = result else {
+        panic!("Expected Enriched result, got {result:?}");
+    };
+    assert_eq!(info.release.map(|r| r.name).as_deref(), Some("rust-v1.0.5"));
+}
+
+/// The library client finds the repository from a path and resolves input like the CLI
+#[rstest]
+#[tokio::test]
+async fn test_client_resolves_in_repo_path(test_repo: TestRepoFixture) {
+    let notices: Arc<Mutex<Vec<Notice>>> = Arc::default();
+    let sink = Arc::clone(&notices);
+    let notice_cb: NoticeCallback = Arc::new(move |notice| sink.lock().unwrap().push(notice));
+
+    let wtg = Wtg::builder()
+        .repo_path(test_repo.path().join("test.txt"))
+        .release_filter(ReleaseFilter::default().skip_prereleases(true))
+        .notice_callback(notice_cb)
+        .build()
+        .expect("Failed to build client");
+
+    let result = wtg.resolve("v1.0.0").await.expect("Failed to resolve tag");
+    assert!(matches!(result, IdentifiedThing::Tag(ref tag) if tag.tag_info.name == "v1.0.0"));
+
+    let short_hash = &test_repo.commits.commit1_add_file[..7];
+    let result = wtg
+        .resolve(short_hash)
+        .await
+        .expect("Failed to resolve commit");
+    let IdentifiedThing::Enriched(info) = result else {
+        panic!("Expected Enriched result, got {result:?}");
+    };
+    assert_eq!(
+        info.commit.map(|c| c.hash),
+        Some(test_repo.commits.commit1_add_file.clone())
+    );
+
+    let err = match wtg.resolve("definitely-not-a-thing").await {
+        Ok(result) => panic!("Expected an error, got {result:?}"),
+        Err(err) => err,
+    };
+    assert!(matches!(err, WtgError::NotFound(_)));
+
+    // One backend per query, and the fixture has no remotes
+    assert!(
+        notices
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|notice| matches!(notice, Notice::NoRemotes))
+    );
+}
+
+/// Concurrent queries ask the ambiguity callback one at a time
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_client_asks_ambiguity_callback_one_at_a_time(test_repo: TestRepoFixture) {
+    // `test.txt` names both a file and a branch
+    {
+        let repo = git2::Repository::open(test_repo.path()).expect("Failed to open test repo");
+        let head = repo.head().and_then(|h| h.peel_to_commit()).expect("HEAD");
+        repo.branch("test.txt", &head, false).expect("branch");
+    }
+
+    let asking = Arc::new(AtomicUsize::new(0));
+    let asked = Arc::new(AtomicUsize::new(0));
+    let (asking_cb, asked_cb) = (Arc::clone(&asking), Arc::clone(&asked));
+    let wtg = Wtg::builder()
+        .repo_path(test_repo.path())
+        .on_ambiguous(Arc::new(move |_, mut candidates| {
+            assert_eq!(
+                asking_cb.fetch_add(1, Ordering::SeqCst),
+                0,
+                "asked twice at once"
+            );
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            asking_cb.fetch_sub(1, Ordering::SeqCst);
+            asked_cb.fetch_add(1, Ordering::SeqCst);
+            Ok(candidates.swap_remove(0).query)
+        }))
+        .build()
+        .expect("Failed to build client");
+
+    let results =
+        futures::future::join_all([wtg.resolve("test.txt"), wtg.resolve("test.txt")]).await;
+    for result in results {
+        let result = result.expect("Failed to resolve file");
+        assert!(matches!(result, IdentifiedThing::File(_)), "got {result:?}");
+    }
+    assert_eq!(asked.load(Ordering::SeqCst), 2);
+}
+
+/// Merge commit of the other/lib PR closing owner/repo#3 in the `cross_project_issue` scenario
+const LIB_MERGE_COMMIT: &str = "3c4f1e0b2a9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b";
+
+/// Point the fixture repository's origin at `owner/repo` on github.com
+fn add_github_origin(test_repo: &TestRepoFixture) {
+    git2::Repository::open(test_repo.path())
+        .expect("Failed to open test repo")
+        .remote("origin", "https://github.com/owner/repo")
+        .expect("Failed to add remote");
+}
+
+fn collect_notices() -> (NoticeCallback, Arc<Mutex<Vec<Notice>>>) {
+    let notices: Arc<Mutex<Vec<Notice>>> = Arc::default();
+    let sink = Arc::clone(&notices);
+    let notice_cb: NoticeCallback = Arc::new(move |notice| sink.lock().unwrap().push(notice));
+    (notice_cb, notices)
+}
+
+/// An issue closed by a PR in another repository ships in that repository's release
+#[rstest]
+#[tokio::test]
+async fn test_fake_github_cross_project_issue(test_repo: TestRepoFixture) {
+    add_github_origin(&test_repo);
+    let github = FakeGitHub::start("cross_project_issue").await;
+    let wtg = github
+        .client()
+        .repo_path(test_repo.path())
+        .build()
+        .expect("Failed to build client");
+
+    let result = wtg.resolve("#3").await.expect("Failed to resolve issue");
+    let IdentifiedThing::Enriched(info) = result else {
+        panic!("Expected Enriched result, got {result:?}");
+    };
+
+    let issue = info.issue.expect("Expected the issue");
+    assert_eq!(issue.number, 3);
+    assert!(!issue.timeline_may_be_incomplete);
+
+    let pr = info.pr.expect("Expected the closing PR");
+    assert_eq!(pr.number, 9);
+    assert_eq!(pr.state, "closed");
+    let pr_repo = pr.repo_info.expect("Expected the PR's repository");
+    assert_eq!((pr_repo.owner(), pr_repo.repo()), ("other", "lib"));
+
+    assert_eq!(
+        info.commit.map(|c| c.hash).as_deref(),
+        Some(LIB_MERGE_COMMIT)
+    );
+    let release = info.release.expect("Expected a release");
+    assert_eq!(release.name, "v2.1.0");
+    assert_eq!(
+        release.release_url.as_deref(),
+        Some("https://github.com/other/lib/releases/tag/v2.1.0")
+    );
+
+    // Without a token nothing is authenticated
+    assert!(github.received().await.iter().all(|r| !r.authenticated));
+}
+
+/// SAML-protected routes are retried anonymously, and the timeline is flagged as partial
+#[tokio::test]
+async fn test_fake_github_saml_fallback() {
+    let github = FakeGitHub::start("cross_project_issue").await;
+    github.enforce_saml("/repos/owner/repo/issues/3").await;
+    github
+        .enforce_saml("/repos/owner/repo/issues/3/timeline")
+        .await;
+    let wtg = github
+        .client()
+        .repo("owner/repo")
+        .token(TOKEN)
+        .build()
+        .expect("Failed to build client");
+
+    let result = wtg.resolve("#3").await.expect("Failed to resolve issue");
+    let IdentifiedThing::Enriched(info) = result else {
+        panic!("Expected Enriched result, got {result:?}");
+    };
+    let issue = info.issue.expect("Expected the issue");
+    assert!(issue.timeline_may_be_incomplete);
+    assert_eq!(info.pr.map(|pr| pr.number), Some(9));
+    assert_eq!(info.release.map(|r| r.name).as_deref(), Some("v2.1.0"));
+
+    let timeline_requests: Vec<bool> = github
+        .received()
+        .await
+        .into_iter()
+        .filter(|r| r.path == "/repos/owner/repo/issues/3/timeline")
+        .map(|r| r.authenticated)
+        .collect();
+    assert_eq!(timeline_requests, [true, false]);
+}
+
+/// Hitting the rate limit is reported, and not retried anonymously
+#[tokio::test]
+async fn test_fake_github_rate_limit() {
+    let github = FakeGitHub::start("cross_project_issue").await;
+    github.exhaust_rate_limit().await;
+    let (notice_cb, notices) = collect_notices();
+    let wtg = github
+        .client()
+        .repo("owner/repo")
+        .token(TOKEN)
+        .notice_callback(notice_cb)
+        .build()
+        .expect("Failed to build client");
+
+    // Lookups that hit the limit find nothing
+    let result = wtg.resolve("#3").await;
+    assert!(matches!(result, Err(WtgError::NotFound(_))), "{result:?}");
+    assert!(notices.lock().unwrap().iter().any(|notice| matches!(
+        notice,
+        Notice::GhRateLimitHit {
+            authenticated: true
+        }
+    )));
+    assert!(github.received().await.iter().all(|r| r.authenticated));
+}
+
+/// Without a clone, a tag's changelog section is read through the contents API
+#[tokio::test]
+async fn test_fake_github_changelog_from_contents() {
+    let github = FakeGitHub::start("release_history").await;
+    let wtg = github
+        .client()
+        .repo("owner/repo")
+        .build()
+        .expect("Failed to build client");
+
+    // API-only backends don't guess that bare input is a tag, so ask for one
+    let parsed = wtg.parse("v1.0.0").expect("Failed to parse input");
+    let backend = wtg.backend_for(&parsed).expect("Failed to pick backend");
+    let query = Query::Tag("v1.0.0".to_string());
+    let result = resolve(backend.as_ref(), &query, &ReleaseFilter::default())
+        .await
+        .expect("Failed to resolve tag");
+    let IdentifiedThing::Tag(tag) = result else {
+        panic!("Expected Tag result, got {result:?}");
+    };
+    assert_eq!(tag.tag_info.name, "v1.0.0");
+    assert!(matches!(tag.changes_source, Some(ChangesSource::Changelog)));
+    let changes = tag.changes.expect("Expected the changelog section");
+    assert!(
+        changes.contains("Crash when the config file is empty"),
+        "{changes}"
+    );
+    assert!(!changes.contains("First public release"), "{changes}");
+
+    assert!(
+        github
+            .received()
+            .await
+            .iter()
+            .any(|r| r.path == "/repos/owner/repo/contents/CHANGELOG.md")
+    );
+}
+
+/// Release listings are walked page by page until the answer turns up
+#[tokio::test]
+async fn test_fake_github_paginated_releases() {
+    let github = FakeGitHub::start("release_history").await;
+    let wtg = github
+        .client()
+        .repo("owner/repo")
+        .build()
+        .expect("Failed to build client");
+
+    // The first page only has nightlies published after that date
+    let result = wtg
+        .resolve("@2026-03-14")
+        .await
+        .expect("Failed to resolve date");
+    let IdentifiedThing::Tag(tag) = result else {
+        panic!("Expected Tag result, got {result:?}");
+    };
+    assert_eq!(tag.tag_info.name, "v1.0.0");
+    assert!(tag.current_at.is_some());
+
+    let pages: Vec<String> = github
+        .received()
+        .await
+        .into_iter()
+        .filter(|r| r.path == "/repos/owner/repo/releases")
+        .filter_map(|r| r.query)
+        .collect();
+    assert_eq!(pages, ["per_page=100&page=1", "per_page=100&page=2"]);
+}
+
+/// A recorded run replays without the network or a token, and gives the same answer
+#[tokio::test]
+async fn test_fake_github_record_and_replay() {
+    let github = FakeGitHub::start("cross_project_issue").await;
+    github
+        .enforce_saml("/repos/owner/repo/issues/3/timeline")
+        .await;
+    let dir = tempfile::tempdir().expect("Failed to create temp dir");
+
+    let recording = Recording::record(dir.path()).expect("Failed to start recording");
+    let wtg = github
+        .client()
+        .repo("owner/repo")
+        .token(TOKEN)
+        .recording(Arc::new(recording))
+        .build()
+        .expect("Failed to build client");
+    let recorded = wtg.resolve("#3").await.expect("Failed to resolve issue");
+    let requests_sent = github.received().await.len();
+
+    // The token never ends up in the recording
+    for entry in std::fs::read_dir(dir.path()).expect("Failed to list recording") {
+        let contents = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+        assert!(!contents.contains(TOKEN), "{contents}");
+    }
+
+    let recording = Recording::replay(dir.path()).expect("Failed to load recording");
+    let wtg = github
+        .client()
+        .repo("owner/repo")
+        .recording(Arc::new(recording))
+        .build()
+        .expect("Failed to build client");
+    let replayed = wtg.resolve("#3").await.expect("Failed to replay issue");
+    assert_eq!(github.received().await.len(), requests_sent);
+
+    let (IdentifiedThing::Enriched(recorded), IdentifiedThing::Enriched(replayed)) =
+        (recorded, replayed)
+    else {
+        panic!("Expected Enriched results");
+    };
+    // The authenticated timeline request failed and was retried anonymously, again
+    assert!(
+        replayed
+            .issue
+            .expect("Expected the issue")
+            .timeline_may_be_incomplete
+    );
+    assert_eq!(replayed.pr.map(|pr| pr.number), Some(9));
+    assert_eq!(recorded.release.map(|r| r.name).as_deref(), Some("v2.1.0"));
+    assert_eq!(replayed.release.map(|r| r.name).as_deref(), Some("v2.1.0"));
+}
@@ -2,6 +2,29 @@
 
 from importlib import metadata as _metadata
 
+from ._wtg import (
+    AmbiguousInputError,
+    AuthenticationError,
+    CommitInfo,
+    EnrichedInfo,
+    FileResult,
+    ForgeError,
+    GitError,
+    InvalidInputError,
+    IssueInfo,
+    NotFoundError,
+    NotInGitRepoError,
+    PullRequestInfo,
+    RateLimitError,
+    RequestTimeoutError,
+    TagInfo,
+    TagResult,
+    UnsupportedError,
+    WtgError,
+    WtgWarning,
+    resolve,
+    resolve_async,
+)
 from .cli import main, run
 
 try:
@@ -11,4 +34,29 @@ except (
 ):  # pragma: no cover - fallback for editable installs
     __version__ = "0.0.0"
 
-__all__ = ["main", "run", "__version__"]
+__all__ = [
+    "AmbiguousInputError",
+    "AuthenticationError",
+    "CommitInfo",
+    "EnrichedInfo",
+    "FileResult",
+    "ForgeError",
+    "GitError",
+    "InvalidInputError",
+    "IssueInfo",
+    "NotFoundError",
+    "NotInGitRepoError",
+    "PullRequestInfo",
+    "RateLimitError",
+    "RequestTimeoutError",
+    "TagInfo",
+    "TagResult",
+    "UnsupportedError",
+    "WtgError",
+    "WtgWarning",
+    "main",
+    "resolve",
+    "resolve_async",
+    "run",
+    "__version__",
+]
@@ -0,0 +1,117 @@
+"""Type stubs for the native extension."""
+
+from __future__ import annotations
+
+from datetime import datetime
+from typing import Any, Callable, Optional, Union
+
+class CommitInfo:
+    hash: str
+    short_hash: str
+    message: str
+    message_lines: int
+    commit_url: Optional[str]
+    author_name: str
+    author_email: Optional[str]
+    author_login: Optional[str]
+    author_url: Optional[str]
+    date: datetime
+
+class PullRequestInfo:
+    number: int
+    repo: Optional[str]
+    title: str
+    body: Optional[str]
+    state: str
+    url: str
+    merged: bool
+    merge_commit_sha: Optional[str]
+    author: Optional[str]
+    author_url: Optional[str]
+    created_at: Optional[datetime]
+    labels: list[str]
+
+class IssueInfo:
+    number: int
+    title: str
+    body: Optional[str]
+    state: str
+    url: str
+    author: Optional[str]
+    author_url: Optional[str]
+    timeline_may_be_incomplete: bool
+
+class TagInfo:
+    name: str
+    commit_hash: str
+    created_at: datetime
+    is_semver: bool
+    is_release: bool
+    release_name: Optional[str]
+    release_url: Optional[str]
+    published_at: Optional[datetime]
+    tag_url: Optional[str]
+
+class EnrichedInfo:
+    commit: Optional[CommitInfo]
+    pr: Optional[PullRequestInfo]
+    issue: Optional[IssueInfo]
+    release: Optional[TagInfo]
+    releases: list[TagInfo]
+
+class FileResult:
+    path: str
+    last_commit: CommitInfo
+    commit_url: Optional[str]
+    previous_authors: list[tuple[str, str, str]]
+    renames: list[tuple[str, str, str]]
+    release: Optional[TagInfo]
+
+class TagResult:
+    tag: TagInfo
+    url: Optional[str]
+    changes: Optional[str]
+    changes_source: Optional[str]
+    truncated_lines: int
+    commits: list[CommitInfo]
+    current_at: Optional[datetime]
+
+class WtgError(Exception): ...
+class NotFoundError(WtgError): ...
+
+class AmbiguousInputError(WtgError):
+    candidates: tuple[tuple[str, str], ...]
+
+class InvalidInputError(WtgError): ...
+class NotInGitRepoError(WtgError): ...
+class UnsupportedError(WtgError): ...
+class GitError(WtgError): ...
+class ForgeError(WtgError): ...
+class RateLimitError(ForgeError): ...
+class AuthenticationError(ForgeError): ...
+class RequestTimeoutError(ForgeError): ...
+class WtgWarning(UserWarning): ...
+
+def run_cli(argv: list[str]) -> int: ...
+def resolve(
+    query: str,
+    *,
+    repo: Optional[str] = None,
+    release: Optional[str] = None,
+    skip_prereleases: bool = False,
+    tag_prefix: Optional[str] = None,
+    all_releases: bool = False,
+    fetch: bool = False,
+    on_notice: Optional[Callable[[dict[str, Any]], object]] = None,
+) -> Union[EnrichedInfo, FileResult, TagResult, dict[str, Any]]: ...
+async def resolve_async(
+    query: str,
+    *,
+    repo: Optional[str] = None,
+    release: Optional[str] = None,
+    skip_prereleases: bool = False,
+    tag_prefix: Optional[str] = None,
+    all_releases: bool = False,
+    fetch: bool = False,
+    on_notice: Optional[Callable[[dict[str, Any]], object]] = None,
+) -> Union[EnrichedInfo, FileResult, TagResult, dict[str, Any]]: ...
@@ -0,0 +1,32 @@
+"""Fixtures shared by the Python binding tests."""
+
+from __future__ import annotations
+
+import zipfile
+from pathlib import Path
+
+import pytest
+
+#: The repository the Rust offline tests use too
+FIXTURE_ZIP = (
+    Path(__file__).resolve().parents[2] / "crates" / "wtg" / "tests" / "fixtures" / "test-repo.zip"
+)
+
+
+@pytest.fixture
+def test_repo(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    """A fresh copy of the fixture repository, as the working directory.
+
+    It has three commits on `main`, `v1.0.0` on the second and `beta-release`
+    on the third, and no remotes.
+    """
+    with zipfile.ZipFile(FIXTURE_ZIP) as archive:
+        archive.extractall(tmp_path)
+    monkeypatch.chdir(tmp_path)
+    return tmp_path
+
+
+@pytest.fixture
+def notices() -> list[dict]:
+    """Collects the notices passed to `on_notice`."""
+    return []
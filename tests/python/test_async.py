@@ -0,0 +1,108 @@
+"""resolve_async can be awaited from asyncio, many queries at a time."""
+
+from __future__ import annotations
+
+import asyncio
+import subprocess
+import sys
+import textwrap
+
+import pytest
+
+import wtg_cli
+
+QUERIES = [
+    "1701b19f169012a64d194efa3b9ec2a3538c7964",
+    "test.txt",
+    "v1.0.0",
+    "beta-release",
+    "e7d0328",
+    "test.txt:1",
+]
+
+
+def test_gather_resolves_like_resolve(test_repo, notices):
+    expected = [repr(wtg_cli.resolve(query, on_notice=notices.append)) for query in QUERIES]
+
+    async def main():
+        # Each query several times over, all in flight at once
+        return await asyncio.gather(
+            *(
+                wtg_cli.resolve_async(query, on_notice=notices.append)
+                for _ in range(4)
+                for query in QUERIES
+            )
+        )
+
+    results = asyncio.run(main())
+
+    assert [repr(result) for result in results] == expected * 4
+
+
+def test_gather_keeps_errors_per_query(test_repo, notices):
+    async def main():
+        return await asyncio.gather(
+            wtg_cli.resolve_async("v1.0.0", on_notice=notices.append),
+            wtg_cli.resolve_async("definitely-not-a-thing", on_notice=notices.append),
+            return_exceptions=True,
+        )
+
+    tag, missing = asyncio.run(main())
+
+    assert isinstance(tag, wtg_cli.TagResult)
+    assert isinstance(missing, wtg_cli.NotFoundError)
+
+
+def test_event_loop_keeps_running(test_repo, notices):
+    async def main():
+        ticks = 0
+
+        async def tick():
+            nonlocal ticks
+            while True:
+                ticks += 1
+                await asyncio.sleep(0)
+
+        ticker = asyncio.create_task(tick())
+        await asyncio.gather(
+            *(wtg_cli.resolve_async(query, on_notice=notices.append) for query in QUERIES)
+        )
+        ticker.cancel()
+        return ticks
+
+    # The queries run off the event loop, which keeps serving other tasks
+    assert asyncio.run(main()) > 1
+
+
+@pytest.mark.parametrize("pending", [0, 8])
+def test_exit_right_after_awaiting(test_repo, pending):
+    # Exits while runtime threads may still be waking coroutines, with
+    # `pending` more queries left running for asyncio.run to cancel
+    script = textwrap.dedent(
+        f"""
+        import asyncio
+        import wtg_cli
+
+        def ignore(notice):
+            pass
+
+        async def main():
+            for _ in range({pending}):
+                asyncio.ensure_future(wtg_cli.resolve_async("test.txt", on_notice=ignore))
+            tag = await wtg_cli.resolve_async("v1.0.0", on_notice=ignore)
+            print(tag.tag.name)
+
+        asyncio.run(main())
+        """
+    )
+
+    for _ in range(5):
+        done = subprocess.run(
+            [sys.executable, "-c", script],
+            cwd=test_repo,
+            capture_output=True,
+            text=True,
+            timeout=60,
+        )
+        assert done.returncode == 0, done.stderr
+        assert done.stdout == "v1.0.0\n"
@@ -0,0 +1,80 @@
+"""wtg errors are raised as typed exceptions."""
+
+from __future__ import annotations
+
+import subprocess
+
+import pytest
+
+import wtg_cli
+
+
+@pytest.mark.parametrize(
+    ("exception", "base"),
+    [
+        (wtg_cli.NotFoundError, wtg_cli.WtgError),
+        (wtg_cli.AmbiguousInputError, wtg_cli.WtgError),
+        (wtg_cli.InvalidInputError, wtg_cli.WtgError),
+        (wtg_cli.NotInGitRepoError, wtg_cli.WtgError),
+        (wtg_cli.UnsupportedError, wtg_cli.WtgError),
+        (wtg_cli.GitError, wtg_cli.WtgError),
+        (wtg_cli.ForgeError, wtg_cli.WtgError),
+        (wtg_cli.RateLimitError, wtg_cli.ForgeError),
+        (wtg_cli.AuthenticationError, wtg_cli.ForgeError),
+        (wtg_cli.RequestTimeoutError, wtg_cli.ForgeError),
+        (wtg_cli.WtgError, Exception),
+        (wtg_cli.WtgWarning, UserWarning),
+    ],
+)
+def test_hierarchy(exception, base):
+    assert issubclass(exception, base)
+
+
+def test_not_found(test_repo, notices):
+    with pytest.raises(wtg_cli.NotFoundError) as excinfo:
+        wtg_cli.resolve("definitely-not-a-thing", on_notice=notices.append)
+
+    message = str(excinfo.value)
+    assert "definitely-not-a-thing" in message
+    # One line of plain text, like `--format json` errors
+    assert "\x1b" not in message
+    assert "\n" not in message
+
+
+@pytest.mark.parametrize(
+    "query",
+    [
+        "",
+        "https://github.com/owner",
+        "https://codeberg.org/owner/repo/src/branch/main",
+    ],
+)
+def test_invalid_input(test_repo, notices, query):
+    with pytest.raises(wtg_cli.InvalidInputError):
+        wtg_cli.resolve(query, on_notice=notices.append)
+
+
+def test_invalid_repo(test_repo, notices):
+    with pytest.raises(wtg_cli.InvalidInputError):
+        wtg_cli.resolve("#1", repo="not a repo", on_notice=notices.append)
+
+
+def test_not_in_git_repo(tmp_path, monkeypatch, notices):
+    monkeypatch.chdir(tmp_path)
+
+    with pytest.raises(wtg_cli.NotInGitRepoError):
+        wtg_cli.resolve("v1.0.0", on_notice=notices.append)
+
+
+def test_ambiguous_input_lists_candidates(test_repo, notices):
+    # `test.txt` is now both a file and a branch
+    subprocess.run(["git", "branch", "test.txt"], cwd=test_repo, check=True)
+
+    with pytest.raises(wtg_cli.AmbiguousInputError) as excinfo:
+        wtg_cli.resolve("test.txt", on_notice=notices.append)
+
+    candidates = excinfo.value.candidates
+    assert isinstance(candidates, tuple)
+    assert [kind for kind, _ in candidates] == ["file", "branch"]
+    assert candidates[1][1].endswith("Update test.txt with new content")
+    assert isinstance(excinfo.value, wtg_cli.WtgError)
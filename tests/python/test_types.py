@@ -0,0 +1,96 @@
+"""Results come back as the classes mirroring wtg's result types."""
+
+from __future__ import annotations
+
+from datetime import datetime, timezone
+
+import pytest
+
+import wtg_cli
+
+INITIAL_COMMIT = "1701b19f169012a64d194efa3b9ec2a3538c7964"
+ADD_FILE_COMMIT = "e7d0328fcad38176b00995b7f763ef1e7c8cf365"
+UPDATE_FILE_COMMIT = "f6f335876c56b42d0c7cecec8727244f9e5183fa"
+
+
+def test_commit(test_repo, notices):
+    info = wtg_cli.resolve(INITIAL_COMMIT, on_notice=notices.append)
+
+    assert isinstance(info, wtg_cli.EnrichedInfo)
+    assert info.pr is None
+    assert info.issue is None
+
+    commit = info.commit
+    assert isinstance(commit, wtg_cli.CommitInfo)
+    assert commit.hash == INITIAL_COMMIT
+    assert commit.short_hash == INITIAL_COMMIT[:7]
+    assert commit.message == "Initial commit"
+    assert commit.message_lines == 1
+    assert commit.author_name == "Test User"
+    assert commit.author_email == "test@example.com"
+    assert commit.author_login is None
+    assert commit.commit_url is None
+    assert isinstance(commit.date, datetime)
+    assert commit.date.tzinfo is not None
+
+    assert isinstance(info.release, wtg_cli.TagInfo)
+    assert info.release.name == "v1.0.0"
+    assert [release.name for release in info.releases] == ["v1.0.0"]
+    assert repr(info) == "EnrichedInfo(1701b19 in v1.0.0)"
+
+
+def test_file(test_repo, notices):
+    result = wtg_cli.resolve("test.txt", on_notice=notices.append)
+
+    assert isinstance(result, wtg_cli.FileResult)
+    assert result.path == "test.txt"
+    assert result.last_commit.hash == UPDATE_FILE_COMMIT
+    assert result.last_commit.author_name == "Another Author"
+    assert result.previous_authors == [(ADD_FILE_COMMIT, "Test User", "test@example.com")]
+    assert result.renames == []
+    assert result.release.name == "beta-release"
+    assert not result.release.is_semver
+    assert repr(result) == 'FileResult("test.txt")'
+
+
+def test_tag(test_repo, notices):
+    result = wtg_cli.resolve("v1.0.0", on_notice=notices.append)
+
+    assert isinstance(result, wtg_cli.TagResult)
+    tag = result.tag
+    assert tag.name == "v1.0.0"
+    assert tag.commit_hash == ADD_FILE_COMMIT
+    assert tag.is_semver
+    assert not tag.is_release
+    assert tag.created_at == datetime(2025, 11, 8, 1, 31, 23, tzinfo=timezone.utc)
+    assert all(isinstance(commit, wtg_cli.CommitInfo) for commit in result.commits)
+    assert repr(result) == 'TagResult("v1.0.0")'
+
+
+def test_other_results_are_dicts_shaped_like_json(test_repo, notices):
+    result = wtg_cli.resolve("test.txt:1", on_notice=notices.append)
+
+    assert result["kind"] == "lines"
+    assert result["path"] == "test.txt"
+    assert result["lines"] == {"start": 1, "end": 1}
+    hunk = result["hunks"][0]
+    assert hunk["lines"] == ["Second version of test file"]
+    assert hunk["info"]["commit"]["hash"] == UPDATE_FILE_COMMIT
+
+
+def test_results_are_read_only(test_repo, notices):
+    info = wtg_cli.resolve(INITIAL_COMMIT, on_notice=notices.append)
+
+    with pytest.raises(AttributeError):
+        info.commit.message = "rewritten"
+
+
+def test_notices_go_to_the_callback(test_repo, notices):
+    wtg_cli.resolve("v1.0.0", on_notice=notices.append)
+
+    assert notices == [{"kind": "no_remotes"}]
+
+
+def test_notices_are_warnings_without_a_callback(test_repo):
+    with pytest.warns(wtg_cli.WtgWarning, match="no_remotes"):
+        wtg_cli.resolve("v1.0.0")